@@ -0,0 +1,31 @@
+//! Compares collecting with and without preallocating according to the iterator's size hint.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use reductor::{CollectVec, Reduce};
+
+const LEN: u64 = 1_000_000;
+
+fn collect_vec(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collect_vec");
+
+    group.bench_function("reduce_with", |b| {
+        b.iter_batched(
+            || 0..LEN,
+            |iter| black_box(iter.reduce_with::<CollectVec<_>>()),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("reduce_with_hint", |b| {
+        b.iter_batched(
+            || 0..LEN,
+            |iter| black_box(iter.reduce_with_hint::<CollectVec<_>>()),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, collect_vec);
+criterion_main!(benches);
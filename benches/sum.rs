@@ -0,0 +1,39 @@
+//! Compares summing with `Sum` (which chains `iter::once`s for every item), with `SumAssign`
+//! (which adds items in place), and with `Iterator::sum`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use reductor::{Reduce, Sum, SumAssign};
+
+const LEN: u64 = 1_000_000;
+
+fn sum(c: &mut Criterion) {
+    let ints: Vec<u64> = (0..LEN).collect();
+    let floats: Vec<f64> = ints.iter().map(|&x| x as f64).collect();
+
+    let mut group = c.benchmark_group("sum");
+
+    group.bench_function("u64/Sum", |b| {
+        b.iter(|| black_box(black_box(&ints).iter().reduce_with::<Sum<u64>>()))
+    });
+    group.bench_function("u64/SumAssign", |b| {
+        b.iter(|| black_box(black_box(&ints).iter().reduce_with::<SumAssign<u64>>()))
+    });
+    group.bench_function("u64/Iterator::sum", |b| {
+        b.iter(|| black_box(black_box(&ints).iter().sum::<u64>()))
+    });
+
+    group.bench_function("f64/Sum", |b| {
+        b.iter(|| black_box(black_box(&floats).iter().reduce_with::<Sum<f64>>()))
+    });
+    group.bench_function("f64/SumAssign", |b| {
+        b.iter(|| black_box(black_box(&floats).iter().reduce_with::<SumAssign<f64>>()))
+    });
+    group.bench_function("f64/Iterator::sum", |b| {
+        b.iter(|| black_box(black_box(&floats).iter().sum::<f64>()))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, sum);
+criterion_main!(benches);
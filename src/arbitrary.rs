@@ -0,0 +1,221 @@
+//! Implementations of [`proptest::arbitrary::Arbitrary`] for the result types of this crate's
+//! [`Reductor`](crate::Reductor)s, enabled by the `proptest` feature.
+//!
+//! Where a result type has an invariant that every reduction upholds, the generated values
+//! uphold it too, e.g. a generated [`MinMax`] always has `min <= max`.
+
+use std::num::NonZeroUsize;
+
+use proptest::{
+    arbitrary::{any, any_with, Arbitrary},
+    num,
+    strategy::{Map, Strategy},
+};
+
+use crate::{
+    ByteHistogram, Count, CountNonZero, Max, MaxF, Mean, Min, MinF, MinMax, MinMaxF, Product,
+    Reductors, SecondMax, SecondMin, Sum,
+};
+
+macro_rules! impl_arbitrary_newtype {
+    ($($ty:ident),+) => {
+        $(
+            impl<T> Arbitrary for $ty<T>
+            where
+                T: Arbitrary,
+            {
+                type Parameters = T::Parameters;
+                type Strategy = Map<T::Strategy, fn(T) -> Self>;
+
+                fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+                    any_with::<T>(args).prop_map($ty)
+                }
+            }
+        )+
+    };
+}
+
+impl_arbitrary_newtype!(Sum, Product, Min, Max, MinF, MaxF, Reductors);
+
+impl Arbitrary for Count {
+    type Parameters = ();
+    type Strategy = Map<num::usize::Any, fn(usize) -> Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<usize>().prop_map(Count)
+    }
+}
+
+impl Arbitrary for CountNonZero {
+    type Parameters = ();
+    type Strategy = Map<<NonZeroUsize as Arbitrary>::Strategy, fn(NonZeroUsize) -> Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<NonZeroUsize>().prop_map(CountNonZero)
+    }
+}
+
+impl<T, const DISTINCT: bool> Arbitrary for SecondMax<T, DISTINCT>
+where
+    T: Arbitrary,
+{
+    type Parameters = <Option<T> as Arbitrary>::Parameters;
+    type Strategy = Map<<Option<T> as Arbitrary>::Strategy, fn(Option<T>) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<Option<T>>(args).prop_map(SecondMax)
+    }
+}
+
+impl<T, const DISTINCT: bool> Arbitrary for SecondMin<T, DISTINCT>
+where
+    T: Arbitrary,
+{
+    type Parameters = <Option<T> as Arbitrary>::Parameters;
+    type Strategy = Map<<Option<T> as Arbitrary>::Strategy, fn(Option<T>) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<Option<T>>(args).prop_map(SecondMin)
+    }
+}
+
+/// Generated values always have `min <= max`.
+impl<T> Arbitrary for MinMax<T>
+where
+    T: Arbitrary + Ord,
+{
+    type Parameters = <(T, T) as Arbitrary>::Parameters;
+    type Strategy = Map<<(T, T) as Arbitrary>::Strategy, fn((T, T)) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<(T, T)>(args).prop_map(|(a, b)| {
+            if a <= b {
+                Self { min: a, max: b }
+            } else {
+                Self { min: b, max: a }
+            }
+        })
+    }
+}
+
+macro_rules! impl_arbitrary_float {
+    ($f:ident, $non_nan:ident) => {
+        fn $non_nan() -> num::$f::Any {
+            num::$f::POSITIVE
+                | num::$f::NEGATIVE
+                | num::$f::NORMAL
+                | num::$f::SUBNORMAL
+                | num::$f::ZERO
+                | num::$f::INFINITE
+        }
+
+        /// Generated values are never NaN.
+        impl Arbitrary for Mean<$f> {
+            type Parameters = ();
+            type Strategy = Map<num::$f::Any, fn($f) -> Self>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                $non_nan().prop_map(Mean)
+            }
+        }
+
+        /// Generated values are never NaN, and always have `min <= max`.
+        impl Arbitrary for MinMaxF<$f> {
+            type Parameters = ();
+            type Strategy = Map<(num::$f::Any, num::$f::Any), fn(($f, $f)) -> Self>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                ($non_nan(), $non_nan()).prop_map(|(a, b)| Self {
+                    min: a.min(b),
+                    max: a.max(b),
+                })
+            }
+        }
+    };
+}
+
+impl_arbitrary_float!(f32, non_nan_f32);
+impl_arbitrary_float!(f64, non_nan_f64);
+
+/// Generated values have a [`total`](ByteHistogram::total) that does not overflow a `u64`.
+impl Arbitrary for ByteHistogram {
+    type Parameters = ();
+    type Strategy = Map<proptest::collection::VecStrategy<num::u32::Any>, fn(Vec<u32>) -> Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        proptest::collection::vec(any::<u32>(), 256).prop_map(|counts| {
+            let mut hist = Self::default();
+            hist.0
+                .iter_mut()
+                .zip(counts)
+                .for_each(|(bin, count)| *bin = count.into());
+            hist
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::Reduce;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_min_max_invariant(min_max in any::<MinMax<i32>>()) {
+            prop_assert!(min_max.min <= min_max.max);
+
+            let reduced = [min_max.min, min_max.max]
+                .into_iter()
+                .reduce_with::<Option<MinMax<i32>>>()
+                .unwrap();
+            prop_assert_eq!(reduced, min_max);
+        }
+
+        #[test]
+        fn test_min_max_merge_commutative(a in any::<MinMax<i32>>(), b in any::<MinMax<i32>>()) {
+            let merge = |a: MinMax<i32>, b: MinMax<i32>| {
+                [a.min, a.max, b.min, b.max]
+                    .into_iter()
+                    .reduce_with::<Option<MinMax<i32>>>()
+                    .unwrap()
+            };
+
+            prop_assert_eq!(merge(a, b), merge(b, a));
+        }
+
+        #[test]
+        fn test_min_max_f_invariant(min_max in any::<MinMaxF<f64>>()) {
+            prop_assert!(min_max.min <= min_max.max);
+        }
+
+        #[test]
+        fn test_mean_not_nan(Mean(mean) in any::<Mean<f32>>()) {
+            prop_assert!(!mean.is_nan());
+        }
+
+        #[test]
+        fn test_count_non_zero(CountNonZero(count) in any::<CountNonZero>()) {
+            prop_assert!(count.get() > 0);
+        }
+
+        #[test]
+        fn test_reductors(
+            Reductors((Sum(sum), Count(count))) in any::<Reductors<(Sum<u8>, Count)>>(),
+        ) {
+            let Reductors((Sum::<u64>(reduced_sum), Count(reduced_count))) =
+                std::iter::repeat_n(u64::from(sum), count % 1000).reduce_with();
+
+            prop_assert_eq!(reduced_sum, u64::from(sum) * (count % 1000) as u64);
+            prop_assert_eq!(reduced_count, count % 1000);
+        }
+
+        #[test]
+        fn test_byte_histogram_entropy(hist in any::<ByteHistogram>()) {
+            let entropy = hist.entropy();
+            prop_assert!((0. ..=8. + 1e-9).contains(&entropy));
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Reducing [`arrow`](https://docs.rs/arrow) arrays with [`Reductor`]s, enabled by the `arrow` feature.
+
+use arrow_array::{types::ArrowPrimitiveType, Array, PrimitiveArray};
+
+use crate::{Reduce, Reductor};
+
+/// Reduce the non-null values of a [`PrimitiveArray`] with a [`Reductor`].
+///
+/// Instead of checking the validity of each value separately (like iterating
+/// over the array with [`PrimitiveArray::iter`] would), the validity bitmap is scanned
+/// for contiguous runs of valid values, and each run is reduced directly from the values buffer.
+///
+/// Returns `None` if the array is empty, or contains only nulls.
+///
+/// ```rust
+/// use arrow_array::Int32Array;
+/// use reductor::{arrow::reduce_array, MinMax, Sum, Reductors};
+///
+/// let array = Int32Array::from(vec![Some(3), None, Some(-1), Some(7), None]);
+///
+/// let Reductors((Sum::<i32>(sum), MinMax { min, max })) = reduce_array(&array).unwrap();
+///
+/// assert_eq!(sum, 9);
+/// assert_eq!((min, max), (-1, 7));
+/// ```
+pub fn reduce_array<R, T>(array: &PrimitiveArray<T>) -> Option<R>
+where
+    T: ArrowPrimitiveType,
+    R: Reductor<T::Native>,
+{
+    let values = array.values();
+
+    match array.nulls() {
+        None => values.iter().copied().reduce_with(),
+        Some(nulls) if nulls.null_count() == nulls.len() => None,
+        Some(nulls) => nulls
+            .valid_slices()
+            .flat_map(|(start, end)| values[start..end].iter().copied())
+            .reduce_with(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Float32Array, Int32Array, Int64Array};
+
+    use super::*;
+    use crate::{Mean, MinMax, Sum};
+
+    fn int_arrays() -> Vec<Int32Array> {
+        vec![
+            Int32Array::from(vec![4, -2, 9, 0, 13, 7]),
+            Int32Array::from(vec![
+                None,
+                Some(4),
+                None,
+                None,
+                Some(-2),
+                Some(9),
+                Some(1),
+                None,
+            ]),
+            Int32Array::from(
+                (0..1000)
+                    .map(|i| (i % 7 != 0).then_some(i * 3 - 500))
+                    .collect::<Vec<_>>(),
+            ),
+            Int32Array::from(vec![Some(1), None, Some(2)]).slice(1, 2),
+        ]
+    }
+
+    #[test]
+    fn test_reduce_array_matches_iter() {
+        for array in int_arrays() {
+            assert_eq!(
+                reduce_array::<Sum<i32>, _>(&array),
+                array.iter().flatten().reduce_with::<Option<Sum<i32>>>()
+            );
+            assert_eq!(
+                reduce_array::<MinMax<i32>, _>(&array),
+                array.iter().flatten().reduce_with::<Option<MinMax<i32>>>()
+            );
+            assert_eq!(
+                reduce_array::<Mean<f64>, _>(&array),
+                array.iter().flatten().reduce_with::<Option<Mean<f64>>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_reduce_array_float() {
+        let array = Float32Array::from(vec![Some(1.5), None, Some(2.5)]);
+        let Mean::<f64>(mean) = reduce_array(&array).unwrap();
+        assert_eq!(mean, 2.);
+    }
+
+    #[test]
+    fn test_reduce_array_empty() {
+        let all_null = Int64Array::from(vec![None, None, None]);
+        assert_eq!(reduce_array::<Sum<i64>, _>(&all_null), None);
+        assert_eq!(reduce_array::<MinMax<i64>, _>(&all_null), None);
+
+        let empty = Int64Array::from(Vec::<i64>::new());
+        assert_eq!(reduce_array::<Sum<i64>, _>(&empty), None);
+    }
+}
@@ -1,4 +1,6 @@
-use super::Reductor;
+use std::ops::ControlFlow;
+
+use super::{EmptyIterator, Reductor, SizedDefault};
 
 /// Allow reducing an [`Iterator`] with a [`Reductor`].
 pub trait Reduce: Iterator + Sized {
@@ -14,8 +16,64 @@ pub trait Reduce: Iterator + Sized {
         R::into_result(self.fold(state, R::reduce))
     }
 
+    /// Similar to [`reduce_with`](Reduce::reduce_with), but for [`Reductor`]s that require at
+    /// least one item (i.e. whose [`State`](Reductor::State) doesn't implement [`Default`]),
+    /// returning an [`EmptyIterator`] error if the iterator yielded no items.
+    ///
+    /// This is equivalent to `reduce_with::<Option<R>>().ok_or(EmptyIterator)`, and allows
+    /// propagating emptiness with `?`:
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    ///
+    /// use reductor::{EmptyIterator, MinMax, Reduce};
+    ///
+    /// fn spread(values: &[i32]) -> Result<i32, Box<dyn Error>> {
+    ///     let MinMax::<i32> { min, max } = values.iter().copied().reduce_with_result()?;
+    ///     Ok(max - min)
+    /// }
+    ///
+    /// assert_eq!(spread(&[4, -2, 7]).unwrap(), 9);
+    ///
+    /// let err = spread(&[]).unwrap_err();
+    /// assert!(err.is::<EmptyIterator>());
+    /// assert_eq!(err.to_string(), "cannot reduce an empty iterator");
+    /// ```
+    #[inline]
+    fn reduce_with_result<R>(self) -> Result<R, EmptyIterator>
+    where
+        R: Reductor<Self::Item>,
+    {
+        self.reduce_with()
+    }
+
+    /// Same as [`reduce_with`](Reduce::reduce_with), but creates the initial state with room for
+    /// the number of items that the iterator is known to yield (the lower bound of its
+    /// [`size_hint`](Iterator::size_hint)), using [`SizedDefault`].
+    ///
+    /// This avoids repeated reallocations when collecting the items of iterators whose length
+    /// is known, and doesn't affect the result.
+    #[inline]
+    fn reduce_with_hint<R>(self) -> R
+    where
+        R: Reductor<Self::Item>,
+        R::State: SizedDefault,
+    {
+        let state = R::State::default_with_capacity(self.size_hint().0);
+        R::into_result(self.fold(state, R::reduce))
+    }
+
     /// Similar to [`Iterator::fold`], but uses a generic implementation of [`Reductor`],
     /// instead of a function parameter, to supply the reduction logic.
+    ///
+    /// # Fold-only reductors
+    /// Some reductors are parameterized at runtime (e.g. by a predicate, a threshold or a random
+    /// number generator), and keep that parameter in their [`State`](Reductor::State), which is
+    /// created from it by passing it as `init`. Such reductors can only be used with `fold_with`:
+    /// their state doesn't implement [`Default`], so they can't be used with
+    /// [`reduce_with`](Reduce::reduce_with), and wrapping them in an [`Option`] to do so panics
+    /// as soon as an item is yielded, since [`Reductor::new`] has no parameter to create the
+    /// state with.
     #[inline]
     fn fold_with<R, I>(self, init: I) -> R
     where
@@ -24,6 +82,97 @@ pub trait Reduce: Iterator + Sized {
     {
         R::into_result(self.fold(init.into(), R::reduce))
     }
+
+    /// Similar to [`reduce_with`](Reduce::reduce_with), but stops consuming the iterator
+    /// as soon as `pred` returns `false` for the intermediate [`State`](Reductor::State).
+    ///
+    /// `pred` is called with the initial state before any item is consumed, and then
+    /// again with each new state after an item is reduced into it. Once it returns `false`,
+    /// no further items are consumed, and the state is turned into a result as-is.
+    ///
+    /// ```rust
+    /// use reductor::{Reduce, Sum, Count};
+    ///
+    /// let quota = 10;
+    /// let mut iter = [4u32, 3, 5, 2, 7].into_iter();
+    ///
+    /// let (Sum::<u32>(sum), Count(count)) = iter
+    ///     .by_ref()
+    ///     .map(|x| (x, ()))
+    ///     .reduce_while::<(Sum<u32>, Count)>(|&(sum, _)| sum <= quota);
+    ///
+    /// assert_eq!((sum, count), (12, 3));
+    /// assert_eq!(iter.next(), Some(2));
+    /// ```
+    #[inline]
+    fn reduce_while<R>(mut self, mut pred: impl FnMut(&R::State) -> bool) -> R
+    where
+        R: Reductor<Self::Item>,
+        R::State: Default,
+    {
+        let state = R::State::default();
+        if !pred(&state) {
+            return R::into_result(state);
+        }
+
+        let (ControlFlow::Continue(state) | ControlFlow::Break(state)) =
+            self.try_fold(state, |state, item| {
+                let state = R::reduce(state, item);
+                if pred(&state) {
+                    ControlFlow::Continue(state)
+                } else {
+                    ControlFlow::Break(state)
+                }
+            });
+
+        R::into_result(state)
+    }
 }
 
 impl<I> Reduce for I where I: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::{Count, Max, Sum};
+
+    #[test]
+    fn test_reduce_while_stops() {
+        let consumed = Cell::new(0);
+        let mut iter = (1..=10u32).inspect(|_| consumed.set(consumed.get() + 1));
+
+        let Sum::<u32>(sum) = iter.by_ref().reduce_while(|&sum| sum < 10);
+        assert_eq!(sum, 1 + 2 + 3 + 4);
+        assert_eq!(consumed.get(), 4);
+
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(consumed.get(), 5);
+    }
+
+    #[test]
+    fn test_reduce_while_initial_state() {
+        let consumed = Cell::new(0);
+        let iter = (1..=10u32).inspect(|_| consumed.set(consumed.get() + 1));
+
+        let Count(count) = iter.reduce_while::<Count>(|_| false);
+        assert_eq!(count, 0);
+        assert_eq!(consumed.get(), 0);
+    }
+
+    #[test]
+    fn test_reduce_with_result() {
+        let max = [3, 9, 2].into_iter().reduce_with_result::<Max<u8>>();
+        assert_eq!(max, Ok(Max(9)));
+
+        let max = std::iter::empty().reduce_with_result::<Max<u8>>();
+        assert_eq!(max, Err(EmptyIterator));
+    }
+
+    #[test]
+    fn test_reduce_while_exhausted() {
+        let Count(count) = (0..10).reduce_while::<Count>(|_| true);
+        assert_eq!(count, 10);
+    }
+}
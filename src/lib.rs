@@ -94,14 +94,38 @@
 //! assert_eq!(min, iter.clone().map(|(.., x)| x).min().unwrap());
 //! assert_eq!(max, iter.map(|(.., x)| x).max().unwrap());
 //! ```
+//!
+//! # Features
+//!
+//! - `arrow`: adds the `arrow` module, for reducing
+//!   [`arrow`](https://docs.rs/arrow) arrays directly.
+//! - `rand`: adds [`Reductor`]s that sample items randomly, e.g. `WeightedReservoir`.
+//! - `half`: adds support for the half-precision floats of the [`half`](https://docs.rs/half) crate,
+//!   in [`MinF`], [`MaxF`] and [`MinMaxF`], and the `WideSum` reductor for summing them.
+//!   [`Mean`] supports them regardless, e.g. via [`Into<f32>`].
+//! - `indexmap`: adds variants of the grouping reductors, such as `IndexedCollectMultiMap`, that
+//!   collect into an [`IndexMap`](https://docs.rs/indexmap), which iterates over keys in the order
+//!   they were first yielded.
+//! - `num-rational`: adds the `ExactMean` reductor, for computing the exact means of integers as
+//!   [`num-rational`](https://docs.rs/num-rational) ratios.
+//! - `proptest`: implements [`Arbitrary`](https://docs.rs/proptest) for the result types of the
+//!   standard [`Reductor`]s, generating values that uphold each type's invariants.
 
 #![warn(missing_docs)]
 
 mod reductor;
-pub use self::reductor::{Reductor, Reductors};
+pub use self::reductor::{EmptyIterator, MergeReductor, Reductor, Reductors, SizedDefault};
 
 mod iter;
 pub use self::iter::Reduce;
 
 pub mod reductors;
 pub use reductors::*;
+
+pub mod parallel;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "proptest")]
+mod arbitrary;
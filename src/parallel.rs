@@ -0,0 +1,119 @@
+//! Reducing slices in parallel, using only [`std::thread`].
+
+use std::{panic, thread};
+
+use crate::{MergeReductor, Reduce};
+
+/// Reduce a slice with a [`MergeReductor`], splitting it into contiguous chunks that are
+/// reduced on separate [scoped](thread::scope) threads.
+///
+/// Each chunk is reduced starting from a [default](Default) state (like
+/// [`reduce_with`](Reduce::reduce_with) does), and the partial states are then
+/// [merged](MergeReductor::merge) in order. Items are [cloned](Clone) out of the slice,
+/// so to avoid expensive clones, reduce a slice of references.
+///
+/// If the slice has fewer than `threshold` items, or `num_threads` is less than two,
+/// the slice is reduced sequentially on the calling thread instead.
+///
+/// For [`Reductor`](crate::Reductor)s whose merge is exact (e.g. integer [`Sum`](crate::Sum)s,
+/// [`Count`](crate::Count) or [`MinMax`](crate::MinMax)), the result is identical to reducing
+/// the slice sequentially.
+///
+/// # Panics
+/// If reducing any of the chunks panics, the panic is propagated to the caller.
+///
+/// ```rust
+/// use reductor::{parallel::par_reduce_slice, Count, Reductors, Sum};
+///
+/// let data: Vec<u64> = (0..100_000).collect();
+///
+/// let Reductors((Sum::<u64>(sum), Count(count))) = par_reduce_slice(&data, 4, 1024);
+///
+/// assert_eq!(sum, data.iter().sum());
+/// assert_eq!(count, data.len());
+/// ```
+pub fn par_reduce_slice<R, T>(slice: &[T], num_threads: usize, threshold: usize) -> R
+where
+    R: MergeReductor<T>,
+    R::State: Default + Send,
+    T: Clone + Sync,
+{
+    if num_threads < 2 || slice.len() < threshold {
+        return slice.iter().cloned().reduce_with();
+    }
+
+    let chunk_size = slice.len().div_ceil(num_threads).max(1);
+
+    let state = thread::scope(|scope| {
+        let handles: Vec<_> = slice
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| chunk.iter().cloned().fold(R::State::default(), R::reduce)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|err| panic::resume_unwind(err))
+            })
+            .reduce(R::merge)
+            .unwrap_or_default()
+    });
+
+    R::into_result(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Count, MinMax, Reductors, Sum};
+
+    fn data() -> Vec<i32> {
+        (0..10_007)
+            .map(|i: i32| i.wrapping_mul(7_919) % 1_000 - 500)
+            .collect()
+    }
+
+    #[test]
+    fn test_par_reduce_slice_matches_sequential() {
+        let data = data();
+        let unsigned: Vec<u64> = data.iter().map(|&x| x.unsigned_abs().into()).collect();
+
+        for num_threads in [2, 3, 8, 64] {
+            assert_eq!(
+                par_reduce_slice::<Sum<u64>, _>(&unsigned, num_threads, 0),
+                unsigned.iter().copied().reduce_with::<Sum<u64>>()
+            );
+            assert_eq!(
+                par_reduce_slice::<Count, _>(&data, num_threads, 0),
+                data.iter().copied().reduce_with::<Count>()
+            );
+            assert_eq!(
+                par_reduce_slice::<Option<MinMax<i32>>, _>(&data, num_threads, 0),
+                data.iter().copied().reduce_with::<Option<MinMax<i32>>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_par_reduce_slice_nested() {
+        let pairs: Vec<(u64, i32)> = data()
+            .into_iter()
+            .map(|x| (x.unsigned_abs().into(), x))
+            .collect();
+
+        type Nested = (Sum<u64>, Reductors<(Count, Option<MinMax<i32>>)>);
+
+        assert_eq!(
+            par_reduce_slice::<Nested, _>(&pairs, 5, 0),
+            pairs.iter().copied().reduce_with::<Nested>()
+        );
+    }
+
+    #[test]
+    fn test_par_reduce_slice_small() {
+        assert_eq!(par_reduce_slice::<Option<MinMax<i32>>, _>(&[], 4, 0), None);
+        assert_eq!(par_reduce_slice::<Sum<u32>, _>(&[1, 2, 3], 4, 1024), Sum(6));
+    }
+}
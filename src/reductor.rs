@@ -1,3 +1,10 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+    hash::{BuildHasher, Hash},
+};
+
 /// Reductors are types that implement the logic for [`fold`](Iterator::fold)ing an iterator
 /// into a single result.
 ///
@@ -62,6 +69,83 @@ pub trait Reductor<A>: Sized {
     fn into_result(state: Self::State) -> Self;
 }
 
+/// [`Reductor`]s whose intermediate [`State`](Reductor::State)s can be merged together.
+///
+/// This allows reducing separate parts of an iterator independently (e.g. on separate threads),
+/// and then combining the partial states into the state that reducing the whole iterator would
+/// have resulted in.
+///
+/// ```rust
+/// use reductor::{MergeReductor, Reductor, MinMax};
+///
+/// type R = MinMax<i32>;
+///
+/// let left = [3, -1, 7].into_iter().fold(R::new(5), R::reduce);
+/// let right = [10, 4].into_iter().fold(R::new(0), R::reduce);
+///
+/// let MinMax { min, max } = R::into_result(R::merge(left, right));
+/// assert_eq!((min, max), (-1, 10));
+/// ```
+pub trait MergeReductor<A>: Reductor<A> {
+    /// Merge the state resulting from reducing some items, with the state resulting from reducing
+    /// the items that immediately followed them.
+    fn merge(left: Self::State, right: Self::State) -> Self::State;
+}
+
+/// [`State`](Reductor::State)s that can preallocate room for a known number of items.
+///
+/// This is implemented by the states of collecting reductors, so that
+/// [`reduce_with_hint`](crate::Reduce::reduce_with_hint) can avoid repeated reallocations when
+/// the length of the iterator is known in advance. Preallocating must not affect the result.
+///
+/// ```rust
+/// use reductor::{Collect, Reduce};
+///
+/// let Collect::<Vec<_>>(squares) = (0..1000).map(|x| x * x).reduce_with_hint();
+/// assert_eq!(squares.capacity(), 1000);
+/// ```
+pub trait SizedDefault: Default {
+    /// Create the default state, with room for (at least) `capacity` items.
+    fn default_with_capacity(capacity: usize) -> Self;
+}
+
+impl<T> SizedDefault for Vec<T> {
+    #[inline]
+    fn default_with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl SizedDefault for String {
+    #[inline]
+    fn default_with_capacity(capacity: usize) -> Self {
+        // every item (e.g. a `char` or a `&str`) is at least one byte, unless it's empty
+        Self::with_capacity(capacity)
+    }
+}
+
+impl<T, S> SizedDefault for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn default_with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, S::default())
+    }
+}
+
+impl<K, V, S> SizedDefault for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn default_with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, S::default())
+    }
+}
+
 /// Wrapping a [`Reductor`] in an [`Option`] allows using [`reduce_with`](crate::Reduce::reduce_with)
 /// with a `Reductor` whose [`State`](Reductor::State) does not implement [`Default`].
 ///
@@ -96,6 +180,73 @@ where
     }
 }
 
+impl<R, A> MergeReductor<A> for Option<R>
+where
+    R: MergeReductor<A>,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        match (left, right) {
+            (Some(left), Some(right)) => Some(R::merge(left, right)),
+            (left, right) => left.or(right),
+        }
+    }
+}
+
+/// Error returned when reducing an empty iterator with a [`Reductor`] that requires at least
+/// one item, see [`reduce_with_result`](crate::Reduce::reduce_with_result).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmptyIterator;
+
+impl fmt::Display for EmptyIterator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cannot reduce an empty iterator")
+    }
+}
+
+impl Error for EmptyIterator {}
+
+/// Like wrapping in an [`Option`], wrapping a [`Reductor`] in a [`Result`] allows using
+/// [`reduce_with`](crate::Reduce::reduce_with) with a `Reductor` whose [`State`](Reductor::State)
+/// does not implement [`Default`], with an empty iterator resulting in an [`EmptyIterator`] error.
+///
+/// ```rust
+/// # use reductor::{EmptyIterator, Reduce, Max, Sum};
+/// let (max, Sum::<u32>(sum)) = [(3, 1), (5, 2)]
+///     .into_iter()
+///     .reduce_with::<(Result<Max<u32>, _>, _)>();
+/// assert_eq!((max, sum), (Ok(Max(5)), 3));
+///
+/// let max = std::iter::empty().reduce_with::<Result<Max<u32>, _>>();
+/// assert_eq!(max, Err(EmptyIterator));
+/// ```
+impl<R, A> Reductor<A> for Result<R, EmptyIterator>
+where
+    R: Reductor<A>,
+{
+    type State = Option<R::State>;
+
+    fn new(item: A) -> Self::State {
+        <Option<R> as Reductor<A>>::new(item)
+    }
+
+    fn reduce(state: Self::State, item: A) -> Self::State {
+        <Option<R> as Reductor<A>>::reduce(state, item)
+    }
+
+    fn into_result(state: Self::State) -> Self {
+        <Option<R> as Reductor<A>>::into_result(state).ok_or(EmptyIterator)
+    }
+}
+
+impl<R, A> MergeReductor<A> for Result<R, EmptyIterator>
+where
+    R: MergeReductor<A>,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        <Option<R> as MergeReductor<A>>::merge(left, right)
+    }
+}
+
 /// This struct can be used to run a tuple of [`Reductor`]s on a single value,
 /// by [cloning](`Clone`) every element yielded, and updating all `Reductor`s'
 /// states in each iteration.
@@ -127,6 +278,15 @@ mod agg_impls {
                     ($($R::into_result(state.$Idx)),+)
                 }
             }
+
+            impl<$($A),+, $($R),+> MergeReductor<($($A),+)> for ($($R),+)
+            where
+                $($R: MergeReductor<$A>),+
+            {
+                fn merge(left: Self::State, right: Self::State) -> Self::State {
+                    ($($R::merge(left.$Idx, right.$Idx)),+)
+                }
+            }
         };
     }
 
@@ -176,9 +336,37 @@ mod agg_impls {
                     Self(($($R::into_result(state.$Idx)),+))
                 }
             }
+
+            impl<A, $($R),+> MergeReductor<A> for Reductors<($($R),+)>
+            where
+                A: Clone,
+                $($R: MergeReductor<A>),+
+            {
+                fn merge(left: Self::State, right: Self::State) -> Self::State {
+                    ($($R::merge(left.$Idx, right.$Idx)),+)
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_sized_default_for_tuple {
+        ($($S:ident),+) => {
+            impl<$($S),+> SizedDefault for ($($S),+)
+            where
+                $($S: SizedDefault),+
+            {
+                fn default_with_capacity(capacity: usize) -> Self {
+                    ($($S::default_with_capacity(capacity)),+)
+                }
+            }
         };
     }
 
+    impl_sized_default_for_tuple!(S1, S2);
+    impl_sized_default_for_tuple!(S1, S2, S3);
+    impl_sized_default_for_tuple!(S1, S2, S3, S4);
+    impl_sized_default_for_tuple!(S1, S2, S3, S4, S5);
+
     impl_reductor_for_reductors!([R1, 0], [R2, 1]);
     impl_reductor_for_reductors!([R1, 0], [R2, 1], [R3, 2]);
     impl_reductor_for_reductors!([R1, 0], [R2, 1], [R3, 2], [R4, 3]);
@@ -0,0 +1,107 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that checks whether all items yielded by an iterator are equal to each other.
+///
+/// Results in `Some(value)` if the iterator yielded at least one item, and all items were equal
+/// to the first one (which is retained as `value`), or `None` if the iterator was empty,
+/// or yielded any item that is not equal to the first one.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, AllEqual, Count};
+///
+/// let rows = [("eu-west", 3), ("eu-west", 5), ("eu-west", 1)];
+///
+/// let (AllEqual(region), Count(count)) = rows.into_iter().reduce_with();
+///
+/// assert_eq!(region, Some("eu-west"));
+/// assert_eq!(count, 3);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AllEqual<T>(pub Option<T>);
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum State<T> {
+    #[default]
+    Empty,
+    Equal(T),
+    Mixed,
+}
+
+impl<T> Reductor<T> for AllEqual<T>
+where
+    T: PartialEq,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        State::Equal(item)
+    }
+
+    #[inline]
+    fn reduce(state: Self::State, item: T) -> Self::State {
+        match state {
+            State::Empty => Self::new(item),
+            State::Equal(first) if first == item => State::Equal(first),
+            State::Equal(_) | State::Mixed => State::Mixed,
+        }
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(match state {
+            State::Equal(first) => Some(first),
+            State::Empty | State::Mixed => None,
+        })
+    }
+}
+
+impl<T> MergeReductor<T> for AllEqual<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        match (left, right) {
+            (State::Empty, state) | (state, State::Empty) => state,
+            (State::Equal(left), State::Equal(right)) if left == right => State::Equal(left),
+            _ => State::Mixed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_all_equal() {
+        let AllEqual(value) = [4, 4, 4].into_iter().reduce_with();
+        assert_eq!(value, Some(4));
+
+        let AllEqual(value) = ["a"].into_iter().reduce_with();
+        assert_eq!(value, Some("a"));
+    }
+
+    #[test]
+    fn test_all_equal_mixed() {
+        let AllEqual(value) = [4, 4, 5, 4].into_iter().reduce_with();
+        assert_eq!(value, None);
+
+        let AllEqual(value) = [f64::NAN].into_iter().reduce_with();
+        assert!(value.unwrap().is_nan());
+
+        let AllEqual(value) = [f64::NAN, f64::NAN].into_iter().reduce_with();
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn test_all_equal_empty() {
+        let AllEqual(value) = std::iter::empty::<u8>().reduce_with();
+        assert_eq!(value, None);
+    }
+}
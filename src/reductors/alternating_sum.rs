@@ -0,0 +1,146 @@
+use std::{
+    iter::{self, empty, once},
+    ops::{Add, Sub},
+};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the alternating sum `a0 - a1 + a2 - a3 + ...` of items yielded by an iterator.
+///
+/// The sign convention is that the first item is always added (i.e. items at even positions
+/// are added, and items at odd positions are subtracted). An empty iterator reduces to zero.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, AlternatingSum};
+///
+/// let AlternatingSum::<i32>(sum) = [1, 2, 3, 4, 5].into_iter().reduce_with();
+/// assert_eq!(sum, 1 - 2 + 3 - 4 + 5);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AlternatingSum<T>(pub T);
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    sum: T,
+    odd: bool,
+}
+
+impl<T> From<T> for State<T> {
+    fn from(sum: T) -> Self {
+        Self { sum, odd: false }
+    }
+}
+
+impl<T> Default for State<T>
+where
+    T: iter::Sum,
+{
+    fn default() -> Self {
+        empty::<T>().sum::<T>().into()
+    }
+}
+
+impl<T> Default for AlternatingSum<T>
+where
+    T: iter::Sum,
+{
+    #[inline]
+    fn default() -> Self {
+        Self(State::<T>::default().sum)
+    }
+}
+
+impl<A, T> Reductor<A> for AlternatingSum<T>
+where
+    T: iter::Sum<A> + Add<Output = T> + Sub<Output = T>,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        State {
+            sum: once(item).sum(),
+            odd: true,
+        }
+    }
+
+    #[inline]
+    fn reduce(State { sum, odd }: Self::State, item: A) -> Self::State {
+        let item: T = once(item).sum();
+
+        State {
+            sum: if odd { sum - item } else { sum + item },
+            odd: !odd,
+        }
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state.sum)
+    }
+}
+
+impl<A, T> MergeReductor<A> for AlternatingSum<T>
+where
+    T: iter::Sum<A> + Add<Output = T> + Sub<Output = T>,
+{
+    #[inline]
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        State {
+            sum: if left.odd {
+                left.sum - right.sum
+            } else {
+                left.sum + right.sum
+            },
+            odd: left.odd != right.odd,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_alternating_sum() {
+        let AlternatingSum::<i64>(sum) = (1..=10).reduce_with();
+        assert_eq!(sum, -5);
+
+        let AlternatingSum::<i64>(sum) = (1..=11).reduce_with();
+        assert_eq!(sum, 6);
+
+        let AlternatingSum::<f64>(sum) = [1.5, 0.5, 2.].iter().reduce_with();
+        assert_eq!(sum, 3.);
+
+        let AlternatingSum::<i64>(sum) = std::iter::empty::<i64>().reduce_with();
+        assert_eq!(sum, 0);
+    }
+
+    #[test]
+    fn test_alternating_sum_fold() {
+        let AlternatingSum::<i64>(sum) = [1, 2, 3].into_iter().fold_with(10);
+        assert_eq!(sum, 10 + 1 - 2 + 3);
+    }
+
+    #[test]
+    fn test_alternating_sum_merge() {
+        type R = AlternatingSum<i64>;
+
+        let data = [3, 8, -2, 5, 11, 4, 7];
+        let AlternatingSum(expected) = data.into_iter().reduce_with::<R>();
+
+        for split in 0..=data.len() {
+            let (left, right) = data.split_at(split);
+            let left = left.iter().copied().fold(Default::default(), R::reduce);
+            let right = right.iter().copied().fold(Default::default(), R::reduce);
+
+            let AlternatingSum(sum) =
+                <R as Reductor<i64>>::into_result(<R as MergeReductor<i64>>::merge(left, right));
+            assert_eq!(sum, expected);
+        }
+    }
+}
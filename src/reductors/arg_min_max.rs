@@ -0,0 +1,190 @@
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    index: usize,
+    value: T,
+    /// Number of items reduced so far, i.e. the index of the next item.
+    len: usize,
+}
+
+impl<T> State<T> {
+    #[inline]
+    fn new(value: T) -> Self {
+        Self {
+            index: 0,
+            value,
+            len: 1,
+        }
+    }
+
+    /// Replaces the retained item with `item` if `replace(&item, &retained)`.
+    #[inline]
+    fn reduce(mut self, item: T, replace: impl FnOnce(&T, &T) -> bool) -> Self {
+        if replace(&item, &self.value) {
+            self.index = self.len;
+            self.value = item;
+        }
+        self.len += 1;
+        self
+    }
+
+    /// Merges the state of the items following `self`'s, so that the result retains the
+    /// earlier item on ties.
+    #[inline]
+    fn merge(mut self, right: Self, replace: impl FnOnce(&T, &T) -> bool) -> Self {
+        if replace(&right.value, &self.value) {
+            self.index = self.len + right.index;
+            self.value = right.value;
+        }
+        self.len += right.len;
+        self
+    }
+}
+
+macro_rules! impl_arg_min_max {
+    ($name:ident, $replace:expr) => {
+        impl<T> Reductor<T> for $name<T>
+        where
+            T: Ord,
+        {
+            type State = NonEmptyState<State<T>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                NonEmptyState(State::new(item))
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(state): Self::State, item: T) -> Self::State {
+                NonEmptyState(state.reduce(item, $replace))
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(State { index, value, .. }): Self::State) -> Self {
+                Self { index, value }
+            }
+        }
+
+        impl<T> MergeReductor<T> for $name<T>
+        where
+            T: Ord,
+        {
+            #[inline]
+            fn merge(
+                NonEmptyState(left): Self::State,
+                NonEmptyState(right): Self::State,
+            ) -> Self::State {
+                NonEmptyState(left.merge(right, $replace))
+            }
+        }
+    };
+}
+
+/// Reductor that retains the minimum value yielded by an iterator (similarly to [`Iterator::min`]),
+/// along with its index.
+///
+/// If several items are equally minimal, the first one is retained, like [`Iterator::min`].
+///
+/// # Examples
+/// ```rust
+/// use reductor::{ArgMin, Reduce};
+///
+/// let ArgMin { index, value } = [3, 1, 4, 1, 5].into_iter().reduce_with::<Option<_>>().unwrap();
+/// assert_eq!((index, value), (1, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArgMin<T> {
+    /// Index of the minimum value.
+    pub index: usize,
+    /// Minimum value yielded by iterator.
+    pub value: T,
+}
+
+impl_arg_min_max!(ArgMin, |item: &T, min: &T| item < min);
+
+/// Reductor that retains the maximum value yielded by an iterator (similarly to [`Iterator::max`]),
+/// along with its index.
+///
+/// If several items are equally maximal, the *first* one is retained, unlike [`Iterator::max`]
+/// (which returns the last one), so that ties are resolved the same way as [`ArgMin`].
+///
+/// # Examples
+/// ```rust
+/// use reductor::{ArgMax, Reduce};
+///
+/// let ArgMax { index, value } = [3, 5, 4, 5, 1].into_iter().reduce_with::<Option<_>>().unwrap();
+/// assert_eq!((index, value), (1, 5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArgMax<T> {
+    /// Index of the maximum value.
+    pub index: usize,
+    /// Maximum value yielded by iterator.
+    pub value: T,
+}
+
+impl_arg_min_max!(ArgMax, |item: &T, max: &T| item > max);
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Reduce, Reductors};
+
+    use super::*;
+
+    #[test]
+    fn test_arg_min_max() {
+        let data = [7, 2, 9, 2, 9, 0, 11, 11, 0];
+
+        let Reductors((
+            ArgMin { index, value },
+            ArgMax {
+                index: max_index,
+                value: max,
+            },
+        )) = data.iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!((index, *value), (5, 0));
+        assert_eq!((max_index, *max), (6, 11));
+
+        for threads in 2..=5 {
+            let merged: Option<ArgMin<i32>> = par_reduce_slice(&data, threads, 0);
+            assert_eq!(merged, Some(ArgMin { index: 5, value: 0 }));
+            let merged: Option<ArgMax<i32>> = par_reduce_slice(&data, threads, 0);
+            assert_eq!(
+                merged,
+                Some(ArgMax {
+                    index: 6,
+                    value: 11
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_arg_min_max_ties() {
+        let data = ["b", "a", "c", "a", "c"];
+
+        let min: ArgMin<_> = data.into_iter().reduce_with::<Option<_>>().unwrap();
+        let max: ArgMax<_> = data.into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(
+            min,
+            ArgMin {
+                index: 1,
+                value: "a"
+            }
+        );
+        assert_eq!(
+            max,
+            ArgMax {
+                index: 2,
+                value: "c"
+            }
+        );
+
+        assert_eq!(
+            std::iter::empty::<u8>().reduce_with::<Option<ArgMin<_>>>(),
+            None
+        );
+    }
+}
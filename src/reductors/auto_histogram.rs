@@ -0,0 +1,156 @@
+use crate::{Reductor, SizedDefault};
+
+/// Reductor that builds a histogram of equal-width bins spanning the range of values yielded
+/// by an iterator, without requiring the range to be known in advance.
+///
+/// This reductor collects all yielded values, and only computes the range and bins the values
+/// when the iterator is exhausted, so it uses `O(n)` memory. NaN values are ignored.
+///
+/// The number of bins defaults to `10`, but can be set by calling [`fold_with`](crate::Reduce::fold_with)
+/// with the desired number of bins (zero is treated as one). All bins are half-open, except for
+/// the last bin, which also includes the maximum value. If all values are equal, the range is
+/// widened to `value - 0.5..value + 0.5`. An iterator that yields no values reduces to
+/// a histogram with no edges and no counts.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, AutoHistogram};
+///
+/// let AutoHistogram::<f64> { edges, counts } = [1, 2, 2, 3, 3, 3, 5].into_iter().fold_with(4);
+///
+/// assert_eq!(edges, [1., 2., 3., 4., 5.]);
+/// assert_eq!(counts, [1, 2, 3, 1]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoHistogram<F> {
+    /// Bin edges, one more than the number of bins, in ascending order.
+    pub edges: Vec<F>,
+    /// Number of values in each bin.
+    pub counts: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct State<F> {
+    bins: usize,
+    values: Vec<F>,
+}
+
+impl<F> From<usize> for State<F> {
+    fn from(bins: usize) -> Self {
+        Self {
+            bins: bins.max(1),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<F> Default for State<F> {
+    fn default() -> Self {
+        Self::from(10)
+    }
+}
+
+impl<F> SizedDefault for State<F> {
+    fn default_with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            ..Self::default()
+        }
+    }
+}
+
+macro_rules! impl_auto_histogram {
+    ($f:ty) => {
+        impl<T> Reductor<T> for AutoHistogram<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = State<$f>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, item: T) -> Self::State {
+                let item = item.into();
+                if !item.is_nan() {
+                    state.values.push(item);
+                }
+                state
+            }
+
+            fn into_result(State { bins, values }: Self::State) -> Self {
+                let Some((mut min, mut max)) = values
+                    .iter()
+                    .map(|&v| (v, v))
+                    .reduce(|(min, max), (v, _)| (min.min(v), max.max(v)))
+                else {
+                    return Self {
+                        edges: Vec::new(),
+                        counts: Vec::new(),
+                    };
+                };
+
+                if min == max {
+                    min -= 0.5;
+                    max += 0.5;
+                }
+
+                let range = max - min;
+                let edges = (0..=bins)
+                    .map(|i| min + range * (i as $f / bins as $f))
+                    .collect();
+
+                let mut counts = vec![0; bins];
+                for v in values {
+                    let bin = (((v - min) / range) * bins as $f) as usize;
+                    counts[bin.min(bins - 1)] += 1;
+                }
+
+                Self { edges, counts }
+            }
+        }
+    };
+}
+
+impl_auto_histogram!(f32);
+impl_auto_histogram!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_auto_histogram() {
+        let AutoHistogram::<f64> { edges, counts } = [0.5, -1., 3., 0., 2.9, f64::NAN, 1.]
+            .into_iter()
+            .fold_with(2);
+
+        assert_eq!(edges, [-1., 1., 3.]);
+        assert_eq!(counts, [3, 3]);
+    }
+
+    #[test]
+    fn test_auto_histogram_default_bins() {
+        let AutoHistogram::<f32> { edges, counts } = (0..100u8).reduce_with();
+
+        assert_eq!(edges.len(), 11);
+        assert_eq!(counts, [10; 10]);
+        assert_eq!(counts.iter().sum::<usize>(), 100);
+    }
+
+    #[test]
+    fn test_auto_histogram_degenerate() {
+        let AutoHistogram::<f64> { edges, counts } = [2., 2., 2.].into_iter().fold_with(2);
+        assert_eq!(edges, [1.5, 2., 2.5]);
+        assert_eq!(counts, [0, 3]);
+
+        let AutoHistogram::<f64> { edges, counts } = std::iter::empty::<f64>().reduce_with();
+        assert!(edges.is_empty());
+        assert!(counts.is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+use std::io::Write;
+
+use crate::{MergeReductor, Reductor};
+
+/// Numbers whose leading (most significant) decimal digit can be counted by [`BenfordDigits`],
+/// implemented for all primitive integer and float types.
+pub trait LeadingDigit {
+    /// The leading decimal digit (between `1` and `9`) of `self`, or `None` if `self` isn't
+    /// positive (i.e. zero, negative, or for floats, NaN or infinite).
+    fn leading_digit(&self) -> Option<u8>;
+}
+
+macro_rules! impl_leading_digit_int {
+    ($($t:ty),*) => {
+        $(
+            impl LeadingDigit for $t {
+                #[allow(unused_comparisons)] // unsigned integers are never negative
+                fn leading_digit(&self) -> Option<u8> {
+                    if *self <= 0 {
+                        return None;
+                    }
+                    let mut n = *self;
+                    while n >= 10 {
+                        n /= 10;
+                    }
+                    Some(n as u8)
+                }
+            }
+        )*
+    };
+}
+
+impl_leading_digit_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_leading_digit_float {
+    ($($f:ty),*) => {
+        $(
+            impl LeadingDigit for $f {
+                fn leading_digit(&self) -> Option<u8> {
+                    if !self.is_finite() || *self <= 0. {
+                        return None;
+                    }
+                    // Scientific notation of the shortest representation that round-trips, which is
+                    // exact where computing `x / 10^floor(log10(x))` suffers from rounding errors
+                    // (e.g. `0.003` might yield `2.9999…`).
+                    let mut buf = [0u8; 1];
+                    // Only the first byte fits, which is all we need, so the "error" is expected.
+                    let _ = write!(&mut buf[..], "{self:e}");
+                    Some(buf[0] - b'0')
+                }
+            }
+        )*
+    };
+}
+
+impl_leading_digit_float!(f32, f64);
+
+/// Expected frequency of each leading digit `d` under Benford's law, i.e. `log10(1 + 1/d)`.
+fn benford_frequency(digit: usize) -> f64 {
+    (1. + 1. / digit as f64).log10()
+}
+
+/// Reductor that counts the leading decimal digits of the numbers yielded by an iterator, for
+/// checking whether they follow [Benford's law].
+///
+/// Items can be any primitive integer or float (see [`LeadingDigit`]). Items that aren't
+/// positive (zeros, negatives, and non-finite floats) have no meaningful leading digit,
+/// so they are only counted in `skipped`.
+///
+/// [Benford's law]: https://en.wikipedia.org/wiki/Benford%27s_law
+///
+/// # Examples
+/// ```rust
+/// use reductor::{BenfordDigits, Reduce};
+///
+/// let amounts = [120.5, 1_999., 14.99, 310., 0., 2.75, -18.];
+///
+/// let digits: BenfordDigits = amounts.into_iter().reduce_with();
+///
+/// assert_eq!(digits.counts, [3, 1, 1, 0, 0, 0, 0, 0, 0]);
+/// assert_eq!(digits.skipped, 2);
+/// assert_eq!(digits.frequencies()[0], 0.6);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BenfordDigits {
+    /// Number of items whose leading digit is `1` through `9`, respectively.
+    pub counts: [usize; 9],
+    /// Number of items that were skipped, since they aren't positive.
+    pub skipped: usize,
+}
+
+impl BenfordDigits {
+    /// Total number of counted (i.e. not skipped) items.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// Fraction of counted items whose leading digit is `1` through `9`, respectively.
+    ///
+    /// All frequencies are zero if no items were counted.
+    pub fn frequencies(&self) -> [f64; 9] {
+        let total = self.total().max(1) as f64;
+        self.counts.map(|count| count as f64 / total)
+    }
+
+    /// [Pearson's chi-squared statistic] of the counts, against the counts expected under
+    /// Benford's law.
+    ///
+    /// The statistic has 8 degrees of freedom, so e.g. values above `20.09` reject the hypothesis
+    /// that the digits follow Benford's law with 99% confidence. Returns `0.0` if no items were
+    /// counted.
+    ///
+    /// [Pearson's chi-squared statistic]: https://en.wikipedia.org/wiki/Pearson%27s_chi-squared_test
+    pub fn chi_squared(&self) -> f64 {
+        let total = self.total() as f64;
+        if total == 0. {
+            return 0.;
+        }
+
+        self.counts
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| {
+                let expected = total * benford_frequency(i + 1);
+                (count as f64 - expected).powi(2) / expected
+            })
+            .sum()
+    }
+}
+
+impl<T> Reductor<T> for BenfordDigits
+where
+    T: LeadingDigit,
+{
+    type State = Self;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        Self::reduce(Self::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        match item.leading_digit() {
+            Some(digit) => state.counts[usize::from(digit) - 1] += 1,
+            None => state.skipped += 1,
+        }
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        state
+    }
+}
+
+impl<T> MergeReductor<T> for BenfordDigits
+where
+    T: LeadingDigit,
+{
+    fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+        for (left, right) in left.counts.iter_mut().zip(right.counts) {
+            *left += right;
+        }
+        left.skipped += right.skipped;
+        left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_leading_digit() {
+        assert_eq!(0.003.leading_digit(), Some(3));
+        assert_eq!(42.leading_digit(), Some(4));
+        assert_eq!(9.9e17.leading_digit(), Some(9));
+        assert_eq!(990_000_000_000_000_000_u64.leading_digit(), Some(9));
+        assert_eq!(0.3_f32.leading_digit(), Some(3));
+        assert_eq!(1e-300.leading_digit(), Some(1));
+        assert_eq!(f64::MIN_POSITIVE.leading_digit(), Some(2));
+        assert_eq!(u128::MAX.leading_digit(), Some(3));
+        assert_eq!(1000.0.leading_digit(), Some(1));
+        assert_eq!(7_u8.leading_digit(), Some(7));
+
+        assert_eq!(0.leading_digit(), None);
+        assert_eq!((-42).leading_digit(), None);
+        assert_eq!((-0.0).leading_digit(), None);
+        assert_eq!(f64::NAN.leading_digit(), None);
+        assert_eq!(f32::INFINITY.leading_digit(), None);
+    }
+
+    #[test]
+    fn test_benford_digits_follows_law() {
+        // spread evenly in log-space over 5 orders of magnitude
+        let digits: BenfordDigits = (0..10_000)
+            .map(|i| 10f64.powf(f64::from(i) / 2_000.))
+            .reduce_with();
+
+        assert_eq!(digits.total(), 10_000);
+        assert_eq!(digits.skipped, 0);
+        for (i, frequency) in digits.frequencies().into_iter().enumerate() {
+            assert!((frequency - benford_frequency(i + 1)).abs() < 1e-3);
+        }
+        assert!(digits.chi_squared() < 1., "{}", digits.chi_squared());
+    }
+
+    #[test]
+    fn test_benford_digits_uniform() {
+        let digits: BenfordDigits = (-10..10_000).reduce_with();
+
+        assert_eq!(digits.counts, [1111; 9]);
+        assert_eq!(digits.skipped, 11);
+        assert!(digits.chi_squared() > 1000., "{}", digits.chi_squared());
+    }
+
+    #[test]
+    fn test_benford_digits_empty() {
+        let digits: BenfordDigits = [0., -1., f64::NAN].into_iter().reduce_with();
+        assert_eq!(digits.total(), 0);
+        assert_eq!(digits.skipped, 3);
+        assert_eq!(digits.frequencies(), [0.; 9]);
+        assert_eq!(digits.chi_squared(), 0.);
+    }
+}
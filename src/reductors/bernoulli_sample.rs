@@ -0,0 +1,139 @@
+use std::marker::PhantomData;
+
+use rand::Rng;
+
+use crate::Reductor;
+
+/// Reductor that independently keeps each item yielded by an iterator with probability `p`,
+/// while counting the total number of items seen.
+///
+/// Unlike [`WeightedReservoir`](crate::WeightedReservoir), the number of sampled items isn't
+/// bounded, but sampling is cheaper and the sampled items retain their original order.
+///
+/// The random number generator and `p` must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with) with an `(rng, p)` tuple, so that sampling can be
+/// made deterministic by using a seeded generator.
+///
+/// Requires the `rand` feature.
+///
+/// # Panics
+/// Panics if `p` is not in the `[0, 1]` range.
+///
+/// Panics if reduced without an RNG (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use rand::{rngs::SmallRng, SeedableRng};
+/// use reductor::{BernoulliSample, Reduce, Reductors, Sum};
+///
+/// let latencies_ms = (0..1_000u64).map(|i| 50 + i % 17);
+///
+/// let Reductors((BernoulliSample { items, seen, .. }, Sum::<u64>(total))) = latencies_ms
+///     .fold_with(((SmallRng::seed_from_u64(7), 0.05).into(), 0));
+///
+/// assert_eq!(seen, 1_000);
+/// assert!(items.len() < seen);
+/// assert!(total > items.iter().sum::<u64>());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BernoulliSample<T, R> {
+    /// Sampled items, in the order they were yielded by the iterator.
+    pub items: Vec<T>,
+    /// Total number of items yielded by the iterator.
+    pub seen: usize,
+    rng: PhantomData<R>,
+}
+
+#[derive(Debug, Clone)]
+pub struct State<T, R> {
+    rng: R,
+    p: f64,
+    items: Vec<T>,
+    seen: usize,
+}
+
+impl<T, R> From<(R, f64)> for State<T, R> {
+    fn from((rng, p): (R, f64)) -> Self {
+        assert!(
+            (0. ..=1.).contains(&p),
+            "sampling probability must be in the [0, 1] range, got {p}"
+        );
+
+        Self {
+            rng,
+            p,
+            items: Vec::new(),
+            seen: 0,
+        }
+    }
+}
+
+impl<T, R> Reductor<T> for BernoulliSample<T, R>
+where
+    R: Rng,
+{
+    type State = State<T, R>;
+
+    fn new(_: T) -> Self::State {
+        panic!("`BernoulliSample` must be used with `fold_with`, providing a random number generator and a probability")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        state.seen += 1;
+        if state.rng.random_bool(state.p) {
+            state.items.push(item);
+        }
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            items: state.items,
+            seen: state.seen,
+            rng: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_bernoulli_sample_exact() {
+        let BernoulliSample { items, seen, .. } =
+            (0..100).fold_with((SmallRng::seed_from_u64(0), 0.));
+        assert!(items.is_empty());
+        assert_eq!(seen, 100);
+
+        let BernoulliSample { items, seen, .. } =
+            (0..100).fold_with((SmallRng::seed_from_u64(0), 1.));
+        assert_eq!(items, (0..100).collect::<Vec<_>>());
+        assert_eq!(seen, 100);
+    }
+
+    #[test]
+    fn test_bernoulli_sample_proportion() {
+        const N: usize = 100_000;
+
+        let BernoulliSample { items, seen, .. } =
+            (0..N).fold_with((SmallRng::seed_from_u64(42), 0.1));
+        assert_eq!(seen, N);
+
+        let observed = items.len() as f64 / N as f64;
+        assert!((observed - 0.1).abs() < 0.005, "observed {observed}");
+        assert!(items.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    #[should_panic = "sampling probability"]
+    fn test_bernoulli_sample_invalid_probability() {
+        let _: BernoulliSample<_, _> = (0..10).fold_with((SmallRng::seed_from_u64(0), 1.5));
+    }
+}
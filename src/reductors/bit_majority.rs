@@ -0,0 +1,122 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that takes a bitwise majority vote over the integers yielded by an iterator,
+/// e.g. for error-correcting repeated transmissions of the same value.
+///
+/// For each of the lowest `BITS` bit positions, the number of items that have that bit set is
+/// counted, and the result has that bit set if it was set in strictly more than half of the items
+/// (so a tie, including an empty iterator, results in an unset bit). Bits above `BITS` are ignored.
+///
+/// Items can be any type that implements [`Into<u128>`] (e.g. any of the unsigned integer types),
+/// and `BITS` must be at most 128.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{BitMajority, Reduce};
+///
+/// // The same byte, received three times over a noisy channel.
+/// let received = [0b1011_0010u8, 0b1001_0010, 0b1011_0110];
+///
+/// let BitMajority::<8>(corrected) = received.into_iter().reduce_with();
+/// assert_eq!(corrected, 0b1011_0010);
+/// ```
+#[repr(transparent)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitMajority<const BITS: usize>(pub u128);
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<const BITS: usize> {
+    set: [usize; BITS],
+    total: usize,
+}
+
+impl<const BITS: usize> Default for State<BITS> {
+    fn default() -> Self {
+        Self {
+            set: [0; BITS],
+            total: 0,
+        }
+    }
+}
+
+impl<T, const BITS: usize> Reductor<T> for BitMajority<BITS>
+where
+    T: Into<u128>,
+{
+    type State = State<BITS>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        const { assert!(BITS <= 128, "`BITS` must be at most 128") };
+
+        let item = item.into();
+        for (bit, set) in state.set.iter_mut().enumerate() {
+            *set += ((item >> bit) & 1) as usize;
+        }
+        state.total += 1;
+        state
+    }
+
+    fn into_result(state: Self::State) -> Self {
+        Self(
+            state
+                .set
+                .iter()
+                .enumerate()
+                .filter(|&(_, &set)| set * 2 > state.total)
+                .fold(0, |majority, (bit, _)| majority | 1 << bit),
+        )
+    }
+}
+
+impl<T, const BITS: usize> MergeReductor<T> for BitMajority<BITS>
+where
+    T: Into<u128>,
+{
+    fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+        for (left, right) in left.set.iter_mut().zip(right.set) {
+            *left += right;
+        }
+        left.total += right.total;
+        left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_bit_majority_u8() {
+        let votes = [
+            0b1100_1010u8,
+            0b1110_1000,
+            0b0100_1011,
+            0b1100_0010,
+            0b1000_1110,
+        ];
+
+        // Set in: 4, 4, 1, 0, 4, 1, 4, 1 of the 5 items (from the highest bit down).
+        let BitMajority::<8>(majority) = votes.into_iter().reduce_with();
+        assert_eq!(majority, 0b1100_1010);
+
+        let BitMajority::<4>(majority) = votes.into_iter().reduce_with();
+        assert_eq!(majority, 0b1010);
+    }
+
+    #[test]
+    fn test_bit_majority_ties() {
+        let BitMajority::<8>(majority) = [0b0011u8, 0b0101].into_iter().reduce_with();
+        assert_eq!(majority, 0b0001);
+
+        let BitMajority::<8>(majority) = std::iter::empty::<u8>().reduce_with();
+        assert_eq!(majority, 0);
+    }
+}
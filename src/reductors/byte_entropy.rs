@@ -0,0 +1,81 @@
+use std::borrow::Borrow;
+
+use crate::{ByteHistogram, MergeReductor, Reductor};
+
+/// Reductor that computes the [Shannon entropy] of the bytes (or references to them) yielded by
+/// an iterator, in bits per byte.
+///
+/// The entropy is between zero (a single distinct byte, or no bytes at all) and eight, when all
+/// byte values are equally frequent, which makes this a quick estimate of how random (or how
+/// compressible) a stream of bytes is. This is a shorthand for reducing into a
+/// [`ByteHistogram`] and taking its [`entropy`](ByteHistogram::entropy).
+///
+/// [Shannon entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+///
+/// # Examples
+/// ```rust
+/// use reductor::{ByteEntropy, Reduce};
+///
+/// let ByteEntropy(entropy) = b"aabb".iter().reduce_with();
+/// assert_eq!(entropy, 1.0);
+///
+/// let ByteEntropy(entropy) = b"".iter().reduce_with();
+/// assert_eq!(entropy, 0.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ByteEntropy(pub f64);
+
+impl<A> Reductor<A> for ByteEntropy
+where
+    A: Borrow<u8>,
+{
+    type State = <ByteHistogram as Reductor<u8>>::State;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        <ByteHistogram as Reductor<u8>>::new(*item.borrow())
+    }
+
+    #[inline]
+    fn reduce(state: Self::State, item: A) -> Self::State {
+        <ByteHistogram as Reductor<u8>>::reduce(state, *item.borrow())
+    }
+
+    fn into_result(state: Self::State) -> Self {
+        Self(<ByteHistogram as Reductor<u8>>::into_result(state).entropy())
+    }
+}
+
+impl<A> MergeReductor<A> for ByteEntropy
+where
+    A: Borrow<u8>,
+{
+    #[inline]
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        <ByteHistogram as MergeReductor<u8>>::merge(left, right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_byte_entropy_identical() {
+        let ByteEntropy(entropy) = [0x42_u8; 1000].into_iter().reduce_with();
+        assert_eq!(entropy, 0.0);
+    }
+
+    #[test]
+    fn test_byte_entropy_uniform() {
+        let data: Vec<u8> = (0..4).flat_map(|_| 0..=u8::MAX).collect();
+
+        let ByteEntropy(entropy) = data.iter().reduce_with();
+        assert_eq!(entropy, 8.0);
+
+        let ByteEntropy(merged) = crate::parallel::par_reduce_slice::<ByteEntropy, _>(&data, 3, 0);
+        assert_eq!(merged, 8.0);
+    }
+}
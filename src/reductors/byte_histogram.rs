@@ -0,0 +1,204 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that counts the occurrences of every byte value yielded by an iterator,
+/// in 256 fixed bins and without allocating.
+///
+/// Items can be either single bytes (`u8` or `&u8`), or whole chunks of bytes (`&[u8]`),
+/// so that buffered readers can be reduced without flattening their buffers first.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, ByteHistogram};
+///
+/// let hist: ByteHistogram = b"abracadabra".iter().reduce_with();
+///
+/// assert_eq!(hist.0[usize::from(b'a')], 5);
+/// assert_eq!(hist.most_common(), Some((b'a', 5)));
+///
+/// let chunked: ByteHistogram = [&b"abra"[..], b"cadabra"].into_iter().reduce_with();
+/// assert_eq!(hist, chunked);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteHistogram(pub [u64; 256]);
+
+impl ByteHistogram {
+    /// Total number of bytes counted.
+    pub fn total(&self) -> u64 {
+        self.0.iter().sum()
+    }
+
+    /// [Shannon entropy] of the byte distribution, in bits per byte (between `0.0` and `8.0`).
+    ///
+    /// Returns `0.0` if no bytes were counted.
+    ///
+    /// [Shannon entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+    pub fn entropy(&self) -> f64 {
+        let total = self.total() as f64;
+
+        self.0
+            .iter()
+            .filter(|&&count| count != 0)
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+
+    /// The most common byte value, along with its count.
+    ///
+    /// Ties are resolved in favor of the smallest byte value. Returns `None` if no bytes
+    /// were counted.
+    pub fn most_common(&self) -> Option<(u8, u64)> {
+        (0..=u8::MAX)
+            .zip(self.0)
+            .filter(|&(_, count)| count != 0)
+            .reduce(|best, cur| if cur.1 > best.1 { cur } else { best })
+    }
+}
+
+impl Default for ByteHistogram {
+    #[inline]
+    fn default() -> Self {
+        Self(State::default().0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State([u64; 256]);
+
+impl From<[u64; 256]> for State {
+    fn from(v: [u64; 256]) -> Self {
+        Self(v)
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self([0; 256])
+    }
+}
+
+impl State {
+    fn merge(mut self, other: Self) -> Self {
+        self.0
+            .iter_mut()
+            .zip(other.0)
+            .for_each(|(count, other)| *count += other);
+        self
+    }
+}
+
+impl Reductor<u8> for ByteHistogram {
+    type State = State;
+
+    #[inline]
+    fn new(item: u8) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: u8) -> Self::State {
+        state.0[usize::from(item)] += 1;
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state.0)
+    }
+}
+
+impl Reductor<&u8> for ByteHistogram {
+    type State = State;
+
+    #[inline]
+    fn new(item: &u8) -> Self::State {
+        <Self as Reductor<u8>>::new(*item)
+    }
+
+    #[inline]
+    fn reduce(state: Self::State, item: &u8) -> Self::State {
+        <Self as Reductor<u8>>::reduce(state, *item)
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state.0)
+    }
+}
+
+impl Reductor<&[u8]> for ByteHistogram {
+    type State = State;
+
+    #[inline]
+    fn new(item: &[u8]) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(state: Self::State, item: &[u8]) -> Self::State {
+        item.iter().fold(state, <Self as Reductor<&u8>>::reduce)
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state.0)
+    }
+}
+
+impl MergeReductor<u8> for ByteHistogram {
+    #[inline]
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        left.merge(right)
+    }
+}
+
+impl MergeReductor<&u8> for ByteHistogram {
+    #[inline]
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        left.merge(right)
+    }
+}
+
+impl MergeReductor<&[u8]> for ByteHistogram {
+    #[inline]
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        left.merge(right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_entropy_uniform() {
+        let hist: ByteHistogram = (0..=u8::MAX).cycle().take(256 * 4).reduce_with();
+        assert!((hist.entropy() - 8.).abs() < 1e-12);
+        assert_eq!(hist.total(), 256 * 4);
+    }
+
+    #[test]
+    fn test_entropy_single_value() {
+        let hist: ByteHistogram = [42u8; 100].iter().reduce_with();
+        assert_eq!(hist.entropy(), 0.);
+        assert_eq!(hist.most_common(), Some((42, 100)));
+
+        assert_eq!(ByteHistogram::default().entropy(), 0.);
+        assert_eq!(ByteHistogram::default().most_common(), None);
+    }
+
+    #[test]
+    fn test_chunked() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        let per_byte: ByteHistogram = data.iter().reduce_with();
+        let chunked: ByteHistogram = data.chunks(37).reduce_with();
+
+        assert_eq!(per_byte, chunked);
+    }
+}
@@ -0,0 +1,142 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::Reductor;
+
+/// Reductor that collects up to `n` distinct items yielded by an iterator, while counting
+/// the number of distinct items using bounded memory.
+///
+/// The first `n` distinct items are kept in the sample, in the order they were first yielded.
+/// Once the sample is full, every item that isn't in the sample is counted (but not kept),
+/// which means that repeats of such items are counted again. So `total_distinct` is exact
+/// as long as it doesn't exceed `n` (see [`is_exact`](Self::is_exact)), and is otherwise an
+/// upper bound on the number of distinct items.
+///
+/// The cap must be provided as the initial state, by calling [`fold_with`](crate::Reduce::fold_with).
+///
+/// # Panics
+/// Panics if reduced without a cap (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CappedDistinct, Reduce};
+///
+/// let user_ids = [7, 3, 7, 7, 3, 9];
+///
+/// let distinct: CappedDistinct<_> = user_ids.into_iter().fold_with(5);
+/// assert_eq!(distinct.sample, [7, 3, 9]);
+/// assert_eq!(distinct.total_distinct, 3);
+/// assert!(distinct.is_exact());
+///
+/// let distinct: CappedDistinct<_> = user_ids.into_iter().fold_with(1);
+/// assert_eq!(distinct.sample, [7]);
+/// assert_eq!(distinct.total_distinct, 4);
+/// assert!(!distinct.is_exact());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CappedDistinct<T> {
+    /// Up to `n` distinct items, in the order they were first yielded.
+    pub sample: Vec<T>,
+    /// Number of distinct items, exact unless it exceeds `n`.
+    pub total_distinct: usize,
+}
+
+impl<T> CappedDistinct<T> {
+    /// Whether [`total_distinct`](Self::total_distinct) is exact, i.e. all distinct items fit
+    /// in the sample.
+    pub fn is_exact(&self) -> bool {
+        self.total_distinct == self.sample.len()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    cap: usize,
+    seen: HashSet<T>,
+    sample: Vec<T>,
+    total_distinct: usize,
+}
+
+impl<T> From<usize> for State<T> {
+    fn from(cap: usize) -> Self {
+        Self {
+            cap,
+            seen: HashSet::new(),
+            sample: Vec::new(),
+            total_distinct: 0,
+        }
+    }
+}
+
+impl<T> Reductor<T> for CappedDistinct<T>
+where
+    T: Eq + Hash + Clone,
+{
+    type State = State<T>;
+
+    fn new(_: T) -> Self::State {
+        panic!("`CappedDistinct` must be used with `fold_with`, providing a cap")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        if state.seen.contains(&item) {
+            return state;
+        }
+
+        state.total_distinct += 1;
+        if state.sample.len() < state.cap {
+            state.seen.insert(item.clone());
+            state.sample.push(item);
+        }
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            sample: state.sample,
+            total_distinct: state.total_distinct,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_capped_distinct() {
+        let words = "the cat sat on the mat and the cat ran".split(' ');
+
+        let distinct: CappedDistinct<_> = words.clone().fold_with(100);
+        assert_eq!(
+            distinct.sample,
+            ["the", "cat", "sat", "on", "mat", "and", "ran"]
+        );
+        assert_eq!(distinct.total_distinct, 7);
+        assert!(distinct.is_exact());
+
+        let distinct: CappedDistinct<_> = words.clone().fold_with(7);
+        assert_eq!(distinct.total_distinct, 7);
+        assert!(distinct.is_exact());
+
+        // "the" and "cat" are in the sample, but the repeated "the" isn't counted again.
+        let distinct: CappedDistinct<_> = words.fold_with(2);
+        assert_eq!(distinct.sample, ["the", "cat"]);
+        assert_eq!(distinct.total_distinct, 7);
+        assert!(!distinct.is_exact());
+    }
+
+    #[test]
+    fn test_capped_distinct_overcounts_beyond_cap() {
+        let distinct: CappedDistinct<_> = [1, 2, 2, 2].into_iter().fold_with(1);
+        assert_eq!(distinct.sample, [1]);
+        assert_eq!(distinct.total_distinct, 4);
+
+        let distinct: CappedDistinct<u8> = std::iter::empty().fold_with(0);
+        assert_eq!(distinct.total_distinct, 0);
+        assert!(distinct.is_exact());
+    }
+}
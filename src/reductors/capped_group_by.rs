@@ -0,0 +1,281 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+use crate::{MergeReductor, Reductor};
+
+/// Policy that decides what [`CappedGroupBy`] does with the state of a key that is evicted.
+///
+/// [`MergeEvicted`] merges the states of all evicted keys into an "other" bucket, while
+/// [`CountEvicted`] discards them, only counting the evictions.
+pub trait EvictionPolicy<A, R>
+where
+    R: Reductor<A>,
+{
+    /// Fold the state of an evicted key into the state of the "other" bucket.
+    fn evict(other: Option<R::State>, evicted: R::State) -> Option<R::State>;
+}
+
+/// [`EvictionPolicy`] that merges the states of evicted keys into an "other" bucket,
+/// which requires the inner reductor to be a [`MergeReductor`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MergeEvicted;
+
+impl<A, R> EvictionPolicy<A, R> for MergeEvicted
+where
+    R: MergeReductor<A>,
+{
+    #[inline]
+    fn evict(other: Option<R::State>, evicted: R::State) -> Option<R::State> {
+        Some(match other {
+            Some(other) => R::merge(other, evicted),
+            None => evicted,
+        })
+    }
+}
+
+/// [`EvictionPolicy`] that discards the states of evicted keys, only counting the evictions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CountEvicted;
+
+impl<A, R> EvictionPolicy<A, R> for CountEvicted
+where
+    R: Reductor<A>,
+{
+    #[inline]
+    fn evict(other: Option<R::State>, _: R::State) -> Option<R::State> {
+        other
+    }
+}
+
+/// Reductor that reduces the values of the `(key, value)` pairs yielded by an iterator
+/// per key, with the inner reductor `R`, while keeping the states of at most `cap` keys.
+///
+/// When a new key is yielded while `cap` keys are already kept, the least recently yielded key
+/// is evicted, and its state is handled by the [`EvictionPolicy`] `P`. An evicted key that is
+/// yielded again starts over from a new state. So with a high-cardinality stream, frequently
+/// yielded ("hot") keys are reduced exactly, while memory stays bounded.
+///
+/// The cap must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with). Each item takes `O(log cap)` time, and keys are
+/// cloned once when they are first yielded (or yielded after being evicted).
+///
+/// # Panics
+/// Panics if reduced without a cap (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CappedGroupBy, Count, MergeEvicted, Reduce};
+///
+/// let visits = ["home", "a", "home", "b", "home", "c", "home"];
+///
+/// let CappedGroupBy::<_, Count, MergeEvicted> { groups, other, evictions, .. } = visits
+///     .into_iter()
+///     .map(|page| (page, ()))
+///     .fold_with(2);
+///
+/// assert_eq!(groups["home"], Count(4));
+/// assert_eq!(groups["c"], Count(1));
+/// assert_eq!(other, Some(Count(2)));
+/// assert_eq!(evictions, 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CappedGroupBy<K, R, P> {
+    /// The results of the retained keys.
+    pub groups: HashMap<K, R>,
+    /// The merged result of all evicted keys, if any keys were evicted with [`MergeEvicted`].
+    pub other: Option<R>,
+    /// Number of times a key was evicted.
+    pub evictions: usize,
+    policy: PhantomData<P>,
+}
+
+impl<K, R, P> PartialEq for CappedGroupBy<K, R, P>
+where
+    K: Eq + Hash,
+    R: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.groups == other.groups
+            && self.other == other.other
+            && self.evictions == other.evictions
+    }
+}
+
+impl<K, R, P> Eq for CappedGroupBy<K, R, P>
+where
+    K: Eq + Hash,
+    R: Eq,
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct State<K, S> {
+    cap: usize,
+    /// State of each retained key, along with the tick it was last yielded at.
+    groups: HashMap<K, (S, u64)>,
+    /// Retained keys, by the tick they were last yielded at.
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+    other: Option<S>,
+    evictions: usize,
+}
+
+impl<K, S> From<usize> for State<K, S> {
+    fn from(cap: usize) -> Self {
+        Self {
+            cap,
+            groups: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            other: None,
+            evictions: 0,
+        }
+    }
+}
+
+impl<K, A, R, P> Reductor<(K, A)> for CappedGroupBy<K, R, P>
+where
+    K: Eq + Hash + Clone,
+    R: Reductor<A>,
+    P: EvictionPolicy<A, R>,
+{
+    type State = State<K, R::State>;
+
+    fn new(_: (K, A)) -> Self::State {
+        panic!("`CappedGroupBy` must be used with `fold_with`, providing a cap")
+    }
+
+    fn reduce(mut state: Self::State, (key, item): (K, A)) -> Self::State {
+        match state.groups.remove_entry(&key) {
+            Some((key, (group, tick))) => {
+                // the retained key already has a clone in `recency`, so move it to the new tick
+                let cloned = state
+                    .recency
+                    .remove(&tick)
+                    .expect("retained keys have a tick");
+                state.recency.insert(state.tick, cloned);
+                state
+                    .groups
+                    .insert(key, (R::reduce(group, item), state.tick));
+            }
+            None => {
+                state.recency.insert(state.tick, key.clone());
+                state.groups.insert(key, (R::new(item), state.tick));
+            }
+        }
+        state.tick += 1;
+
+        while state.groups.len() > state.cap {
+            let Some((_, key)) = state.recency.pop_first() else {
+                break;
+            };
+            if let Some((evicted, _)) = state.groups.remove(&key) {
+                state.other = P::evict(state.other.take(), evicted);
+                state.evictions += 1;
+            }
+        }
+
+        state
+    }
+
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            groups: state
+                .groups
+                .into_iter()
+                .map(|(key, (group, _))| (key, R::into_result(group)))
+                .collect(),
+            other: state.other.map(R::into_result),
+            evictions: state.evictions,
+            policy: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CollectMap, Count, Reduce, ReduceValues, Sum};
+
+    use super::*;
+
+    /// Every other item has one of three hot keys, and the rest have unique cold keys.
+    fn hot_and_cold() -> impl Iterator<Item = (u32, u32)> {
+        (0..1_000).flat_map(|i| [(i % 3, i), (1_000 + i, i)])
+    }
+
+    #[test]
+    fn test_capped_group_by_below_cap() {
+        let pairs = (0..500u32).map(|i| (i % 17, i));
+
+        let capped: CappedGroupBy<_, Sum<u32>, MergeEvicted> = pairs.clone().fold_with(17);
+        let CollectMap { map: uncapped, .. } =
+            pairs.reduce_with::<CollectMap<_, Sum<u32>, ReduceValues>>();
+
+        assert_eq!(capped.groups, uncapped);
+        assert_eq!(capped.other, None);
+        assert_eq!(capped.evictions, 0);
+    }
+
+    #[test]
+    fn test_capped_group_by_merge_evicted() {
+        let CappedGroupBy::<_, Count, MergeEvicted> {
+            groups,
+            other,
+            evictions,
+            ..
+        } = hot_and_cold().fold_with(8);
+
+        assert_eq!(groups.len(), 8);
+        for hot in 0..3 {
+            assert_eq!(groups[&hot], Count((1_000 + 2 - hot as usize) / 3));
+        }
+
+        // Every cold key is yielded once, and all but the most recent ones are evicted.
+        assert_eq!(evictions, 1_000 - 5);
+        let Count(other) = other.unwrap();
+        let retained: usize = groups.values().map(|&Count(count)| count).sum();
+        assert_eq!(retained + other, 2_000);
+    }
+
+    #[test]
+    fn test_capped_group_by_count_evicted() {
+        let CappedGroupBy::<_, Sum<u32>, CountEvicted> {
+            groups,
+            other,
+            evictions,
+            ..
+        } = hot_and_cold().fold_with(8);
+
+        assert_eq!(groups.len(), 8);
+        assert_eq!(other, None);
+        assert_eq!(evictions, 1_000 - 5);
+        assert_eq!(
+            groups[&0],
+            Sum(hot_and_cold()
+                .filter(|&(key, _)| key == 0)
+                .map(|(_, i)| i)
+                .sum())
+        );
+    }
+
+    #[test]
+    fn test_capped_group_by_returning_key() {
+        // `a` is evicted by `c`, and then starts over when it is yielded again.
+        let CappedGroupBy::<_, Count, MergeEvicted> {
+            groups,
+            other,
+            evictions,
+            ..
+        } = ["a", "a", "b", "c", "a"]
+            .into_iter()
+            .map(|key| (key, ()))
+            .fold_with(2);
+
+        assert_eq!(groups, HashMap::from([("c", Count(1)), ("a", Count(1))]));
+        assert_eq!(other, Some(Count(3)));
+        assert_eq!(evictions, 2);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::Reductor;
+
+/// Reductor that computes the value of the [empirical CDF] of the items yielded by an iterator at
+/// a fixed query value, i.e. the fraction of items that are less than *or equal to* the query.
+///
+/// Since items equal to the query are counted, the CDF at a value that appears in the iterator
+/// includes all of its occurrences (e.g. the CDF of `[1, 2, 2, 3]` at `2` is `0.75`). Items that
+/// can't be compared with the query (e.g. NaN) are never counted as being at or below it, but are
+/// still counted towards the total.
+///
+/// This is computed exactly, in a single pass, using two counters, without collecting the items.
+/// The query must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with).
+///
+/// [empirical CDF]: https://en.wikipedia.org/wiki/Empirical_distribution_function
+///
+/// # Panics
+/// Panics if reduced without a query value (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CdfAt, Reduce};
+///
+/// let latencies_ms = [12, 48, 7, 250, 31, 48, 19, 95];
+///
+/// let slo: CdfAt<_> = latencies_ms.into_iter().fold_with(48);
+/// assert_eq!((slo.at_or_below, slo.total), (6, 8));
+/// assert_eq!(slo.fraction(), 0.75);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CdfAt<F> {
+    /// The query value the CDF was computed at.
+    pub query: F,
+    /// Number of items that are less than or equal to the query.
+    pub at_or_below: usize,
+    /// Total number of items.
+    pub total: usize,
+}
+
+impl<F> CdfAt<F> {
+    /// Fraction of items that are less than or equal to the query.
+    ///
+    /// If the iterator yielded no items, this is NaN.
+    pub fn fraction(&self) -> f64 {
+        self.at_or_below as f64 / self.total as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<F> {
+    query: F,
+    at_or_below: usize,
+    total: usize,
+}
+
+impl<F> From<F> for State<F> {
+    fn from(query: F) -> Self {
+        Self {
+            query,
+            at_or_below: 0,
+            total: 0,
+        }
+    }
+}
+
+impl<F> Reductor<F> for CdfAt<F>
+where
+    F: PartialOrd,
+{
+    type State = State<F>;
+
+    fn new(_: F) -> Self::State {
+        panic!("`CdfAt` must be used with `fold_with`, providing a query value")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: F) -> Self::State {
+        state.at_or_below += usize::from(item <= state.query);
+        state.total += 1;
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            query: state.query,
+            at_or_below: state.at_or_below,
+            total: state.total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_cdf_at_uniform() {
+        // 0, 1, ..., 99 each appear 10 times.
+        let items = (0..1000).map(|i| i % 100);
+
+        for query in [-1, 0, 24, 49, 99, 150] {
+            let cdf: CdfAt<_> = items.clone().fold_with(query);
+            let expected = (query + 1).clamp(0, 100) as f64 / 100.;
+            assert_eq!(cdf.total, 1000);
+            assert_eq!(cdf.fraction(), expected, "query {query}");
+        }
+    }
+
+    #[test]
+    fn test_cdf_at_ties_and_nan() {
+        let cdf: CdfAt<_> = [1., 2., 2., 3.].into_iter().fold_with(2.);
+        assert_eq!(cdf.fraction(), 0.75);
+
+        let cdf: CdfAt<_> = [1., f64::NAN, 3., 0.5].into_iter().fold_with(2.);
+        assert_eq!((cdf.at_or_below, cdf.total), (2, 4));
+
+        let cdf: CdfAt<_> = std::iter::empty::<f64>().fold_with(2.);
+        assert_eq!(cdf.total, 0);
+        assert!(cdf.fraction().is_nan());
+    }
+}
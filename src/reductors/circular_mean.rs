@@ -0,0 +1,196 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the [circular mean] of angles (in radians) yielded by an iterator,
+/// e.g. wind directions or phases, for which an arithmetic [`Mean`](crate::Mean) is wrong
+/// (the mean of 359° and 1° is 0°, not 180°).
+///
+/// The mean is the direction of the sum of the unit vectors of all angles, `atan2(Σsin, Σcos)`,
+/// in the `(-π, π]` range. The length of that sum, divided by the number of angles, is the
+/// mean resultant length, which ranges from zero (angles are spread evenly, or perfectly
+/// opposing) to one (all angles are equal), and is a measure of concentration.
+///
+/// When the mean resultant length is indistinguishable from zero (lower than `sqrt(F::EPSILON)`),
+/// the angles have no meaningful mean direction, so the mean is `None`. The same applies
+/// when the iterator yields no items.
+///
+/// For angles in degrees, use [`from_degrees`](Self::from_degrees).
+///
+/// [circular mean]: https://en.wikipedia.org/wiki/Circular_mean
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CircularMean, Reduce};
+///
+/// let CircularMean { mean, resultant_length } = CircularMean::<f64>::from_degrees([350., 10., 20.]);
+///
+/// assert!((mean.unwrap() - 6.7).abs() < 0.1);
+/// assert!(resultant_length > 0.95);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct CircularMean<F> {
+    /// Mean direction, or `None` if undefined.
+    pub mean: Option<F>,
+    /// Mean resultant length, in the `[0, 1]` range.
+    pub resultant_length: F,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<F> {
+    sin: F,
+    cos: F,
+    count: usize,
+}
+
+macro_rules! impl_circular_mean {
+    ($f:ty) => {
+        impl Default for State<$f> {
+            fn default() -> Self {
+                Self {
+                    sin: 0.,
+                    cos: 0.,
+                    count: 0,
+                }
+            }
+        }
+
+        impl CircularMean<$f> {
+            /// Compute the circular mean of angles in degrees, returning the mean in degrees as well.
+            pub fn from_degrees<I>(angles: I) -> Self
+            where
+                I: IntoIterator,
+                I::Item: Into<$f>,
+            {
+                let Self {
+                    mean,
+                    resultant_length,
+                } = angles
+                    .into_iter()
+                    .map(|angle| angle.into().to_radians())
+                    .fold(State::default(), Self::reduce)
+                    .into();
+
+                Self {
+                    mean: mean.map(<$f>::to_degrees),
+                    resultant_length,
+                }
+            }
+        }
+
+        impl From<State<$f>> for CircularMean<$f> {
+            fn from(State { sin, cos, count }: State<$f>) -> Self {
+                if count == 0 {
+                    return Self {
+                        mean: None,
+                        resultant_length: 0.,
+                    };
+                }
+
+                let resultant_length = sin.hypot(cos) / count as $f;
+                Self {
+                    mean: (resultant_length >= <$f>::EPSILON.sqrt()).then(|| sin.atan2(cos)),
+                    resultant_length,
+                }
+            }
+        }
+
+        impl<T> Reductor<T> for CircularMean<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = State<$f>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: T) -> Self::State {
+                let (sin, cos) = item.into().sin_cos();
+                State {
+                    sin: state.sin + sin,
+                    cos: state.cos + cos,
+                    count: state.count + 1,
+                }
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                state.into()
+            }
+        }
+
+        impl<T> MergeReductor<T> for CircularMean<$f>
+        where
+            T: Into<$f>,
+        {
+            #[inline]
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                State {
+                    sin: left.sin + right.sin,
+                    cos: left.cos + right.cos,
+                    count: left.count + right.count,
+                }
+            }
+        }
+    };
+}
+
+impl_circular_mean!(f32);
+impl_circular_mean!(f64);
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{Mean, Reduce};
+
+    use super::*;
+
+    #[test]
+    fn test_circular_mean_wraparound() {
+        let CircularMean::<f64> {
+            mean,
+            resultant_length,
+        } = CircularMean::<f64>::from_degrees([359., 1.]);
+
+        assert!(mean.unwrap().abs() < 1e-9);
+        assert!((resultant_length - 1f64.to_radians().cos()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_circular_mean_undefined() {
+        let CircularMean::<f64> {
+            mean,
+            resultant_length,
+        } = (0..360)
+            .map(|deg| f64::from(deg).to_radians())
+            .reduce_with();
+        assert!(resultant_length < 1e-12);
+        assert_eq!(mean, None);
+
+        let CircularMean::<f32> { mean, .. } = [0., PI as f32].into_iter().reduce_with();
+        assert_eq!(mean, None);
+
+        let CircularMean::<f64> {
+            mean,
+            resultant_length,
+        } = std::iter::empty::<f64>().reduce_with();
+        assert_eq!((mean, resultant_length), (None, 0.));
+    }
+
+    #[test]
+    fn test_circular_mean_clustered() {
+        let angles = [1.0, 1.01, 0.99, 1.005, 0.998, 1.002];
+
+        let CircularMean::<f64> {
+            mean,
+            resultant_length,
+        } = angles.into_iter().reduce_with();
+        let Mean::<f64>(linear) = angles.into_iter().reduce_with::<Option<_>>().unwrap();
+
+        assert!((mean.unwrap() - linear).abs() < 1e-5);
+        assert!(resultant_length > 0.999);
+    }
+}
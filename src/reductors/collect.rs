@@ -0,0 +1,160 @@
+use std::iter::once;
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that collects items yielded by an iterator into any collection that implements
+/// [`Default`] and [`Extend`] (similarly to [`Iterator::collect`]), e.g. a
+/// [`HashSet`](std::collections::HashSet), a [`BTreeSet`](std::collections::BTreeSet) or
+/// a [`String`].
+///
+/// Collecting into a [`Vec`], a [`String`], a [`HashSet`](std::collections::HashSet) or a
+/// [`HashMap`](std::collections::HashMap) with
+/// [`reduce_with_hint`](crate::Reduce::reduce_with_hint) preallocates it according to the
+/// iterator's [`size_hint`](Iterator::size_hint), like `collect` does. Merging states (with
+/// [`MergeReductor`]) requires the collection to also be iterable over its items, so it isn't
+/// supported for e.g. a [`String`].
+///
+/// # Examples
+/// ```rust
+/// use std::collections::BTreeSet;
+///
+/// use reductor::{Collect, Count, Reduce, Reductors};
+///
+/// let Reductors((Collect::<BTreeSet<_>>(distinct), Count(count))) =
+///     [3, 1, 4, 1, 5].into_iter().reduce_with();
+///
+/// assert_eq!(distinct, BTreeSet::from([1, 3, 4, 5]));
+/// assert_eq!(count, 5);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Collect<C>(pub C);
+
+/// [`Collect`] into a [`Vec`].
+pub type CollectVec<T> = Collect<Vec<T>>;
+
+impl<A, C> Reductor<A> for Collect<C>
+where
+    C: Default + Extend<A>,
+{
+    type State = C;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        Self::reduce(C::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: A) -> Self::State {
+        state.extend(once(item));
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state)
+    }
+}
+
+impl<A, C> MergeReductor<A> for Collect<C>
+where
+    C: Default + Extend<A> + IntoIterator<Item = A>,
+{
+    #[inline]
+    fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+        left.extend(right);
+        left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::{parallel::par_reduce_slice, ConcatBytes, Count, Reduce, Reductors, Sum};
+
+    use super::*;
+
+    #[test]
+    fn test_collect_vec() {
+        let Reductors((Collect::<Vec<_>>(items), Count(count))) =
+            (0..100).map(|x| x * 2).reduce_with();
+        assert_eq!(items, (0..100).map(|x| x * 2).collect::<Vec<_>>());
+        assert_eq!(count, 100);
+
+        let Collect::<Vec<_>>(hinted) = (0..100).reduce_with_hint();
+        assert_eq!(hinted.capacity(), 100);
+
+        let data: Vec<u32> = (0..1000).collect();
+        let Collect::<Vec<_>>(merged) = par_reduce_slice(&data, 4, 0);
+        assert_eq!(merged, data);
+    }
+
+    #[test]
+    fn test_collect_hash_set() {
+        let Reductors((Collect::<HashSet<_>>(distinct), Count(count), Sum::<u32>(sum))) =
+            [2, 7, 2, 8, 7, 1].into_iter().reduce_with();
+        assert_eq!(distinct, HashSet::from([1, 2, 7, 8]));
+        assert_eq!(count, 6);
+        assert_eq!(sum, 27);
+    }
+
+    #[test]
+    fn test_collect_string() {
+        let Reductors((Collect::<String>(word), Count(count))) =
+            "hello".chars().rev().reduce_with();
+        assert_eq!(word, "olleh");
+        assert_eq!(count, 5);
+
+        let Reductors((Collect::<String>(sentence), Count(count))) =
+            ["collect", " ", "strings"].into_iter().reduce_with();
+        assert_eq!(sentence, "collect strings");
+        assert_eq!(count, 3);
+
+        let Collect::<String>(empty) = std::iter::empty::<char>().reduce_with();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_collect_vec_hint() {
+        let Collect(hinted): CollectVec<_> = (0..1000).reduce_with_hint();
+        let Collect(unhinted): CollectVec<_> = (0..1000).reduce_with();
+
+        assert_eq!(hinted, unhinted);
+        assert_eq!(hinted, (0..1000).collect::<Vec<_>>());
+        assert_eq!(hinted.capacity(), 1000);
+
+        let Collect(filtered): CollectVec<_> = (0..1000).filter(|x| x % 3 == 0).reduce_with_hint();
+        assert_eq!(filtered, (0..1000).step_by(3).collect::<Vec<_>>());
+
+        let Collect(empty): CollectVec<_> = std::iter::empty::<u8>().reduce_with_hint();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_collect_hint() {
+        let Collect::<String>(word) = "hello".chars().reduce_with_hint();
+        assert_eq!(word, "hello");
+        assert!(word.capacity() >= 5);
+
+        let Collect::<HashSet<_>>(distinct) = [2, 7, 2, 8].into_iter().reduce_with_hint();
+        assert_eq!(distinct, HashSet::from([2, 7, 8]));
+        assert!(distinct.capacity() >= 4);
+
+        let Collect::<HashMap<_, _>>(map) = (0..100).map(|x| (x, x * x)).reduce_with_hint();
+        assert_eq!(map.len(), 100);
+        assert!(map.capacity() >= 100);
+    }
+
+    #[test]
+    fn test_hint_tuple() {
+        let (Collect::<Vec<_>>(chars), ConcatBytes(bytes)) = ["ab", "c", "de"]
+            .into_iter()
+            .map(|s| (s.len(), s))
+            .reduce_with_hint();
+
+        assert_eq!(chars, [2, 1, 2]);
+        assert_eq!(bytes, b"abcde");
+        assert!(chars.capacity() >= 3);
+    }
+}
@@ -0,0 +1,423 @@
+use std::{collections::HashMap, marker::PhantomData};
+
+#[cfg(feature = "indexmap")]
+use indexmap::IndexMap;
+
+use super::map_like::MapLike;
+use crate::{MergeReductor, Reductor};
+
+/// Policy that decides what [`CollectMap`] does when a key is yielded more than once,
+/// keeping one of the values yielded for it.
+///
+/// Implemented by [`KeepFirst`], [`KeepLast`] and [`PanicOnDuplicate`]. See [`ReduceValues`]
+/// for reducing all values yielded for each key instead.
+pub trait DuplicateKeyPolicy {
+    /// Update the value kept for a key with another value yielded for it.
+    fn duplicate<V>(value: &mut V, item: V);
+}
+
+/// [`DuplicateKeyPolicy`] that keeps the first value yielded for each key.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeepFirst;
+
+impl DuplicateKeyPolicy for KeepFirst {
+    #[inline]
+    fn duplicate<V>(_: &mut V, _: V) {}
+}
+
+/// [`DuplicateKeyPolicy`] that keeps the last value yielded for each key
+/// (like [`HashMap`]'s [`FromIterator`] implementation).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeepLast;
+
+impl DuplicateKeyPolicy for KeepLast {
+    #[inline]
+    fn duplicate<V>(value: &mut V, item: V) {
+        *value = item;
+    }
+}
+
+/// [`DuplicateKeyPolicy`] that panics when a key is yielded more than once.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PanicOnDuplicate;
+
+impl DuplicateKeyPolicy for PanicOnDuplicate {
+    fn duplicate<V>(_: &mut V, _: V) {
+        panic!("`CollectMap` encountered a duplicate key")
+    }
+}
+
+/// Policy for [`CollectMap`] that reduces all values yielded for each key with the
+/// [`Reductor`] `V`, which is the value type of the resulting map.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReduceValues;
+
+/// Reductor that collects the `(key, value)` pairs yielded by an iterator into a map,
+/// with a [`DuplicateKeyPolicy`] `P` deciding what happens when a key is yielded more than once,
+/// or with `P` set to [`ReduceValues`] to reduce the values of each key.
+///
+/// The number of items whose key was already yielded before is counted in
+/// [`collisions`](Self::collisions), regardless of the policy.
+///
+/// See `IndexedCollectMap` (with the `indexmap` feature) for keeping the keys in the order
+/// they were first yielded.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CollectMap, KeepFirst, Max, Reduce, ReduceValues};
+///
+/// let logins = [("alice", 3), ("bob", 5), ("alice", 9), ("alice", 1)];
+///
+/// let first: CollectMap<_, _, KeepFirst> = logins.into_iter().reduce_with();
+/// assert_eq!(first.map["alice"], 3);
+/// assert_eq!(first.collisions, 2);
+///
+/// let latest: CollectMap<_, Max<u32>, ReduceValues> = logins.into_iter().reduce_with();
+/// assert_eq!(latest.map["alice"], Max(9));
+/// assert_eq!(latest.map["bob"], Max(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CollectMap<K, V, P> {
+    /// The collected map.
+    pub map: HashMap<K, V>,
+    /// Number of items whose key was already yielded before.
+    pub collisions: usize,
+    policy: PhantomData<P>,
+}
+
+/// Like [`CollectMap`], but collects the pairs into an [`IndexMap`], so that keys are iterated
+/// in the order they were first yielded.
+///
+/// Requires the `indexmap` feature.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{IndexedCollectMap, KeepLast, Reduce};
+///
+/// let logins = [("bob", 5), ("alice", 3), ("bob", 9)];
+///
+/// let latest: IndexedCollectMap<_, _, KeepLast> = logins.into_iter().reduce_with();
+/// assert!(latest.map.into_iter().eq([("bob", 9), ("alice", 3)]));
+/// assert_eq!(latest.collisions, 1);
+/// ```
+#[cfg(feature = "indexmap")]
+#[derive(Debug, Clone)]
+pub struct IndexedCollectMap<K, V, P> {
+    /// The collected map, in the order keys were first yielded.
+    pub map: IndexMap<K, V>,
+    /// Number of items whose key was already yielded before.
+    pub collisions: usize,
+    policy: PhantomData<P>,
+}
+
+#[derive(Debug, Clone)]
+pub struct State<S> {
+    map: S,
+    collisions: usize,
+}
+
+impl<S> Default for State<S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self {
+            map: S::default(),
+            collisions: 0,
+        }
+    }
+}
+
+macro_rules! impl_collect_map {
+    ($name:ident, $map:ident) => {
+        impl<K, V, P> PartialEq for $name<K, V, P>
+        where
+            $map<K, V>: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.map == other.map && self.collisions == other.collisions
+            }
+        }
+
+        impl<K, V, P> Eq for $name<K, V, P> where $map<K, V>: Eq {}
+
+        impl<K, V, P> Reductor<(K, V)> for $name<K, V, P>
+        where
+            P: DuplicateKeyPolicy,
+            $map<K, V>: MapLike<K, V>,
+        {
+            type State = State<$map<K, V>>;
+
+            #[inline]
+            fn new(item: (K, V)) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, (key, item): (K, V)) -> Self::State {
+                state.map.upsert(
+                    key,
+                    item,
+                    |item| item,
+                    |value, item| {
+                        state.collisions += 1;
+                        P::duplicate(value, item);
+                    },
+                );
+                state
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self {
+                    map: state.map,
+                    collisions: state.collisions,
+                    policy: PhantomData,
+                }
+            }
+        }
+
+        impl<K, V, P> MergeReductor<(K, V)> for $name<K, V, P>
+        where
+            P: DuplicateKeyPolicy,
+            $map<K, V>: MapLike<K, V>,
+        {
+            fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+                left.collisions += right.collisions;
+                for (key, item) in right.map {
+                    left.map.upsert(
+                        key,
+                        item,
+                        |item| item,
+                        |value, item| {
+                            left.collisions += 1;
+                            P::duplicate(value, item);
+                        },
+                    );
+                }
+                left
+            }
+        }
+
+        impl<K, A, R> Reductor<(K, A)> for $name<K, R, ReduceValues>
+        where
+            R: Reductor<A>,
+            $map<K, Option<R::State>>: MapLike<K, Option<R::State>>,
+            $map<K, R>: MapLike<K, R>,
+        {
+            // Values are only ever `None` while being reduced, so that states can be moved out of
+            // the map.
+            type State = State<$map<K, Option<R::State>>>;
+
+            #[inline]
+            fn new(item: (K, A)) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, (key, item): (K, A)) -> Self::State {
+                state.map.upsert(
+                    key,
+                    item,
+                    |item| Some(R::new(item)),
+                    |value, item| {
+                        state.collisions += 1;
+                        *value = value.take().map(|value| R::reduce(value, item));
+                    },
+                );
+                state
+            }
+
+            fn into_result(state: Self::State) -> Self {
+                Self {
+                    map: state
+                        .map
+                        .into_iter()
+                        .map(|(key, value)| {
+                            let value = value.expect("state is always present between reductions");
+                            (key, R::into_result(value))
+                        })
+                        .collect(),
+                    collisions: state.collisions,
+                    policy: PhantomData,
+                }
+            }
+        }
+
+        impl<K, A, R> MergeReductor<(K, A)> for $name<K, R, ReduceValues>
+        where
+            R: MergeReductor<A>,
+            $map<K, Option<R::State>>: MapLike<K, Option<R::State>>,
+            $map<K, R>: MapLike<K, R>,
+        {
+            fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+                left.collisions += right.collisions;
+                for (key, value) in right.map {
+                    left.map.upsert(
+                        key,
+                        value,
+                        |value| value,
+                        |left_value, right_value| {
+                            left.collisions += 1;
+                            *left_value = left_value
+                                .take()
+                                .zip(right_value)
+                                .map(|(l, r)| R::merge(l, r));
+                        },
+                    );
+                }
+                left
+            }
+        }
+    };
+}
+
+impl_collect_map!(CollectMap, HashMap);
+#[cfg(feature = "indexmap")]
+impl_collect_map!(IndexedCollectMap, IndexMap);
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Max, Reduce, Sum};
+
+    use super::*;
+
+    const PAIRS: [(char, u32); 7] = [
+        ('a', 4),
+        ('b', 2),
+        ('a', 7),
+        ('c', 1),
+        ('b', 9),
+        ('a', 3),
+        ('d', 5),
+    ];
+
+    #[test]
+    fn test_collect_map_keep_first() {
+        let CollectMap {
+            map, collisions, ..
+        } = PAIRS
+            .into_iter()
+            .reduce_with::<CollectMap<_, _, KeepFirst>>();
+        assert_eq!(map, HashMap::from([('a', 4), ('b', 2), ('c', 1), ('d', 5)]));
+        assert_eq!(collisions, 3);
+    }
+
+    #[test]
+    fn test_collect_map_keep_last() {
+        let CollectMap {
+            map, collisions, ..
+        } = PAIRS
+            .into_iter()
+            .reduce_with::<CollectMap<_, _, KeepLast>>();
+        assert_eq!(map, PAIRS.into_iter().collect());
+        assert_eq!(collisions, 3);
+    }
+
+    #[test]
+    fn test_collect_map_panic_on_duplicate() {
+        let CollectMap {
+            map, collisions, ..
+        } = PAIRS[..2]
+            .iter()
+            .copied()
+            .reduce_with::<CollectMap<_, _, PanicOnDuplicate>>();
+        assert_eq!(map, HashMap::from([('a', 4), ('b', 2)]));
+        assert_eq!(collisions, 0);
+    }
+
+    #[test]
+    #[should_panic = "duplicate key"]
+    fn test_collect_map_panic_on_duplicate_panics() {
+        PAIRS
+            .into_iter()
+            .reduce_with::<CollectMap<_, _, PanicOnDuplicate>>();
+    }
+
+    #[test]
+    fn test_collect_map_reduce_values() {
+        let CollectMap {
+            map, collisions, ..
+        } = PAIRS
+            .into_iter()
+            .reduce_with::<CollectMap<_, Max<u32>, ReduceValues>>();
+        assert_eq!(
+            map,
+            HashMap::from([('a', Max(7)), ('b', Max(9)), ('c', Max(1)), ('d', Max(5))])
+        );
+        assert_eq!(collisions, 3);
+
+        let CollectMap { map, .. } = PAIRS
+            .into_iter()
+            .reduce_with::<CollectMap<_, Sum<u32>, ReduceValues>>();
+        assert_eq!(map[&'a'], Sum(14));
+    }
+
+    #[test]
+    fn test_collect_map_collisions() {
+        let CollectMap {
+            map, collisions, ..
+        } = (0..1000)
+            .map(|i| (i % 37, i))
+            .reduce_with::<CollectMap<_, _, KeepLast>>();
+        assert_eq!(map.len(), 37);
+        assert_eq!(collisions, 1000 - 37);
+
+        let CollectMap {
+            map, collisions, ..
+        } = std::iter::empty::<(u8, u8)>().reduce_with::<CollectMap<_, _, KeepFirst>>();
+        assert!(map.is_empty());
+        assert_eq!(collisions, 0);
+    }
+
+    #[test]
+    fn test_collect_map_merge() {
+        let pairs: Vec<(u8, u32)> = (0..200).map(|i| ((i * 7 % 23) as u8, i)).collect();
+
+        let first = pairs
+            .iter()
+            .copied()
+            .reduce_with::<CollectMap<_, _, KeepFirst>>();
+        let last = pairs
+            .iter()
+            .copied()
+            .reduce_with::<CollectMap<_, _, KeepLast>>();
+        let sums = pairs
+            .iter()
+            .copied()
+            .reduce_with::<CollectMap<_, Sum<u32>, ReduceValues>>();
+
+        for threads in 2..=4 {
+            assert_eq!(
+                par_reduce_slice::<CollectMap<_, _, KeepFirst>, _>(&pairs, threads, 0),
+                first
+            );
+            assert_eq!(
+                par_reduce_slice::<CollectMap<_, _, KeepLast>, _>(&pairs, threads, 0),
+                last
+            );
+            assert_eq!(
+                par_reduce_slice::<CollectMap<_, Sum<u32>, ReduceValues>, _>(&pairs, threads, 0),
+                sums
+            );
+        }
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_collect_map_indexed() {
+        let hashed = PAIRS
+            .into_iter()
+            .reduce_with::<CollectMap<_, Sum<u32>, ReduceValues>>();
+        let indexed = PAIRS
+            .into_iter()
+            .reduce_with::<IndexedCollectMap<_, Sum<u32>, ReduceValues>>();
+
+        assert!(indexed.map.keys().eq(&['a', 'b', 'c', 'd']));
+        assert_eq!(indexed.collisions, hashed.collisions);
+        assert_eq!(
+            indexed.map.into_iter().collect::<HashMap<_, _>>(),
+            hashed.map
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "indexmap")]
+use indexmap::IndexMap;
+
+use super::map_like::MapLike;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that groups the values of the `(key, value)` pairs yielded by an iterator by their
+/// keys, keeping all values of each key in a [`Vec`], in the order they were yielded.
+///
+/// Unlike reducing the values of each key, all values are retained, so this is the equivalent
+/// of grouping rows by a key, and keeping the rows.
+///
+/// See `IndexedCollectMultiMap` (with the `indexmap` feature) for keeping the keys in the
+/// order they were first yielded.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CollectMultiMap, Reduce};
+///
+/// let requests = [("GET", "/"), ("POST", "/login"), ("GET", "/about")];
+///
+/// let CollectMultiMap(by_method) = requests.into_iter().reduce_with();
+/// assert_eq!(by_method["GET"], ["/", "/about"]);
+/// assert_eq!(by_method["POST"], ["/login"]);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct CollectMultiMap<K, V>(pub HashMap<K, Vec<V>>);
+
+/// Like [`CollectMultiMap`], but groups the values into an [`IndexMap`], so that keys are
+/// iterated in the order they were first yielded.
+///
+/// Requires the `indexmap` feature.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{IndexedCollectMultiMap, Reduce};
+///
+/// let requests = [("POST", "/login"), ("GET", "/"), ("POST", "/logout")];
+///
+/// let IndexedCollectMultiMap(by_method) = requests.into_iter().reduce_with();
+/// assert!(by_method.keys().eq(&["POST", "GET"]));
+/// assert_eq!(by_method["POST"], ["/login", "/logout"]);
+/// ```
+#[cfg(feature = "indexmap")]
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct IndexedCollectMultiMap<K, V>(pub IndexMap<K, Vec<V>>);
+
+macro_rules! impl_collect_multi_map {
+    ($name:ident, $map:ident) => {
+        impl<K, V> PartialEq for $name<K, V>
+        where
+            $map<K, Vec<V>>: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<K, V> Eq for $name<K, V> where $map<K, Vec<V>>: Eq {}
+
+        impl<K, V> Default for $name<K, V> {
+            fn default() -> Self {
+                Self($map::default())
+            }
+        }
+
+        impl<K, V> Reductor<(K, V)> for $name<K, V>
+        where
+            $map<K, Vec<V>>: MapLike<K, Vec<V>>,
+        {
+            type State = $map<K, Vec<V>>;
+
+            #[inline]
+            fn new(item: (K, V)) -> Self::State {
+                Self::reduce($map::default(), item)
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, (key, value): (K, V)) -> Self::State {
+                state.upsert(key, value, |value| vec![value], Vec::push);
+                state
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self(state)
+            }
+        }
+
+        impl<K, V> MergeReductor<(K, V)> for $name<K, V>
+        where
+            $map<K, Vec<V>>: MapLike<K, Vec<V>>,
+        {
+            fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+                for (key, values) in right {
+                    left.upsert(
+                        key,
+                        values,
+                        |values| values,
+                        |left, mut right| left.append(&mut right),
+                    );
+                }
+                left
+            }
+        }
+    };
+}
+
+impl_collect_multi_map!(CollectMultiMap, HashMap);
+#[cfg(feature = "indexmap")]
+impl_collect_multi_map!(IndexedCollectMultiMap, IndexMap);
+
+#[cfg(test)]
+mod tests {
+    use crate::{CountDistinct, Reduce};
+
+    use super::*;
+
+    const ROWS: [(char, u32); 6] = [('b', 1), ('a', 2), ('b', 3), ('c', 4), ('a', 5), ('b', 6)];
+
+    #[test]
+    fn test_collect_multi_map_value_order() {
+        let CollectMultiMap(groups) = ROWS.into_iter().reduce_with();
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&'a'], [2, 5]);
+        assert_eq!(groups[&'b'], [1, 3, 6]);
+        assert_eq!(groups[&'c'], [4]);
+    }
+
+    #[test]
+    fn test_collect_multi_map_key_order() {
+        let CollectMultiMap(forward) = ROWS.into_iter().reduce_with();
+
+        // Moving all rows of a key around doesn't change the result.
+        let mut regrouped = ROWS;
+        regrouped.sort_by_key(|&(key, _)| std::cmp::Reverse(key));
+        let CollectMultiMap(regrouped) = regrouped.into_iter().reduce_with();
+
+        assert_eq!(forward, regrouped);
+
+        let CollectMultiMap(empty) = std::iter::empty::<(char, u32)>().reduce_with();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_collect_multi_map_with_count_distinct() {
+        let (CollectMultiMap(groups), CountDistinct(distinct)) =
+            ROWS.into_iter().map(|row| (row, row.0)).reduce_with();
+
+        assert_eq!(distinct, 3);
+        assert_eq!(distinct, groups.len());
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_collect_multi_map_indexed() {
+        let CollectMultiMap(hashed) = ROWS.into_iter().reduce_with();
+        let IndexedCollectMultiMap(indexed) = ROWS.into_iter().reduce_with();
+
+        assert!(indexed.keys().eq(&['b', 'a', 'c']));
+        assert_eq!(indexed.len(), hashed.len());
+        for (key, values) in &indexed {
+            assert_eq!(&hashed[key], values);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::state::NonEmptyState;
+use crate::Reductor;
+
+/// Reductor that finds the longest prefix shared by all strings (or any [`AsRef<str>`])
+/// yielded by an iterator.
+///
+/// The prefix is copied out of the first item into a [`String`], which only shrinks as more
+/// items are yielded, and once it is empty, the remaining items aren't compared at all.
+/// The prefix never ends in the middle of a multi-byte character, even if items share the
+/// character's first bytes.
+///
+/// Since an empty iterator has no prefix, this reductor must be wrapped in an [`Option`].
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CommonPrefix, Count, Reduce, Reductors};
+///
+/// let paths = ["/var/log/nginx/access.log", "/var/log/nginx/error.log", "/var/log/syslog"];
+///
+/// let Reductors((CommonPrefix(prefix), Count(count))) =
+///     paths.into_iter().reduce_with::<Option<_>>().unwrap();
+///
+/// assert_eq!(prefix, "/var/log/");
+/// assert_eq!(count, 3);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CommonPrefix(pub String);
+
+impl<T> Reductor<T> for CommonPrefix
+where
+    T: AsRef<str>,
+{
+    type State = NonEmptyState<String>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        NonEmptyState(item.as_ref().to_owned())
+    }
+
+    #[inline]
+    fn reduce(NonEmptyState(mut prefix): Self::State, item: T) -> Self::State {
+        if !prefix.is_empty() {
+            let len = prefix
+                .char_indices()
+                .zip(item.as_ref().chars())
+                .find(|&((_, left), right)| left != right)
+                .map_or_else(
+                    || prefix.len().min(item.as_ref().len()),
+                    |((idx, _), _)| idx,
+                );
+            prefix.truncate(len);
+        }
+        NonEmptyState(prefix)
+    }
+
+    #[inline]
+    fn into_result(NonEmptyState(prefix): Self::State) -> Self {
+        Self(prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    fn common_prefix<'a>(items: impl IntoIterator<Item = &'a str>) -> Option<String> {
+        items
+            .into_iter()
+            .reduce_with::<Option<CommonPrefix>>()
+            .map(|CommonPrefix(prefix)| prefix)
+    }
+
+    #[test]
+    fn test_common_prefix() {
+        assert_eq!(common_prefix(["same"; 4]).as_deref(), Some("same"));
+        assert_eq!(common_prefix(["abc", "xyz", "abd"]).as_deref(), Some(""));
+        assert_eq!(
+            common_prefix(["interstellar", "internet", "interval", "inter"]).as_deref(),
+            Some("inter")
+        );
+        assert_eq!(common_prefix(["prefix", "pre"]).as_deref(), Some("pre"));
+    }
+
+    #[test]
+    fn test_common_prefix_mid_codepoint() {
+        // 'é' is [0xC3, 0xA9] and 'è' is [0xC3, 0xA8], so they share their first byte.
+        assert_eq!(common_prefix(["café", "cafè"]).as_deref(), Some("caf"));
+        // '😀' is [0xF0, 0x9F, 0x98, 0x80] and '😁' is [0xF0, 0x9F, 0x98, 0x81].
+        assert_eq!(common_prefix(["a😀b", "a😁b"]).as_deref(), Some("a"));
+        assert_eq!(common_prefix(["日本語", "日本"]).as_deref(), Some("日本"));
+    }
+
+    #[test]
+    fn test_common_prefix_single_item() {
+        assert_eq!(common_prefix(["lonely"]).as_deref(), Some("lonely"));
+        assert_eq!(common_prefix([""]).as_deref(), Some(""));
+        assert_eq!(common_prefix([]), None);
+
+        let CommonPrefix(prefix) = [String::from("owned"), String::from("own")]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(prefix, "own");
+    }
+}
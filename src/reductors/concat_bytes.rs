@@ -0,0 +1,108 @@
+use crate::{MergeReductor, Reductor, SizedDefault};
+
+/// Reductor that concatenates byte slices yielded by an iterator into a single [`Vec<u8>`].
+///
+/// The buffer starts out empty, and grows as needed (amortized, like [`Vec::extend_from_slice`]).
+/// To avoid reallocations when the total length is known in advance, call
+/// [`fold_with`](crate::Reduce::fold_with) with the capacity to allocate up front, or with
+/// an existing [`Vec<u8>`] to append to.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, ConcatBytes, Sum};
+///
+/// let fragments = [&b"GET "[..], b"/index.html", b" HTTP/1.1"];
+///
+/// let (ConcatBytes(bytes), Sum::<usize>(len)) = fragments
+///     .into_iter()
+///     .map(|fragment| (fragment, fragment.len()))
+///     .reduce_with();
+///
+/// assert_eq!(bytes, b"GET /index.html HTTP/1.1");
+/// assert_eq!(bytes.len(), len);
+/// ```
+#[repr(transparent)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConcatBytes(pub Vec<u8>);
+
+#[derive(Default, Debug, Clone)]
+pub struct State(Vec<u8>);
+
+impl From<usize> for State {
+    fn from(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+}
+
+/// Since items are byte slices of unknown lengths, this assumes at least one byte per item.
+impl SizedDefault for State {
+    fn default_with_capacity(capacity: usize) -> Self {
+        Self::from(capacity)
+    }
+}
+
+impl From<Vec<u8>> for State {
+    fn from(v: Vec<u8>) -> Self {
+        Self(v)
+    }
+}
+
+impl<T> Reductor<T> for ConcatBytes
+where
+    T: AsRef<[u8]>,
+{
+    type State = State;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        State(item.as_ref().to_vec())
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        state.0.extend_from_slice(item.as_ref());
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state.0)
+    }
+}
+
+impl<T> MergeReductor<T> for ConcatBytes
+where
+    T: AsRef<[u8]>,
+{
+    #[inline]
+    fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+        left.0.extend_from_slice(&right.0);
+        left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_concat_bytes() {
+        let ConcatBytes(bytes) = ["ab", "", "cde"].into_iter().reduce_with();
+        assert_eq!(bytes, b"abcde");
+
+        let ConcatBytes(bytes) = std::iter::empty::<Vec<u8>>().reduce_with();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn test_concat_bytes_capacity() {
+        let ConcatBytes(bytes) = [vec![1u8; 10], vec![2; 20]].into_iter().fold_with(64);
+        assert_eq!(bytes.len(), 30);
+        assert!(bytes.capacity() >= 64);
+
+        let ConcatBytes(bytes) = [[3u8, 4]].into_iter().fold_with(vec![1u8, 2]);
+        assert_eq!(bytes, [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,99 @@
+use std::{
+    iter::{self, empty},
+    ops::{Add, Mul},
+};
+
+use crate::Reductor;
+
+/// Reductor that computes the sum of products of consecutive pairs of items yielded by an iterator,
+/// i.e. `x0 * x1 + x1 * x2 + x2 * x3 + ...`.
+///
+/// An iterator yielding fewer than two items has no consecutive pairs, and reduces to zero.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, ConsecutiveProductSum};
+///
+/// let ConsecutiveProductSum(sum) = [1, 2, 3, 4].into_iter().reduce_with();
+/// assert_eq!(sum, 1 * 2 + 2 * 3 + 3 * 4);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConsecutiveProductSum<T>(pub T);
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    prev: Option<T>,
+    sum: T,
+}
+
+impl<T> Default for State<T>
+where
+    T: iter::Sum,
+{
+    fn default() -> Self {
+        Self {
+            prev: None,
+            sum: empty::<T>().sum(),
+        }
+    }
+}
+
+impl<T> Reductor<T> for ConsecutiveProductSum<T>
+where
+    T: Clone + iter::Sum + Add<Output = T> + Mul<Output = T>,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        State {
+            prev: Some(item),
+            sum: empty::<T>().sum(),
+        }
+    }
+
+    #[inline]
+    fn reduce(State { prev, sum }: Self::State, item: T) -> Self::State {
+        match prev {
+            Some(prev) => State {
+                sum: sum + prev * item.clone(),
+                prev: Some(item),
+            },
+            None => State {
+                prev: Some(item),
+                sum,
+            },
+        }
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state.sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_consecutive_product_sum() {
+        let ConsecutiveProductSum(sum) = [2, -3, 5, 1, 4].into_iter().reduce_with();
+        assert_eq!(sum, -6 - 15 + 5 + 4);
+
+        let ConsecutiveProductSum(sum) = [0.5, 4., 0.25].into_iter().reduce_with();
+        assert_eq!(sum, 3.);
+    }
+
+    #[test]
+    fn test_consecutive_product_sum_short() {
+        let ConsecutiveProductSum(sum) = [7].into_iter().reduce_with();
+        assert_eq!(sum, 0);
+
+        let ConsecutiveProductSum(sum) = std::iter::empty::<i32>().reduce_with();
+        assert_eq!(sum, 0);
+    }
+}
@@ -0,0 +1,150 @@
+//! `const fn` reductions of arrays of primitive integers.
+//!
+//! Trait methods can't be called in `const` contexts on stable Rust, so these are implemented
+//! separately from the [`Reductor`](crate::Reductor) impls, with plain `while` loops.
+
+use super::{Max, Min, MinMax, Product, Sum};
+
+macro_rules! impl_const_of {
+    ($($int:ty),+) => {
+        $(
+            impl Sum<$int> {
+                /// Sum the elements of an array, in a `const` context.
+                ///
+                /// # Panics
+                /// Panics (or fails to compile, when evaluated at compile time) on overflow.
+                pub const fn const_of<const N: usize>(arr: [$int; N]) -> Self {
+                    let mut sum: $int = 0;
+                    let mut i = 0;
+                    while i < N {
+                        sum = match sum.checked_add(arr[i]) {
+                            Some(sum) => sum,
+                            None => panic!("attempt to add with overflow"),
+                        };
+                        i += 1;
+                    }
+                    Self(sum)
+                }
+            }
+
+            impl Product<$int> {
+                /// Multiply the elements of an array, in a `const` context.
+                ///
+                /// # Panics
+                /// Panics (or fails to compile, when evaluated at compile time) on overflow.
+                pub const fn const_of<const N: usize>(arr: [$int; N]) -> Self {
+                    let mut product: $int = 1;
+                    let mut i = 0;
+                    while i < N {
+                        product = match product.checked_mul(arr[i]) {
+                            Some(product) => product,
+                            None => panic!("attempt to multiply with overflow"),
+                        };
+                        i += 1;
+                    }
+                    Self(product)
+                }
+            }
+
+            impl Min<$int> {
+                /// Find the minimum element of an array, in a `const` context.
+                ///
+                /// Returns `None` if the array is empty.
+                pub const fn const_of<const N: usize>(arr: [$int; N]) -> Option<Self> {
+                    match MinMax::<$int>::const_of(arr) {
+                        Some(MinMax { min, .. }) => Some(Self(min)),
+                        None => None,
+                    }
+                }
+            }
+
+            impl Max<$int> {
+                /// Find the maximum element of an array, in a `const` context.
+                ///
+                /// Returns `None` if the array is empty.
+                pub const fn const_of<const N: usize>(arr: [$int; N]) -> Option<Self> {
+                    match MinMax::<$int>::const_of(arr) {
+                        Some(MinMax { max, .. }) => Some(Self(max)),
+                        None => None,
+                    }
+                }
+            }
+
+            impl MinMax<$int> {
+                /// Find both the minimum and the maximum elements of an array, in a `const` context.
+                ///
+                /// Returns `None` if the array is empty.
+                pub const fn const_of<const N: usize>(arr: [$int; N]) -> Option<Self> {
+                    if N == 0 {
+                        return None;
+                    }
+
+                    let mut min = arr[0];
+                    let mut max = arr[0];
+                    let mut i = 1;
+                    while i < N {
+                        if arr[i] < min {
+                            min = arr[i];
+                        }
+                        if arr[i] > max {
+                            max = arr[i];
+                        }
+                        i += 1;
+                    }
+                    Some(Self { min, max })
+                }
+            }
+        )+
+    };
+}
+
+impl_const_of!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: [i32; 6] = [4, -7, 12, 0, 3, -1];
+
+    const SUM: Sum<i32> = Sum::<i32>::const_of(DATA);
+    const PRODUCT: Product<u64> = Product::<u64>::const_of([1, 2, 3, 4, 5]);
+    const MIN: Option<Min<i32>> = Min::<i32>::const_of(DATA);
+    const MAX: Option<Max<i32>> = Max::<i32>::const_of(DATA);
+    const MIN_MAX: Option<MinMax<u8>> = MinMax::<u8>::const_of([9, 3, 250, 17]);
+    const EMPTY: Option<MinMax<u8>> = MinMax::<u8>::const_of([]);
+
+    const _: () = {
+        assert!(SUM.0 == 11);
+        assert!(PRODUCT.0 == 120);
+        assert!(matches!(MIN, Some(Min(-7))));
+        assert!(matches!(MAX, Some(Max(12))));
+        assert!(matches!(MIN_MAX, Some(MinMax { min: 3, max: 250 })));
+        assert!(EMPTY.is_none());
+        assert!(Sum::<u16>::const_of([]).0 == 0);
+    };
+
+    #[test]
+    fn test_const_of_matches_reductors() {
+        use crate::Reduce;
+
+        assert_eq!(SUM, DATA.into_iter().reduce_with());
+        assert_eq!(MIN, DATA.into_iter().reduce_with());
+        assert_eq!(MAX, DATA.into_iter().reduce_with());
+        assert_eq!(
+            MinMax::<i32>::const_of(DATA),
+            DATA.into_iter().reduce_with()
+        );
+    }
+
+    #[test]
+    #[should_panic = "attempt to add with overflow"]
+    fn test_const_of_sum_overflow() {
+        Sum::<u8>::const_of([200, 50, 10]);
+    }
+
+    #[test]
+    #[should_panic = "attempt to multiply with overflow"]
+    fn test_const_of_product_overflow() {
+        Product::<i16>::const_of([-2, 300, 100]);
+    }
+}
@@ -0,0 +1,97 @@
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the [contraharmonic mean] of items yielded by an iterator,
+/// i.e. `Σx² / Σx`, which weights larger values more heavily than the arithmetic mean.
+///
+/// No special care is taken when `Σx` is zero (e.g. when items cancel each other out):
+/// the result is then infinite, or NaN if all items are zero.
+///
+/// [contraharmonic mean]: https://en.wikipedia.org/wiki/Contraharmonic_mean
+///
+/// # Examples
+/// ```rust
+/// use reductor::{ContraharmonicMean, Reduce};
+///
+/// let ContraharmonicMean::<f64>(mean) = [1, 2, 3, 4]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+/// assert_eq!(mean, 3.);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ContraharmonicMean<F>(pub F);
+
+macro_rules! impl_contraharmonic_mean {
+    ($f:ty) => {
+        impl<T> Reductor<T> for ContraharmonicMean<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<($f, $f)>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                let item = item.into();
+                NonEmptyState((item * item, item))
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState((sum_sq, sum)): Self::State, item: T) -> Self::State {
+                let item = item.into();
+                NonEmptyState((item.mul_add(item, sum_sq), sum + item))
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState((sum_sq, sum)): Self::State) -> Self {
+                Self(sum_sq / sum)
+            }
+        }
+
+        impl<T> MergeReductor<T> for ContraharmonicMean<$f>
+        where
+            T: Into<$f>,
+        {
+            #[inline]
+            fn merge(
+                NonEmptyState((left_sum_sq, left_sum)): Self::State,
+                NonEmptyState((right_sum_sq, right_sum)): Self::State,
+            ) -> Self::State {
+                NonEmptyState((left_sum_sq + right_sum_sq, left_sum + right_sum))
+            }
+        }
+    };
+}
+
+impl_contraharmonic_mean!(f32);
+impl_contraharmonic_mean!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_contraharmonic_mean() {
+        let data = [2.5, 0.5, 4., 1.];
+
+        let ContraharmonicMean::<f64>(mean) = data.into_iter().reduce_with::<Option<_>>().unwrap();
+        let expected = data.iter().map(|x| x * x).sum::<f64>() / data.iter().sum::<f64>();
+        assert_eq!(mean, expected);
+        assert_eq!(mean, 23.5 / 8.);
+    }
+
+    #[test]
+    fn test_contraharmonic_mean_zero_sum() {
+        let ContraharmonicMean::<f32>(mean) =
+            [1i8, -1].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(mean, f32::INFINITY);
+
+        let ContraharmonicMean::<f32>(mean) =
+            [0u8; 3].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert!(mean.is_nan());
+    }
+}
@@ -1,6 +1,6 @@
 use std::num::NonZeroUsize;
 
-use crate::Reductor;
+use crate::{MergeReductor, Reductor};
 
 /// Reductor that counts the number of items yielded by an iterator (similarly to [`Iterator::count`]).
 #[repr(transparent)]
@@ -23,6 +23,12 @@ impl<A> Reductor<A> for Count {
     }
 }
 
+impl<A> MergeReductor<A> for Count {
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        left + right
+    }
+}
+
 /// Reductor that counts the number of items yielded by an iterator (similarly to [`Iterator::count`]),
 /// but results in an `Option<NonZeroUsize>` (unlike [`Count`] which results in a `usize`), with a `None`
 /// being returned for empty iterators.
@@ -60,3 +66,9 @@ impl<A> Reductor<A> for CountNonZero {
         Self(state)
     }
 }
+
+impl<A> MergeReductor<A> for CountNonZero {
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        left.checked_add(right.get()).unwrap()
+    }
+}
@@ -0,0 +1,77 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that counts the number of distinct items yielded by an iterator.
+///
+/// All distinct items are kept in a [`HashSet`] until the end of the reduction, see
+/// [`CappedDistinct`](crate::CappedDistinct) for counting them with bounded memory.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CountDistinct, Reduce};
+///
+/// let CountDistinct(count) = ["a", "b", "a", "c", "b"].into_iter().reduce_with();
+/// assert_eq!(count, 3);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CountDistinct(pub usize);
+
+#[derive(Debug, Clone)]
+pub struct State<T>(HashSet<T>);
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self(HashSet::new())
+    }
+}
+
+impl<T> Reductor<T> for CountDistinct
+where
+    T: Eq + Hash,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(State(mut seen): Self::State, item: T) -> Self::State {
+        seen.insert(item);
+        State(seen)
+    }
+
+    #[inline]
+    fn into_result(State(seen): Self::State) -> Self {
+        Self(seen.len())
+    }
+}
+
+impl<T> MergeReductor<T> for CountDistinct
+where
+    T: Eq + Hash,
+{
+    fn merge(State(mut left): Self::State, State(right): Self::State) -> Self::State {
+        left.extend(right);
+        State(left)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_count_distinct() {
+        let CountDistinct(count) = [3, 1, 3, 3, 2, 1].into_iter().reduce_with();
+        assert_eq!(count, 3);
+
+        let CountDistinct(count) = std::iter::empty::<u8>().reduce_with();
+        assert_eq!(count, 0);
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use super::fnv::{mix, Fnv1a};
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that builds a [count-min sketch] of items yielded by an iterator, for estimating
+/// the frequency of each item using a fixed amount of memory.
+///
+/// The sketch consists of `depth` rows of `width` counters each. Every item increments one
+/// counter in each row, and the estimated frequency of an item is the minimum of its counters.
+/// Estimates never undercount, and with probability at least `1 - e^(-depth)`, overcount by at
+/// most `e / width` times the total number of items.
+///
+/// The width and depth must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with) with a `(width, depth)` tuple (zeroes are treated as
+/// ones). Items are hashed with [FNV-1a], and the counter of each row is derived from that hash
+/// using double hashing.
+///
+/// Sketches with equal dimensions can be merged, so that reduction can be split across threads.
+///
+/// [count-min sketch]: https://en.wikipedia.org/wiki/Count%E2%80%93min_sketch
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+///
+/// # Panics
+/// Panics if reduced without dimensions (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// Merging sketches with different dimensions panics.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CountMinSketch, Reduce};
+///
+/// let requests = ["/", "/login", "/", "/about", "/", "/login"];
+///
+/// let sketch: CountMinSketch<&str> = requests.into_iter().fold_with((64, 4));
+///
+/// assert!(sketch.estimate("/") >= 3);
+/// assert!(sketch.estimate("/login") >= 2);
+/// assert_eq!(sketch.total(), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CountMinSketch<T> {
+    width: usize,
+    depth: usize,
+    counters: Vec<u64>,
+    total: u64,
+    items: PhantomData<fn(T)>,
+}
+
+impl<T> CountMinSketch<T> {
+    /// Number of counters in each row of the sketch.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows in the sketch.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Total number of items added to the sketch.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Estimated number of occurrences of `item`.
+    ///
+    /// The estimate is never lower than the actual number of occurrences.
+    pub fn estimate<Q>(&self, item: &Q) -> u64
+    where
+        T: Borrow<Q>,
+        Q: Hash + ?Sized,
+    {
+        indices(self.width, self.depth, item)
+            .map(|idx| self.counters[idx])
+            .min()
+            .unwrap_or_default()
+    }
+}
+
+/// Index (into the flattened counters) of the counter of `item` in each row.
+fn indices<Q>(width: usize, depth: usize, item: &Q) -> impl Iterator<Item = usize>
+where
+    Q: Hash + ?Sized,
+{
+    let mut hasher = Fnv1a::default();
+    item.hash(&mut hasher);
+    let hash = mix(hasher.finish());
+
+    let (h1, h2) = (hash & 0xffff_ffff, (hash >> 32) | 1);
+    (0..depth).map(move |row| {
+        let col = h1.wrapping_add((row as u64).wrapping_mul(h2)) % width as u64;
+        row * width + col as usize
+    })
+}
+
+impl<T> From<(usize, usize)> for CountMinSketch<T> {
+    fn from((width, depth): (usize, usize)) -> Self {
+        let (width, depth) = (width.max(1), depth.max(1));
+        Self {
+            width,
+            depth,
+            counters: vec![0; width * depth],
+            total: 0,
+            items: PhantomData,
+        }
+    }
+}
+
+impl<T> Reductor<T> for CountMinSketch<T>
+where
+    T: Hash,
+{
+    type State = Self;
+
+    fn new(_: T) -> Self::State {
+        panic!("`CountMinSketch` must be used with `fold_with`, providing a width and a depth")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        for idx in indices(state.width, state.depth, &item) {
+            state.counters[idx] += 1;
+        }
+        state.total += 1;
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        state
+    }
+}
+
+impl<T> MergeReductor<T> for CountMinSketch<T>
+where
+    T: Hash,
+{
+    fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+        assert_eq!(
+            (left.width, left.depth),
+            (right.width, right.depth),
+            "cannot merge sketches with different dimensions"
+        );
+
+        for (l, r) in left.counters.iter_mut().zip(right.counters) {
+            *l += r;
+        }
+        left.total += right.total;
+        left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use crate::Reduce;
+
+    use super::*;
+
+    const KEYS: usize = 10_000;
+    const ITEMS: usize = 50_000;
+
+    /// Zipf-distributed keys (with exponent 1) out of `KEYS` keys.
+    fn zipfian(seed: u64) -> Vec<u32> {
+        let cdf: Vec<f64> = (1..=KEYS)
+            .scan(0., |acc, k| {
+                *acc += 1. / k as f64;
+                Some(*acc)
+            })
+            .collect();
+        let total = cdf[KEYS - 1];
+
+        let mut rng = SmallRng::seed_from_u64(seed);
+        (0..ITEMS)
+            .map(|_| {
+                let u = rng.random::<f64>() * total;
+                cdf.partition_point(|&c| c < u) as u32
+            })
+            .collect()
+    }
+
+    fn exact(items: &[u32]) -> HashMap<u32, u64> {
+        let mut counts = HashMap::new();
+        for &item in items {
+            *counts.entry(item).or_default() += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_count_min_sketch_never_underestimates() {
+        let items = zipfian(0);
+        let sketch: CountMinSketch<u32> = items.iter().copied().fold_with((256, 4));
+
+        assert_eq!(sketch.total(), ITEMS as u64);
+        for (key, count) in exact(&items) {
+            assert!(sketch.estimate(&key) >= count);
+        }
+    }
+
+    #[test]
+    fn test_count_min_sketch_error_bound() {
+        let (width, depth) = (1024, 5);
+        let bound = (std::f64::consts::E / width as f64 * ITEMS as f64) as u64;
+
+        for seed in 0..10 {
+            let items = zipfian(seed);
+            let sketch: CountMinSketch<u32> = items.iter().copied().fold_with((width, depth));
+            let counts = exact(&items);
+
+            let violations = counts
+                .iter()
+                .filter(|&(key, &count)| sketch.estimate(key) > count + bound)
+                .count();
+
+            // Each key may violate the bound with probability `e^-5 < 0.7%`.
+            assert!(
+                (violations as f64) < counts.len() as f64 * 0.007,
+                "seed {seed}: {violations} violations out of {}",
+                counts.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_min_sketch_merge() {
+        type R = CountMinSketch<u32>;
+
+        let items = zipfian(1);
+        let (left, right) = items.split_at(ITEMS / 3);
+        let fold = |items: &[u32]| items.iter().copied().fold(R::from((128, 3)), R::reduce);
+
+        assert_eq!(
+            R::merge(fold(left), fold(right)),
+            items.iter().copied().fold_with((128, 3)),
+        );
+    }
+
+    #[test]
+    #[should_panic = "different dimensions"]
+    fn test_count_min_sketch_merge_mismatch() {
+        type R = CountMinSketch<u32>;
+        R::merge(R::from((128, 3)), R::from((128, 4)));
+    }
+}
@@ -0,0 +1,238 @@
+use std::array;
+
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the mean vector and the (population) [covariance matrix] of the
+/// fixed-size vectors yielded by an iterator, in a single pass.
+///
+/// The mean and the co-moment matrix (the sum of products of deviations from the mean) are
+/// updated for each item using [Welford's online algorithm], which is numerically stable.
+/// Since the covariance matrix is symmetric, only its upper triangle is updated, and it is
+/// mirrored into the lower triangle in [`into_result`](Reductor::into_result). Still, each item
+/// costs `O(D²)` time to reduce (as does merging two states), so this is best suited for
+/// vectors with a handful of components.
+///
+/// Items are arrays of any type that implements [`Into<f64>`].
+///
+/// [covariance matrix]: https://en.wikipedia.org/wiki/Covariance_matrix
+/// [Welford's online algorithm]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CovarianceMatrix, Reduce};
+///
+/// // (cpu %, memory %, request rate)
+/// let samples = [[10., 20., 5.], [20., 30., 5.], [30., 70., 5.]];
+///
+/// let CovarianceMatrix { mean, covariance } = samples
+///     .into_iter()
+///     .reduce_with::<Option<CovarianceMatrix<3>>>()
+///     .unwrap();
+///
+/// assert_eq!(mean, [20., 40., 5.]);
+/// assert_eq!(covariance[0][0], 200. / 3.);
+/// assert_eq!(covariance[0][1], covariance[1][0]);
+/// assert_eq!(covariance[2], [0.; 3]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct CovarianceMatrix<const D: usize> {
+    /// Mean of each component of the items.
+    pub mean: [f64; D],
+    /// Population covariance between each pair of components of the items, where the diagonal
+    /// holds the variance of each component.
+    pub covariance: [[f64; D]; D],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<const D: usize> {
+    count: usize,
+    mean: [f64; D],
+    /// Only the upper triangle (`i <= j`) is maintained.
+    comoment: [[f64; D]; D],
+}
+
+impl<T, const D: usize> Reductor<[T; D]> for CovarianceMatrix<D>
+where
+    T: Into<f64>,
+{
+    type State = NonEmptyState<State<D>>;
+
+    #[inline]
+    fn new(item: [T; D]) -> Self::State {
+        NonEmptyState(State {
+            count: 1,
+            mean: item.map(Into::into),
+            comoment: [[0.; D]; D],
+        })
+    }
+
+    #[inline]
+    fn reduce(NonEmptyState(mut state): Self::State, item: [T; D]) -> Self::State {
+        let item = item.map(Into::into);
+
+        state.count += 1;
+        let count = state.count as f64;
+
+        let delta: [f64; D] = array::from_fn(|i| item[i] - state.mean[i]);
+        for (mean, delta) in state.mean.iter_mut().zip(delta) {
+            *mean += delta / count;
+        }
+
+        for (i, row) in state.comoment.iter_mut().enumerate() {
+            for (j, comoment) in row.iter_mut().enumerate().skip(i) {
+                *comoment += delta[i] * (item[j] - state.mean[j]);
+            }
+        }
+
+        NonEmptyState(state)
+    }
+
+    fn into_result(NonEmptyState(state): Self::State) -> Self {
+        let count = state.count as f64;
+
+        let covariance =
+            array::from_fn(|i| array::from_fn(|j| state.comoment[i.min(j)][i.max(j)] / count));
+
+        Self {
+            mean: state.mean,
+            covariance,
+        }
+    }
+}
+
+impl<T, const D: usize> MergeReductor<[T; D]> for CovarianceMatrix<D>
+where
+    T: Into<f64>,
+{
+    fn merge(NonEmptyState(left): Self::State, NonEmptyState(right): Self::State) -> Self::State {
+        let count = left.count + right.count;
+        let (left_count, right_count) = (left.count as f64, right.count as f64);
+
+        let delta: [f64; D] = array::from_fn(|i| right.mean[i] - left.mean[i]);
+        let mean = array::from_fn(|i| left.mean[i] + delta[i] * right_count / count as f64);
+
+        let mut comoment = left.comoment;
+        for (i, row) in comoment.iter_mut().enumerate() {
+            for (j, comoment) in row.iter_mut().enumerate().skip(i) {
+                *comoment += right.comoment[i][j]
+                    + delta[i] * delta[j] * left_count * right_count / count as f64;
+            }
+        }
+
+        NonEmptyState(State {
+            count,
+            mean,
+            comoment,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Reduce};
+
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn two_pass_mean(values: &[f64]) -> f64 {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+
+    /// Two-pass population covariance.
+    fn two_pass_covariance(xs: &[f64], ys: &[f64]) -> f64 {
+        let (mean_x, mean_y) = (two_pass_mean(xs), two_pass_mean(ys));
+        xs.iter()
+            .zip(ys)
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum::<f64>()
+            / xs.len() as f64
+    }
+
+    #[test]
+    fn test_covariance_matrix_scalar() {
+        let data = [2., 4., 4., 4., 5., 5., 7., 9.];
+
+        let CovarianceMatrix { mean, covariance } = data
+            .map(|x| [x])
+            .into_iter()
+            .reduce_with::<Option<CovarianceMatrix<1>>>()
+            .unwrap();
+
+        assert_eq!(mean, [5.]);
+        assert!((covariance[0][0] - 4.).abs() < EPSILON);
+        assert!((covariance[0][0] - two_pass_covariance(&data, &data)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_covariance_matrix_by_hand() {
+        // deviations from the mean `[3, 5]` are `[-2, -3]`, `[0, 1]` and `[2, 2]`
+        let CovarianceMatrix { mean, covariance } = [[1, 2], [3, 6], [5, 7]]
+            .into_iter()
+            .reduce_with::<Option<CovarianceMatrix<2>>>()
+            .unwrap();
+
+        assert_eq!(mean, [3., 5.]);
+        assert_eq!(covariance, [[8. / 3., 10. / 3.], [10. / 3., 14. / 3.]]);
+    }
+
+    #[test]
+    fn test_covariance_matrix_pairwise() {
+        let xs = [1.5, -2., 3.25, 8., 0., 4.5, 1e3];
+        let ys = [10., 12., 9.5, 3., 14., 8., -200.];
+
+        let CovarianceMatrix { covariance, .. } = xs
+            .into_iter()
+            .zip(ys)
+            .map(|(x, y)| [x, y])
+            .reduce_with::<Option<CovarianceMatrix<2>>>()
+            .unwrap();
+
+        assert!((covariance[0][0] - two_pass_covariance(&xs, &xs)).abs() < EPSILON);
+        assert!((covariance[1][1] - two_pass_covariance(&ys, &ys)).abs() < EPSILON);
+        assert!((covariance[0][1] - two_pass_covariance(&xs, &ys)).abs() < EPSILON);
+        assert_eq!(covariance[0][1], covariance[1][0]);
+    }
+
+    #[test]
+    fn test_covariance_matrix_two_pass() {
+        let data: [[f64; 3]; 8] = [
+            [0.3, 1e6 + 1., -4.],
+            [1.7, 1e6 + 3., -2.],
+            [2.2, 1e6 + 2., 0.],
+            [0.9, 1e6 + 8., 3.],
+            [4.1, 1e6 + 5., 1.],
+            [3.3, 1e6 + 7., -1.],
+            [2.8, 1e6 + 4., 5.],
+            [1.0, 1e6 + 6., 2.],
+        ];
+        let columns: [Vec<f64>; 3] =
+            std::array::from_fn(|i| data.iter().map(|row| row[i]).collect());
+
+        let result = data
+            .into_iter()
+            .reduce_with::<Option<CovarianceMatrix<3>>>()
+            .unwrap();
+
+        for i in 0..3 {
+            assert!((result.mean[i] - two_pass_mean(&columns[i])).abs() < EPSILON);
+            for j in 0..3 {
+                let expected = two_pass_covariance(&columns[i], &columns[j]);
+                assert!(
+                    (result.covariance[i][j] - expected).abs() < EPSILON,
+                    "covariance[{i}][{j}] = {}, expected {expected}",
+                    result.covariance[i][j]
+                );
+            }
+        }
+
+        let merged = par_reduce_slice::<Option<CovarianceMatrix<3>>, _>(&data, 3, 0).unwrap();
+        for i in 0..3 {
+            assert!((merged.mean[i] - result.mean[i]).abs() < EPSILON);
+            for j in 0..3 {
+                assert!((merged.covariance[i][j] - result.covariance[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+}
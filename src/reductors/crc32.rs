@@ -0,0 +1,65 @@
+/// The [CRC-32] checksum (as used by e.g. zlib, PNG and Ethernet), with the reflected
+/// `0xEDB88320` polynomial, computed one byte at a time using a precomputed table.
+///
+/// [CRC-32]: https://en.wikipedia.org/wiki/Cyclic_redundancy_check
+#[derive(Debug, Clone, Copy)]
+pub struct Crc32(u32);
+
+impl Crc32 {
+    const POLYNOMIAL: u32 = 0xedb8_8320;
+
+    const TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ Self::POLYNOMIAL
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+
+    #[inline]
+    pub fn update(&mut self, byte: u8) {
+        self.0 = Self::TABLE[usize::from(self.0 as u8 ^ byte)] ^ (self.0 >> 8);
+    }
+
+    pub fn finish(&self) -> u32 {
+        !self.0
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self(!0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_reference() {
+        let crc = |bytes: &[u8]| {
+            let mut crc = Crc32::default();
+            for &byte in bytes {
+                crc.update(byte);
+            }
+            crc.finish()
+        };
+
+        assert_eq!(crc(b""), 0);
+        assert_eq!(crc(b"a"), 0xe8b7_be43);
+        assert_eq!(crc(b"123456789"), 0xcbf4_3926);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::Reductor;
+
+/// Reductor that computes an exponentially time-decayed sum of the `(timestamp, value)` pairs
+/// yielded by an iterator, where each value's contribution halves every `half_life`, relative
+/// to the newest timestamp seen (the reference time).
+///
+/// That is, the result is `Σ vᵢ * 2^(-(reference - tᵢ) / half_life)`. The sum is kept relative
+/// to the reference time, so when a newer timestamp arrives, the sum is rescaled once, while
+/// an item that is older than the reference time (i.e. items may arrive out of order) only has
+/// its own contribution decayed.
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and both
+/// timestamps and values can be any type that implements [`Into<F>`]. The half-life (in the same
+/// units as the timestamps) must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with).
+///
+/// # Panics
+/// Panics if the half-life isn't positive.
+///
+/// Panics if reduced without a half-life (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{DecayedCount, DecayedSum, Reduce, Reductors};
+///
+/// // (seconds, bytes transferred)
+/// let transfers = [(0, 800), (60, 400), (120, 100)];
+///
+/// let Reductors((DecayedSum::<f64> { value: bytes, reference }, DecayedCount { count, .. })) =
+///     transfers.into_iter().fold_with((60.0.into(), 60.0.into()));
+///
+/// assert_eq!(reference, Some(120.));
+/// assert_eq!(bytes, 800. / 4. + 400. / 2. + 100.);
+/// assert_eq!(count, 1. / 4. + 1. / 2. + 1.);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DecayedSum<F> {
+    /// Decayed sum of values, relative to the reference time.
+    pub value: F,
+    /// Newest timestamp yielded by iterator, or `None` if there were no items.
+    pub reference: Option<F>,
+}
+
+/// Reductor that computes an exponentially time-decayed count of the `(timestamp, value)` pairs
+/// yielded by an iterator (ignoring the values), where each item's contribution halves every
+/// `half_life`, relative to the newest timestamp seen (the reference time).
+///
+/// This is a [`DecayedSum<f64>`] where every value is `1`, see it for details. Items have the
+/// same shape as `DecayedSum`'s, so both can reduce the same iterator (e.g. with
+/// [`Reductors`](crate::Reductors)).
+///
+/// # Panics
+/// Panics if the half-life isn't positive.
+///
+/// Panics if reduced without a half-life (see [`fold_with`](crate::Reduce::fold_with)).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DecayedCount {
+    /// Decayed count of items, relative to the reference time.
+    pub count: f64,
+    /// Newest timestamp yielded by iterator, or `None` if there were no items.
+    pub reference: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<F> {
+    half_life: F,
+    value: F,
+    reference: Option<F>,
+}
+
+macro_rules! impl_decayed_sum {
+    ($f:ty) => {
+        impl From<$f> for State<$f> {
+            fn from(half_life: $f) -> Self {
+                assert!(
+                    half_life > 0.,
+                    "half-life must be positive, got {half_life}"
+                );
+
+                Self {
+                    half_life,
+                    value: 0.,
+                    reference: None,
+                }
+            }
+        }
+
+        impl State<$f> {
+            #[inline]
+            fn add(mut self, timestamp: $f, value: $f) -> Self {
+                match self.reference {
+                    Some(reference) if timestamp > reference => {
+                        self.value *= (-(timestamp - reference) / self.half_life).exp2();
+                        self.value += value;
+                        self.reference = Some(timestamp);
+                    }
+                    Some(reference) => {
+                        self.value += value * (-(reference - timestamp) / self.half_life).exp2();
+                    }
+                    None => {
+                        self.value = value;
+                        self.reference = Some(timestamp);
+                    }
+                }
+                self
+            }
+        }
+
+        impl<T, V> Reductor<(T, V)> for DecayedSum<$f>
+        where
+            T: Into<$f>,
+            V: Into<$f>,
+        {
+            type State = State<$f>;
+
+            fn new(_: (T, V)) -> Self::State {
+                panic!("`DecayedSum` must be used with `fold_with`, providing a half-life")
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, (timestamp, value): (T, V)) -> Self::State {
+                state.add(timestamp.into(), value.into())
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self {
+                    value: state.value,
+                    reference: state.reference,
+                }
+            }
+        }
+    };
+}
+
+impl_decayed_sum!(f32);
+impl_decayed_sum!(f64);
+
+impl<T, A> Reductor<(T, A)> for DecayedCount
+where
+    T: Into<f64>,
+{
+    type State = State<f64>;
+
+    fn new(_: (T, A)) -> Self::State {
+        panic!("`DecayedCount` must be used with `fold_with`, providing a half-life")
+    }
+
+    #[inline]
+    fn reduce(state: Self::State, (timestamp, _): (T, A)) -> Self::State {
+        state.add(timestamp.into(), 1.)
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            count: state.value,
+            reference: state.reference,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    const HALF_LIFE: f64 = 30.;
+
+    const EVENTS: [(f64, f64); 8] = [
+        (0., 5.),
+        (10., 1.),
+        (12., 2.5),
+        (45., 4.),
+        (45., 1.),
+        (90., 0.5),
+        (100., 3.),
+        (160., 2.),
+    ];
+
+    fn brute_force(events: &[(f64, f64)]) -> (f64, f64, f64) {
+        let reference = events.iter().map(|&(t, _)| t).fold(f64::MIN, f64::max);
+        let weight = |t: f64| 0.5f64.powf((reference - t) / HALF_LIFE);
+        (
+            events.iter().map(|&(t, v)| v * weight(t)).sum(),
+            events.iter().map(|&(t, _)| weight(t)).sum(),
+            reference,
+        )
+    }
+
+    fn check(events: &[(f64, f64)]) {
+        let (expected_sum, expected_count, expected_reference) = brute_force(events);
+
+        let DecayedSum::<f64> { value, reference } = events.iter().copied().fold_with(HALF_LIFE);
+        assert!(
+            (value - expected_sum).abs() < 1e-12,
+            "{value} != {expected_sum}"
+        );
+        assert_eq!(reference, Some(expected_reference));
+
+        let DecayedCount { count, reference } = events.iter().copied().fold_with(HALF_LIFE);
+        assert!(
+            (count - expected_count).abs() < 1e-12,
+            "{count} != {expected_count}"
+        );
+        assert_eq!(reference, Some(expected_reference));
+    }
+
+    #[test]
+    fn test_decayed_in_order() {
+        check(&EVENTS);
+    }
+
+    #[test]
+    fn test_decayed_out_of_order() {
+        let mut events = EVENTS;
+        events.reverse();
+        check(&events);
+
+        events.swap(1, 5);
+        events.swap(0, 3);
+        check(&events);
+    }
+
+    #[test]
+    fn test_decayed_empty() {
+        let DecayedSum::<f32> { value, reference } = std::iter::empty::<(u8, u8)>().fold_with(1.);
+        assert_eq!((value, reference), (0., None));
+
+        let DecayedCount { count, reference } = std::iter::empty::<(u32, ())>().fold_with(1.);
+        assert_eq!((count, reference), (0., None));
+    }
+
+    #[test]
+    #[should_panic = "half-life must be positive"]
+    fn test_decayed_zero_half_life() {
+        let _: DecayedCount = EVENTS.into_iter().fold_with(0.);
+    }
+}
@@ -0,0 +1,131 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes both the number of distinct items yielded by an iterator, and the
+/// [Shannon entropy] of their distribution.
+///
+/// Both are derived from the same item frequencies, so this only builds a single [`HashMap`] of
+/// counts, rather than pairing [`CountDistinct`](crate::CountDistinct) with another reductor that
+/// builds its own (e.g. with [`Reductors`](crate::Reductors)). The entropy is between zero (a single distinct
+/// item, or no items at all) and `log₂(distinct)`, when all items are equally frequent.
+///
+/// [Shannon entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+///
+/// # Examples
+/// ```rust
+/// use reductor::{DiversitySummary, Reduce};
+///
+/// let DiversitySummary { distinct, entropy_bits } =
+///     ["GET", "POST", "GET", "PUT"].into_iter().reduce_with();
+///
+/// assert_eq!(distinct, 3);
+/// assert_eq!(entropy_bits, 1.5);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DiversitySummary {
+    /// Number of distinct items yielded by iterator.
+    pub distinct: usize,
+    /// Shannon entropy of the distribution of items, in bits.
+    pub entropy_bits: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct State<T>(HashMap<T, usize>);
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<T> Reductor<T> for DiversitySummary
+where
+    T: Eq + Hash,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(State(mut counts): Self::State, item: T) -> Self::State {
+        *counts.entry(item).or_default() += 1;
+        State(counts)
+    }
+
+    fn into_result(State(counts): Self::State) -> Self {
+        let total = counts.values().sum::<usize>() as f64;
+
+        Self {
+            distinct: counts.len(),
+            entropy_bits: counts
+                .values()
+                .map(|&count| {
+                    let p = count as f64 / total;
+                    -p * p.log2()
+                })
+                .sum(),
+        }
+    }
+}
+
+impl<T> MergeReductor<T> for DiversitySummary
+where
+    T: Eq + Hash,
+{
+    fn merge(State(mut left): Self::State, State(right): Self::State) -> Self::State {
+        for (item, count) in right {
+            *left.entry(item).or_default() += count;
+        }
+        State(left)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_diversity_summary_uniform() {
+        let DiversitySummary {
+            distinct,
+            entropy_bits,
+        } = (0..64).flat_map(|i| [i; 3]).reduce_with();
+        assert_eq!(distinct, 64);
+        assert!((entropy_bits - 6.).abs() < 1e-12, "{entropy_bits}");
+    }
+
+    #[test]
+    fn test_diversity_summary_skewed() {
+        // p = [1/2, 1/4, 1/8, 1/8]
+        let labels = [0, 0, 0, 0, 1, 1, 2, 3];
+        let DiversitySummary {
+            distinct,
+            entropy_bits,
+        } = labels.into_iter().reduce_with();
+        assert_eq!(distinct, 4);
+        assert_eq!(entropy_bits, 1.75);
+        assert!(entropy_bits < 4f64.log2());
+
+        let summary: DiversitySummary = ["x"; 10].into_iter().reduce_with();
+        assert_eq!(
+            summary,
+            DiversitySummary {
+                distinct: 1,
+                entropy_bits: 0.
+            }
+        );
+    }
+
+    #[test]
+    fn test_diversity_summary_empty() {
+        let summary: DiversitySummary = std::iter::empty::<u8>().reduce_with();
+        assert_eq!(summary, DiversitySummary::default());
+        assert_eq!((summary.distinct, summary.entropy_bits), (0, 0.));
+    }
+}
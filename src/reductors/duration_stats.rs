@@ -0,0 +1,159 @@
+use std::{borrow::Borrow, time::Duration};
+
+use crate::{MergeReductor, Reductor};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Reductor that computes the minimum, mean, maximum and total of the [`Duration`]s (or
+/// references to them) yielded by an iterator, in a single pass.
+///
+/// The total is accumulated as [`u128`] nanoseconds, so neither it nor the mean can overflow
+/// during the reduction, but like [`DurationSum`](crate::DurationSum), the resulting `total`
+/// saturates at [`Duration::MAX`]. The mean is rounded down to the nearest nanosecond.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use reductor::{DurationStats, Reduce};
+///
+/// let latencies = [12, 40, 8, 20].map(Duration::from_millis);
+///
+/// let stats: DurationStats = latencies.iter().reduce_with();
+///
+/// assert_eq!(stats.min, Some(Duration::from_millis(8)));
+/// assert_eq!(stats.mean, Some(Duration::from_millis(20)));
+/// assert_eq!(stats.max, Some(Duration::from_millis(40)));
+/// assert_eq!(stats.total, Duration::from_millis(80));
+/// assert_eq!(stats.count, 4);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationStats {
+    /// Shortest duration yielded by iterator, or `None` if there were no items.
+    pub min: Option<Duration>,
+    /// Mean of durations yielded by iterator, or `None` if there were no items.
+    pub mean: Option<Duration>,
+    /// Longest duration yielded by iterator, or `None` if there were no items.
+    pub max: Option<Duration>,
+    /// Sum of durations yielded by iterator.
+    pub total: Duration,
+    /// Number of durations yielded by iterator.
+    pub count: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct State {
+    bounds: Option<(Duration, Duration)>,
+    nanos: u128,
+    count: usize,
+}
+
+fn from_nanos(nanos: u128) -> Duration {
+    u64::try_from(nanos / NANOS_PER_SEC).map_or(Duration::MAX, |secs| {
+        Duration::new(secs, (nanos % NANOS_PER_SEC) as u32)
+    })
+}
+
+impl<A> Reductor<A> for DurationStats
+where
+    A: Borrow<Duration>,
+{
+    type State = State;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: A) -> Self::State {
+        let item = *item.borrow();
+        state.bounds = Some(match state.bounds {
+            Some((min, max)) => (min.min(item), max.max(item)),
+            None => (item, item),
+        });
+        state.nanos += item.as_nanos();
+        state.count += 1;
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        let (min, max) = state.bounds.unzip();
+        Self {
+            min,
+            mean: (state.count != 0).then(|| from_nanos(state.nanos / state.count as u128)),
+            max,
+            total: from_nanos(state.nanos),
+            count: state.count,
+        }
+    }
+}
+
+impl<A> MergeReductor<A> for DurationStats
+where
+    A: Borrow<Duration>,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        State {
+            bounds: match (left.bounds, right.bounds) {
+                (Some((left_min, left_max)), Some((right_min, right_max))) => {
+                    Some((left_min.min(right_min), left_max.max(right_max)))
+                }
+                (bounds, None) | (None, bounds) => bounds,
+            },
+            nanos: left.nanos + right.nanos,
+            count: left.count + right.count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_duration_stats() {
+        let durations = [
+            Duration::from_micros(1500),
+            Duration::from_nanos(1),
+            Duration::from_secs(2),
+            Duration::from_millis(250),
+            Duration::from_nanos(999_999_999),
+        ];
+
+        let stats: DurationStats = durations.into_iter().reduce_with();
+        let total: Duration = durations.iter().sum();
+        assert_eq!(
+            stats,
+            DurationStats {
+                min: Some(Duration::from_nanos(1)),
+                mean: Some(total / 5),
+                max: Some(Duration::from_secs(2)),
+                total,
+                count: 5,
+            }
+        );
+        assert_eq!(total, Duration::new(3, 251_500_000));
+
+        let merged: DurationStats = crate::parallel::par_reduce_slice(&durations, 2, 0);
+        assert_eq!(merged, stats);
+    }
+
+    #[test]
+    fn test_duration_stats_no_overflow() {
+        let stats: DurationStats = [Duration::MAX; 3].iter().reduce_with();
+        assert_eq!(stats.mean, Some(Duration::MAX));
+        assert_eq!(stats.total, Duration::MAX);
+    }
+
+    #[test]
+    fn test_duration_stats_empty() {
+        let stats: DurationStats = std::iter::empty::<Duration>().reduce_with();
+        assert_eq!(stats, DurationStats::default());
+        assert_eq!((stats.min, stats.mean, stats.max), (None, None, None));
+        assert_eq!((stats.total, stats.count), (Duration::ZERO, 0));
+    }
+}
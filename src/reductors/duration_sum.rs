@@ -0,0 +1,97 @@
+use std::{borrow::Borrow, time::Duration};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that adds the [`Duration`]s (or references to them) yielded by an iterator.
+///
+/// Unlike [`Duration`]'s implementation of [`iter::Sum`](std::iter::Sum) (and thus,
+/// [`Sum<Duration>`](crate::Sum)), which panics on overflow, the total saturates at
+/// [`Duration::MAX`].
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use reductor::{DurationSum, Reduce};
+///
+/// let timings = [Duration::from_millis(250), Duration::from_millis(1500)];
+///
+/// let DurationSum(total) = timings.iter().reduce_with();
+/// assert_eq!(total, Duration::from_millis(1750));
+///
+/// let DurationSum(total) = [Duration::MAX, Duration::from_secs(1)].into_iter().reduce_with();
+/// assert_eq!(total, Duration::MAX);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DurationSum(pub Duration);
+
+impl<A> Reductor<A> for DurationSum
+where
+    A: Borrow<Duration>,
+{
+    type State = Duration;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        *item.borrow()
+    }
+
+    #[inline]
+    fn reduce(state: Self::State, item: A) -> Self::State {
+        state.saturating_add(*item.borrow())
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state)
+    }
+}
+
+impl<A> MergeReductor<A> for DurationSum
+where
+    A: Borrow<Duration>,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        left.saturating_add(right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_duration_sum() {
+        let timings = [
+            Duration::from_millis(1200),
+            Duration::from_micros(350),
+            Duration::from_secs(3),
+            Duration::from_nanos(999_999_999),
+        ];
+
+        let DurationSum(total) = timings.into_iter().reduce_with();
+        assert_eq!(total, timings.iter().sum());
+        assert_eq!(total, Duration::new(5, 200_349_999));
+
+        let DurationSum(total) = std::iter::empty::<Duration>().reduce_with();
+        assert_eq!(total, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_duration_sum_saturates() {
+        let DurationSum(total) = [
+            Duration::from_secs(1),
+            Duration::MAX,
+            Duration::from_secs(1),
+        ]
+        .iter()
+        .reduce_with();
+        assert_eq!(total, Duration::MAX);
+
+        let DurationSum(total) = crate::parallel::par_reduce_slice(&[Duration::MAX; 10], 4, 0);
+        assert_eq!(total, Duration::MAX);
+    }
+}
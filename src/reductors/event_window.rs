@@ -0,0 +1,155 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that finds the earliest and latest of the timestamps yielded by an iterator,
+/// along with the number of timestamps.
+///
+/// This is equivalent to combining [`MinMax`](crate::MinMax) with [`Count`](crate::Count)
+/// (e.g. with [`Reductors`](crate::Reductors)), but only clones the first item, rather than
+/// every item. `earliest` and `latest` are `None` for an empty iterator, while `count` is
+/// still reported as zero.
+///
+/// # Examples
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+///
+/// use reductor::{EventWindow, Reduce};
+///
+/// let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+/// let events = [30, 0, 45, 10].map(|secs| start + Duration::from_secs(secs));
+///
+/// let EventWindow { earliest, latest, count } = events.into_iter().reduce_with();
+///
+/// assert_eq!(earliest, Some(start));
+/// assert_eq!(latest, Some(start + Duration::from_secs(45)));
+/// assert_eq!(count, 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventWindow<T> {
+    /// Smallest item yielded by iterator.
+    pub earliest: Option<T>,
+    /// Largest item yielded by iterator.
+    pub latest: Option<T>,
+    /// Number of items yielded by iterator.
+    pub count: usize,
+}
+
+impl<T> Default for EventWindow<T> {
+    fn default() -> Self {
+        Self {
+            earliest: None,
+            latest: None,
+            count: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    bounds: Option<(T, T)>,
+    count: usize,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            bounds: None,
+            count: 0,
+        }
+    }
+}
+
+impl<T> Reductor<T> for EventWindow<T>
+where
+    T: Ord + Clone,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        State {
+            bounds: Some((item.clone(), item)),
+            count: 1,
+        }
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        match &mut state.bounds {
+            Some((earliest, _)) if item < *earliest => *earliest = item,
+            Some((_, latest)) if item > *latest => *latest = item,
+            Some(_) => {}
+            None => return Self::new(item),
+        }
+        state.count += 1;
+        state
+    }
+
+    #[inline]
+    fn into_result(State { bounds, count }: Self::State) -> Self {
+        let (earliest, latest) = bounds.unzip();
+        Self {
+            earliest,
+            latest,
+            count,
+        }
+    }
+}
+
+impl<T> MergeReductor<T> for EventWindow<T>
+where
+    T: Ord + Clone,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        State {
+            bounds: match (left.bounds, right.bounds) {
+                (Some((left_earliest, left_latest)), Some((right_earliest, right_latest))) => {
+                    Some((
+                        left_earliest.min(right_earliest),
+                        left_latest.max(right_latest),
+                    ))
+                }
+                (bounds, None) | (None, bounds) => bounds,
+            },
+            count: left.count + right.count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_event_window() {
+        let timestamps: Vec<u64> = vec![
+            1_700_000_120,
+            1_700_000_005,
+            1_700_000_300,
+            1_700_000_005,
+            1_700_000_299,
+        ];
+
+        let EventWindow {
+            earliest,
+            latest,
+            count,
+        } = timestamps.iter().reduce_with();
+
+        assert_eq!(earliest, Some(&1_700_000_005));
+        assert_eq!(latest, Some(&1_700_000_300));
+        assert_eq!(count, timestamps.len());
+    }
+
+    #[test]
+    fn test_event_window_empty() {
+        let window: EventWindow<u64> = std::iter::empty().reduce_with();
+        assert_eq!(window, EventWindow::default());
+        assert_eq!((window.earliest, window.count), (None, 0));
+
+        let window: EventWindow<_> = std::iter::once("2024-01-01T00:00:00Z").reduce_with();
+        assert_eq!(window.earliest, window.latest);
+        assert_eq!(window.count, 1);
+    }
+}
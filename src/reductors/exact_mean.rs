@@ -0,0 +1,205 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the exact [arithmetic mean] of the integers yielded by an iterator,
+/// as a [`BigRational`].
+///
+/// Unlike [`Mean`](crate::Mean), which rounds after every item, the sum is accumulated exactly
+/// in an [`i128`], and if that ever overflows, the sum is transparently upgraded to a [`BigInt`],
+/// so the reduction never overflows, and never fails. The iterator's item type can be any type
+/// that implements [`Into<i128>`] (i.e. any primitive integer that fits in an `i128`).
+///
+/// Requires the `num-rational` feature.
+///
+/// [arithmetic mean]: https://en.wikipedia.org/wiki/Arithmetic_mean
+///
+/// # Examples
+/// ```rust
+/// use num_rational::BigRational;
+/// use reductor::{ExactMean, Reduce};
+///
+/// let mean: ExactMean = [1u8, 1, 2].into_iter().reduce_with::<Option<_>>().unwrap();
+///
+/// assert_eq!(mean.0, BigRational::new(4.into(), 3.into()));
+/// assert_eq!(mean.floor(), 1.into());
+/// assert_eq!(mean.to_f64(), 4. / 3.);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExactMean(pub BigRational);
+
+impl ExactMean {
+    /// Largest integer less than or equal to the mean.
+    pub fn floor(&self) -> BigInt {
+        self.0.floor().to_integer()
+    }
+
+    /// Integer nearest to the mean, with halfway cases rounded away from zero.
+    pub fn round(&self) -> BigInt {
+        self.0.round().to_integer()
+    }
+
+    /// The mean, converted (lossily) to the nearest [`f64`].
+    pub fn to_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Total {
+    Small(i128),
+    Big(BigInt),
+}
+
+impl Total {
+    fn add(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Small(left), Self::Small(right)) => left
+                .checked_add(right)
+                .map_or_else(|| Self::Big(BigInt::from(left) + right), Self::Small),
+            (Self::Big(left), Self::Small(right)) | (Self::Small(right), Self::Big(left)) => {
+                Self::Big(left + right)
+            }
+            (Self::Big(left), Self::Big(right)) => Self::Big(left + right),
+        }
+    }
+}
+
+impl From<Total> for BigInt {
+    fn from(total: Total) -> Self {
+        match total {
+            Total::Small(total) => total.into(),
+            Total::Big(total) => total,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    total: Total,
+    count: usize,
+}
+
+impl<T> Reductor<T> for ExactMean
+where
+    T: Into<i128>,
+{
+    type State = NonEmptyState<State>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        NonEmptyState(State {
+            total: Total::Small(item.into()),
+            count: 1,
+        })
+    }
+
+    #[inline]
+    fn reduce(NonEmptyState(state): Self::State, item: T) -> Self::State {
+        NonEmptyState(State {
+            total: state.total.add(Total::Small(item.into())),
+            count: state.count + 1,
+        })
+    }
+
+    fn into_result(NonEmptyState(state): Self::State) -> Self {
+        Self(BigRational::new(state.total.into(), state.count.into()))
+    }
+}
+
+impl<T> MergeReductor<T> for ExactMean
+where
+    T: Into<i128>,
+{
+    fn merge(NonEmptyState(left): Self::State, NonEmptyState(right): Self::State) -> Self::State {
+        NonEmptyState(State {
+            total: left.total.add(right.total),
+            count: left.count + right.count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use crate::{Mean, Reduce};
+
+    use super::*;
+
+    fn exact_mean<T: Into<i128>>(items: impl IntoIterator<Item = T>) -> ExactMean {
+        items.into_iter().reduce_with::<Option<_>>().unwrap()
+    }
+
+    #[test]
+    fn test_exact_mean_thirds() {
+        let mean = exact_mean([0, 0, 1]);
+        assert_eq!(mean.0, BigRational::new(1.into(), 3.into()));
+        assert_eq!(
+            mean.0 * BigInt::from(3),
+            BigRational::from_integer(1.into())
+        );
+
+        let mean = exact_mean([-7i64, 0, 0]);
+        assert_eq!(mean.0, BigRational::new((-7).into(), 3.into()));
+        assert_eq!(mean.floor(), (-3).into());
+        assert_eq!(mean.round(), (-2).into());
+    }
+
+    #[test]
+    fn test_exact_mean_rounding() {
+        let mean = exact_mean([1, 2]);
+        assert_eq!((mean.floor(), mean.round()), (1.into(), 2.into()));
+
+        let mean = exact_mean([-1, -2]);
+        assert_eq!((mean.floor(), mean.round()), ((-2).into(), (-2).into()));
+    }
+
+    #[test]
+    fn test_exact_mean_overflow() {
+        let mean = exact_mean([i128::MAX, i128::MAX, i128::MAX - 3]);
+        assert_eq!(mean.0, BigRational::from_integer((i128::MAX - 1).into()));
+
+        let mean = exact_mean([i128::MIN, i128::MIN, 2, 2]);
+        assert_eq!(
+            mean.0,
+            BigRational::from_integer((i128::MIN / 2 + 1).into())
+        );
+
+        let data = [u64::MAX; 1000];
+        let merged: ExactMean =
+            crate::parallel::par_reduce_slice::<Option<_>, _>(&data, 4, 0).unwrap();
+        assert_eq!(merged.0, BigRational::from_integer(u64::MAX.into()));
+    }
+
+    #[test]
+    fn test_exact_mean_to_f64() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for len in [1, 10, 1000] {
+            let data: Vec<i32> = (0..len)
+                .map(|_| rng.random_range(-1_000_000..1_000_000))
+                .collect();
+
+            let exact = exact_mean(data.iter().copied()).to_f64();
+
+            // the sum fits in an `f64` exactly, so this is only rounded once
+            let sum: i64 = data.iter().copied().map(i64::from).sum();
+            let rounded_once = sum as f64 / len as f64;
+            assert!(
+                (exact - rounded_once).abs() <= f64::EPSILON * exact.abs(),
+                "{exact} != {rounded_once}"
+            );
+
+            // `Mean` rounds after every item, so it drifts further from the exact mean
+            let Mean::<f64>(mean) = data.iter().copied().reduce_with::<Option<_>>().unwrap();
+            assert!(
+                (exact - mean).abs() <= 1e-9 * exact.abs(),
+                "{exact} != {mean}"
+            );
+        }
+    }
+}
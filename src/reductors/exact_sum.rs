@@ -0,0 +1,274 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the correctly rounded sum of the floats yielded by an iterator,
+/// i.e. the exact sum of all items, rounded once to the nearest float.
+///
+/// Unlike [`Sum`](crate::Sum), whose rounding errors accumulate and depend on the order
+/// of the items, the result does not depend on the order of the items at all, so it is
+/// reproducible (and exact whenever the exact sum is representable).
+///
+/// This uses Shewchuk's algorithm (like Python's [`math.fsum`]): the exact sum is kept as an
+/// expansion of non-overlapping partial sums, and each item is added to it using error-free
+/// transformations (two-sum). Since the partials don't overlap, their number is bounded by
+/// the exponent range of the float type divided by its mantissa width (about 40 for [`f64`]
+/// and 12 for [`f32`]), though in practice it is usually just a handful. Each item takes time
+/// proportional to the number of partials.
+///
+/// Like `Sum`, the generic type `F` must be one of [`f32`] or [`f64`], and the iterator's item
+/// type can be any type that implements [`Into<F>`]. If any item is infinite or NaN, the result
+/// is the sum of those items (i.e. infinite or NaN). If the partial sums overflow, the overflow
+/// is treated like an infinite item, so the result is infinite (or NaN, if an infinity of the
+/// opposite sign is also yielded), even if later items would bring the exact sum back in range.
+///
+/// [`math.fsum`]: https://docs.python.org/3/library/math.html#math.fsum
+///
+/// # Examples
+/// ```rust
+/// use reductor::{ExactSum, Reduce, Sum};
+///
+/// let values = [1e16, 1., -1e16];
+///
+/// let Sum::<f64>(sum) = values.into_iter().reduce_with();
+/// let ExactSum::<f64>(exact) = values.into_iter().reduce_with();
+///
+/// assert_eq!(sum, 0.);
+/// assert_eq!(exact, 1.);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ExactSum<F>(pub F);
+
+#[derive(Debug, Clone)]
+pub struct State<F> {
+    /// Non-overlapping partial sums, in increasing order of magnitude.
+    partials: Vec<F>,
+    /// Sum of non-finite items.
+    special: Option<F>,
+}
+
+impl<F> Default for State<F> {
+    fn default() -> Self {
+        Self {
+            partials: Vec::new(),
+            special: None,
+        }
+    }
+}
+
+macro_rules! impl_exact_sum {
+    ($f:ty) => {
+        impl State<$f> {
+            fn add_special(&mut self, x: $f) {
+                self.special = Some(self.special.map_or(x, |special| special + x));
+            }
+
+            fn add(&mut self, mut x: $f) {
+                if !x.is_finite() {
+                    self.add_special(x);
+                    return;
+                }
+
+                let mut len = 0;
+                for i in 0..self.partials.len() {
+                    let mut y = self.partials[i];
+                    if x.abs() < y.abs() {
+                        (x, y) = (y, x);
+                    }
+                    // Two-sum: `hi + lo == x + y` exactly.
+                    let hi = x + y;
+                    if !hi.is_finite() {
+                        // `lo` would be infinite too, so keep the overflow out of the partials.
+                        self.add_special(hi);
+                        self.partials.drain(len..=i);
+                        return;
+                    }
+                    let lo = y - (hi - x);
+                    if lo != 0. {
+                        self.partials[len] = lo;
+                        len += 1;
+                    }
+                    x = hi;
+                }
+                self.partials.truncate(len);
+                self.partials.push(x);
+            }
+
+            fn sum(self) -> $f {
+                if let Some(special) = self.special {
+                    return special;
+                }
+
+                let partials = self.partials;
+                let Some((&(mut hi), rest)) = partials.split_last() else {
+                    return 0.;
+                };
+
+                // Add the partials from the largest down, until the sum becomes inexact.
+                let mut lo = 0.;
+                let mut n = rest.len();
+                while n > 0 {
+                    n -= 1;
+                    let (x, y) = (hi, rest[n]);
+                    hi = x + y;
+                    lo = y - (hi - x);
+                    if lo != 0. {
+                        break;
+                    }
+                }
+
+                // `hi` was rounded to nearest-even, but if the remaining partials have the same
+                // sign as the rounding error, the exact sum is past the halfway point, so `hi`
+                // must be rounded in that direction instead.
+                if n > 0 && ((lo < 0. && rest[n - 1] < 0.) || (lo > 0. && rest[n - 1] > 0.)) {
+                    let y = lo * 2.;
+                    let x = hi + y;
+                    if y == x - hi {
+                        hi = x;
+                    }
+                }
+
+                hi
+            }
+        }
+
+        impl<T> Reductor<T> for ExactSum<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = State<$f>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, item: T) -> Self::State {
+                state.add(item.into());
+                state
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self(state.sum())
+            }
+        }
+
+        impl<T> MergeReductor<T> for ExactSum<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+                if let Some(special) = right.special {
+                    left.add(special);
+                }
+                for partial in right.partials {
+                    left.add(partial);
+                }
+                left
+            }
+        }
+    };
+}
+
+impl_exact_sum!(f32);
+impl_exact_sum!(f64);
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+
+    use crate::{parallel::par_reduce_slice, Reduce, Sum};
+
+    use super::*;
+
+    /// Integer-valued floats, so that their exact sum can be computed with integers.
+    fn pathological() -> Vec<f64> {
+        let mut values = Vec::new();
+        for i in 0..50 {
+            values.extend([1e16, 1., -1e16, 3., 1e16, -1e16, 1.]);
+            values.push(f64::from(i));
+        }
+        values
+    }
+
+    #[test]
+    fn test_exact_sum_permutations() {
+        let mut values = pathological();
+
+        let exact: i128 = values.iter().map(|&value| value as i128).sum();
+        let expected = exact as f64;
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut naive_sums = Vec::new();
+        for _ in 0..100 {
+            values.shuffle(&mut rng);
+
+            let ExactSum::<f64>(sum) = values.iter().copied().reduce_with();
+            assert_eq!(sum.to_bits(), expected.to_bits());
+
+            let Sum::<f64>(naive) = values.iter().copied().reduce_with();
+            naive_sums.push(naive.to_bits());
+        }
+
+        naive_sums.sort_unstable();
+        naive_sums.dedup();
+        assert!(naive_sums.len() > 1);
+    }
+
+    #[test]
+    fn test_exact_sum_rounding() {
+        // The exact sum is just past halfway between two floats, which requires looking
+        // beyond the two largest partials to round correctly.
+        let ExactSum::<f64>(sum) = [1., 2f64.powi(-53), 2f64.powi(-105)]
+            .into_iter()
+            .reduce_with();
+        assert_eq!(sum, 1. + f64::EPSILON);
+
+        let ExactSum::<f64>(sum) = [1., 2f64.powi(-53)].into_iter().reduce_with();
+        assert_eq!(sum, 1.);
+
+        let ExactSum::<f32>(sum) = [0.1f32; 10].into_iter().reduce_with();
+        assert_eq!(sum, (0.1f32 as f64 * 10.) as f32);
+    }
+
+    #[test]
+    fn test_exact_sum_special() {
+        let ExactSum::<f64>(sum) = std::iter::empty::<f64>().reduce_with();
+        assert_eq!(sum, 0.);
+
+        let ExactSum::<f64>(sum) = [1., f64::INFINITY, -3.].into_iter().reduce_with();
+        assert_eq!(sum, f64::INFINITY);
+
+        let ExactSum::<f64>(sum) = [f64::INFINITY, 1., f64::NEG_INFINITY]
+            .into_iter()
+            .reduce_with();
+        assert!(sum.is_nan());
+    }
+
+    #[test]
+    fn test_exact_sum_overflow() {
+        let ExactSum::<f64>(sum) = [f64::MAX, f64::MAX].into_iter().reduce_with();
+        assert_eq!(sum, f64::INFINITY);
+
+        let ExactSum::<f64>(sum) = [-f64::MAX, 1., -f64::MAX].into_iter().reduce_with();
+        assert_eq!(sum, f64::NEG_INFINITY);
+
+        // an intermediate overflow isn't undone by later items
+        let ExactSum::<f64>(sum) = [f64::MAX, f64::MAX, -f64::MAX].into_iter().reduce_with();
+        assert_eq!(sum, f64::INFINITY);
+
+        let ExactSum::<f32>(merged) = par_reduce_slice(&[f32::MAX, 1., f32::MAX, 2.], 2, 0);
+        assert_eq!(merged, f32::INFINITY);
+    }
+
+    #[test]
+    fn test_exact_sum_merge() {
+        let values = pathological();
+
+        let ExactSum::<f64>(sequential) = values.iter().copied().reduce_with();
+        let ExactSum::<f64>(parallel) = par_reduce_slice(&values, 4, 0);
+        assert_eq!(parallel.to_bits(), sequential.to_bits());
+    }
+}
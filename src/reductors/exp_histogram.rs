@@ -0,0 +1,222 @@
+use crate::Reductor;
+
+/// Reductor that counts values yielded by an iterator into exponentially growing buckets
+/// (like Prometheus or HDR histograms), which is well suited for latencies and other
+/// long-tailed distributions.
+///
+/// The buckets must be configured as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with) with a `(min, factor, buckets)` tuple:
+/// the `i`th bucket spans `min * factor^i..min * factor^(i + 1)`. Values below `min` are
+/// counted as underflow, and values at or above the upper edge of the last bucket are counted
+/// as overflow. NaN values are ignored.
+///
+/// The edges are precomputed once (each with a single [`powi`](f64::powi), so that rounding
+/// errors don't accumulate), and values are assigned to buckets by a binary search over the
+/// edges, so every value lands in the bucket whose edges actually bracket it.
+///
+/// # Panics
+/// Panics if `min` isn't positive and finite, or if `factor` isn't greater than one.
+///
+/// Panics if reduced without buckets (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{ExpHistogram, Reduce};
+///
+/// let latencies_ms = [0.5, 1.2, 1.9, 3., 3.5, 7., 12., 40.];
+///
+/// let hist: ExpHistogram = latencies_ms.into_iter().fold_with((1., 2., 4));
+///
+/// assert_eq!(hist.edges, [1., 2., 4., 8., 16.]);
+/// assert_eq!(hist.counts, [2, 2, 1, 1]);
+/// assert_eq!((hist.underflow, hist.overflow), (1, 1));
+///
+/// let p50 = hist.p50().unwrap();
+/// assert!((2. ..4.).contains(&p50));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpHistogram {
+    /// Bucket edges, one more than the number of buckets, in ascending order.
+    pub edges: Vec<f64>,
+    /// Number of values in each bucket.
+    pub counts: Vec<u64>,
+    /// Number of values below the first edge.
+    pub underflow: u64,
+    /// Number of values at or above the last edge.
+    pub overflow: u64,
+}
+
+impl ExpHistogram {
+    /// Total number of values counted, including underflow and overflow.
+    pub fn total(&self) -> u64 {
+        self.underflow + self.counts.iter().sum::<u64>() + self.overflow
+    }
+
+    /// Approximate `q`-quantile of the counted values (`q` is clamped to `[0, 1]`),
+    /// or `None` if no values were counted.
+    ///
+    /// The bucket containing the quantile is found exactly, and the quantile is interpolated
+    /// linearly within that bucket. Quantiles that fall into the underflow or overflow are
+    /// reported as the first or last edge, respectively.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+
+        let target = (q.clamp(0., 1.) * total as f64).max(1.);
+        let mut cumulative = self.underflow as f64;
+        if cumulative >= target {
+            return self.edges.first().copied();
+        }
+
+        for (bucket, &count) in self.counts.iter().enumerate() {
+            let count = count as f64;
+            if cumulative + count >= target {
+                let (lower, upper) = (self.edges[bucket], self.edges[bucket + 1]);
+                return Some(lower + (upper - lower) * ((target - cumulative) / count));
+            }
+            cumulative += count;
+        }
+
+        self.edges.last().copied()
+    }
+
+    /// Approximate median, see [`quantile`](Self::quantile).
+    pub fn p50(&self) -> Option<f64> {
+        self.quantile(0.5)
+    }
+
+    /// Approximate 95th percentile, see [`quantile`](Self::quantile).
+    pub fn p95(&self) -> Option<f64> {
+        self.quantile(0.95)
+    }
+
+    /// Approximate 99th percentile, see [`quantile`](Self::quantile).
+    pub fn p99(&self) -> Option<f64> {
+        self.quantile(0.99)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State(ExpHistogram);
+
+impl From<(f64, f64, usize)> for State {
+    fn from((min, factor, buckets): (f64, f64, usize)) -> Self {
+        assert!(
+            min.is_finite() && min > 0.,
+            "`min` must be positive, got {min}"
+        );
+        assert!(
+            factor > 1.,
+            "`factor` must be greater than one, got {factor}"
+        );
+
+        Self(ExpHistogram {
+            edges: (0..=buckets).map(|i| min * factor.powi(i as i32)).collect(),
+            counts: vec![0; buckets],
+            underflow: 0,
+            overflow: 0,
+        })
+    }
+}
+
+impl<T> Reductor<T> for ExpHistogram
+where
+    T: Into<f64>,
+{
+    type State = State;
+
+    fn new(_: T) -> Self::State {
+        panic!("`ExpHistogram` must be used with `fold_with`, providing the bucket configuration")
+    }
+
+    #[inline]
+    fn reduce(State(mut hist): Self::State, item: T) -> Self::State {
+        let item = item.into();
+        if item.is_nan() {
+            return State(hist);
+        }
+
+        // Number of edges that are lower than or equal to `item`.
+        match hist.edges.partition_point(|&edge| edge <= item) {
+            0 => hist.underflow += 1,
+            n if n == hist.edges.len() => hist.overflow += 1,
+            n => hist.counts[n - 1] += 1,
+        }
+
+        State(hist)
+    }
+
+    #[inline]
+    fn into_result(State(hist): Self::State) -> Self {
+        hist
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_exp_histogram_buckets() {
+        let hist: ExpHistogram = [0.99, 1., 1.49, 1.5, 2.25, 3.375, 5.0624, 5.0625, f64::NAN]
+            .into_iter()
+            .fold_with((1., 1.5, 4));
+
+        assert_eq!(hist.edges, [1., 1.5, 2.25, 3.375, 5.0625]);
+        assert_eq!(hist.counts, [2, 1, 1, 2]);
+        assert_eq!((hist.underflow, hist.overflow), (1, 1));
+        assert_eq!(hist.total(), 8);
+    }
+
+    #[test]
+    fn test_exp_histogram_quantiles() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut latencies: Vec<f64> = (0..10_000)
+            .map(|_| {
+                // Mostly fast, with a long tail of slow requests.
+                let base = 5. + 20. * rng.random::<f64>();
+                if rng.random_bool(0.05) {
+                    base * 40.
+                } else {
+                    base
+                }
+            })
+            .collect();
+
+        let hist: ExpHistogram = latencies.iter().copied().fold_with((1., 2., 12));
+        latencies.sort_unstable_by(f64::total_cmp);
+
+        let bucket = |x: f64| hist.edges.partition_point(|&edge| edge <= x);
+        for (q, estimate) in [(0.5, hist.p50()), (0.95, hist.p95()), (0.99, hist.p99())] {
+            let estimate = estimate.unwrap();
+            let exact = latencies[(q * latencies.len() as f64).ceil() as usize - 1];
+            assert_eq!(bucket(estimate), bucket(exact), "q = {q}");
+            assert!(
+                hist.edges[bucket(exact) - 1] <= exact && exact < hist.edges[bucket(exact)],
+                "q = {q}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_exp_histogram_out_of_range_quantiles() {
+        let hist: ExpHistogram = [0.1, 0.2, 0.3, 100.].into_iter().fold_with((1., 2., 3));
+        assert_eq!(hist.p50(), Some(1.));
+        assert_eq!(hist.p99(), Some(8.));
+
+        let empty: ExpHistogram = std::iter::empty::<f64>().fold_with((1., 2., 3));
+        assert_eq!(empty.p50(), None);
+    }
+
+    #[test]
+    #[should_panic = "greater than one"]
+    fn test_exp_histogram_invalid_factor() {
+        let _: ExpHistogram = [1.].into_iter().fold_with((1., 1., 3));
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that finds both the most common and the least common of the items yielded by an
+/// iterator, along with their number of occurrences.
+///
+/// Both are found from the same item frequencies, so this only builds a single [`HashMap`] of
+/// counts, and only the two resulting items are cloned. If several items are tied for the
+/// highest (or lowest) count, the one that was yielded first wins at either end. For example,
+/// for `[a, b, b, a, c, d]`, `a` is the most common item (before `b`), and `c` is the least
+/// common item (before `d`). If there is only one distinct item, it is both the most and least
+/// common. Both are `None` if the iterator yields no items.
+///
+/// See [`ModeAndFrequencies`](crate::ModeAndFrequencies) for retaining the frequencies of all
+/// items.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{ExtremeFrequencies, Reduce};
+///
+/// let statuses = [200, 200, 404, 200, 500, 404, 200];
+///
+/// let ExtremeFrequencies { most_common, least_common } = statuses.into_iter().reduce_with();
+///
+/// assert_eq!(most_common, Some((200, 4)));
+/// assert_eq!(least_common, Some((500, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtremeFrequencies<T> {
+    /// Most common item, and its number of occurrences.
+    pub most_common: Option<(T, usize)>,
+    /// Least common item, and its number of occurrences.
+    pub least_common: Option<(T, usize)>,
+}
+
+impl<T> Default for ExtremeFrequencies<T> {
+    fn default() -> Self {
+        Self {
+            most_common: None,
+            least_common: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    /// Number of occurrences of each item, and the index at which it was first yielded.
+    frequencies: HashMap<T, (usize, usize)>,
+    len: usize,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            frequencies: HashMap::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T> Reductor<T> for ExtremeFrequencies<T>
+where
+    T: Eq + Hash + Clone,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        state.frequencies.entry(item).or_insert((0, state.len)).0 += 1;
+        state.len += 1;
+        state
+    }
+
+    fn into_result(State { frequencies, .. }: Self::State) -> Self {
+        let extreme = |(item, &(count, _)): (&T, _)| (item.clone(), count);
+
+        Self {
+            most_common: frequencies
+                .iter()
+                .max_by_key(|(_, &(count, first))| (count, Reverse(first)))
+                .map(extreme),
+            least_common: frequencies
+                .iter()
+                .min_by_key(|(_, &(count, first))| (count, first))
+                .map(extreme),
+        }
+    }
+}
+
+impl<T> MergeReductor<T> for ExtremeFrequencies<T>
+where
+    T: Eq + Hash + Clone,
+{
+    fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+        for (item, (count, first)) in right.frequencies {
+            match left.frequencies.entry(item) {
+                Entry::Occupied(mut entry) => entry.get_mut().0 += count,
+                Entry::Vacant(entry) => {
+                    entry.insert((count, left.len + first));
+                }
+            }
+        }
+        left.len += right.len;
+        left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_extreme_frequencies_skewed() {
+        let words = "the cat and the dog and the bird saw the cat".split(' ');
+
+        let ExtremeFrequencies {
+            most_common,
+            least_common,
+        } = words.clone().reduce_with();
+        assert_eq!(most_common, Some(("the", 4)));
+        assert_eq!(least_common, Some(("dog", 1)));
+
+        let words: Vec<_> = words.collect();
+        for threads in 2..=5 {
+            let merged: ExtremeFrequencies<_> =
+                crate::parallel::par_reduce_slice(&words, threads, 0);
+            assert_eq!(merged.most_common, most_common);
+            assert_eq!(merged.least_common, least_common);
+        }
+    }
+
+    #[test]
+    fn test_extreme_frequencies_ties() {
+        let ExtremeFrequencies {
+            most_common,
+            least_common,
+        } = ['a', 'b', 'b', 'a', 'c', 'd'].into_iter().reduce_with();
+        assert_eq!(most_common, Some(('a', 2)));
+        assert_eq!(least_common, Some(('c', 1)));
+
+        let ExtremeFrequencies {
+            most_common,
+            least_common,
+        } = [7; 5].into_iter().reduce_with();
+        assert_eq!(most_common, Some((7, 5)));
+        assert_eq!(least_common, Some((7, 5)));
+    }
+
+    #[test]
+    fn test_extreme_frequencies_empty() {
+        let extremes: ExtremeFrequencies<u8> = std::iter::empty().reduce_with();
+        assert_eq!(extremes, ExtremeFrequencies::default());
+        assert_eq!((extremes.most_common, extremes.least_common), (None, None));
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    hash::{Hash, Hasher},
+    iter::{self, empty, once},
+};
+
+use super::fnv::Fnv1a;
+use crate::Reductor;
+
+/// Reductor that adds items yielded by an iterator to each other (like [`Sum`](crate::Sum)),
+/// while also computing an order-sensitive fingerprint of all items.
+///
+/// The fingerprint is the 64-bit [FNV-1a] hash of all items, fed one after the other
+/// (using their [`Hash`] implementations) into a single hasher. Unlike hashing with
+/// [`DefaultHasher`](std::collections::hash_map::DefaultHasher), the fingerprint is stable
+/// across runs of the same build. It isn't stable across platforms, though: integers are hashed
+/// in native endianness, and [`Hash`] implementations feed lengths (e.g. of strings and slices) to
+/// the hasher as `usize`s, so fingerprints differ between 32-bit and 64-bit targets. The standard
+/// library also doesn't guarantee that its `Hash` implementations feed the same data to the hasher
+/// across Rust versions, so fingerprints shouldn't be persisted.
+/// FNV-1a is not a cryptographic hash, so the fingerprint is only suitable for detecting
+/// accidental changes to the input.
+///
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SumAndFingerprint};
+///
+/// let a: SumAndFingerprint<u32> = [1u32, 2, 3].into_iter().reduce_with();
+/// let b: SumAndFingerprint<u32> = [3u32, 2, 1].into_iter().reduce_with();
+///
+/// assert_eq!(a.sum, b.sum);
+/// assert_ne!(a.fingerprint, b.fingerprint);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SumAndFingerprint<T> {
+    /// Sum of items yielded by iterator.
+    pub sum: T,
+    /// Order-sensitive hash of items yielded by iterator.
+    pub fingerprint: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    sum: T,
+    hasher: Fnv1a,
+}
+
+impl<T> Default for State<T>
+where
+    T: iter::Sum,
+{
+    fn default() -> Self {
+        Self {
+            sum: empty::<T>().sum(),
+            hasher: Fnv1a::default(),
+        }
+    }
+}
+
+impl<T> Default for SumAndFingerprint<T>
+where
+    T: iter::Sum,
+{
+    #[inline]
+    fn default() -> Self {
+        let State { sum, hasher } = State::default();
+        Self {
+            sum,
+            fingerprint: hasher.finish(),
+        }
+    }
+}
+
+impl<A, T> Reductor<A> for SumAndFingerprint<T>
+where
+    A: Hash,
+    T: iter::Sum + iter::Sum<A>,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(State { sum, mut hasher }: Self::State, item: A) -> Self::State {
+        item.hash(&mut hasher);
+        State {
+            sum: once(sum).chain(once(once(item).sum())).sum(),
+            hasher,
+        }
+    }
+
+    #[inline]
+    fn into_result(State { sum, hasher }: Self::State) -> Self {
+        Self {
+            sum,
+            fingerprint: hasher.finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_sum_and_fingerprint() {
+        let data = [17u64, 4, 99, 4, 250];
+
+        let original: SumAndFingerprint<u64> = data.iter().reduce_with();
+        let again: SumAndFingerprint<u64> = data.iter().reduce_with();
+        assert_eq!(original, again);
+        assert_eq!(original.sum, 374);
+
+        let mut reordered = data;
+        reordered.swap(0, 2);
+        let reordered: SumAndFingerprint<u64> = reordered.iter().reduce_with();
+        assert_eq!(reordered.sum, original.sum);
+        assert_ne!(reordered.fingerprint, original.fingerprint);
+
+        let mut changed = data;
+        changed[1] += 1;
+        changed[3] -= 1;
+        let changed: SumAndFingerprint<u64> = changed.iter().reduce_with();
+        assert_eq!(changed.sum, original.sum);
+        assert_ne!(changed.fingerprint, original.fingerprint);
+    }
+
+    #[test]
+    fn test_sum_and_fingerprint_empty() {
+        let empty: SumAndFingerprint<i32> = std::iter::empty::<i32>().reduce_with();
+        assert_eq!(empty, SumAndFingerprint::default());
+        assert_eq!(empty.sum, 0);
+    }
+}
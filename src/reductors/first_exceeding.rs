@@ -0,0 +1,97 @@
+use crate::Reductor;
+
+/// Reductor that finds the first item yielded by an iterator that is strictly greater than a threshold,
+/// along with its index.
+///
+/// The threshold must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with). The whole iterator is still consumed after
+/// a match was found, but the remaining items are not compared with the threshold.
+///
+/// # Panics
+/// Panics if reduced without a threshold (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, FirstExceeding};
+///
+/// let cpu_load = [0.42, 0.67, 0.91, 0.88, 0.97];
+///
+/// let FirstExceeding(breach) = cpu_load.into_iter().fold_with(0.9);
+/// assert_eq!(breach, Some((2, 0.91)));
+///
+/// let FirstExceeding(breach) = cpu_load.into_iter().fold_with(1.0);
+/// assert_eq!(breach, None);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FirstExceeding<T>(pub Option<(usize, T)>);
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    threshold: T,
+    index: usize,
+    first: Option<(usize, T)>,
+}
+
+impl<T> From<T> for State<T> {
+    fn from(threshold: T) -> Self {
+        Self {
+            threshold,
+            index: 0,
+            first: None,
+        }
+    }
+}
+
+impl<T> Reductor<T> for FirstExceeding<T>
+where
+    T: PartialOrd,
+{
+    type State = State<T>;
+
+    fn new(_: T) -> Self::State {
+        panic!("`FirstExceeding` must be used with `fold_with`, providing a threshold")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        if state.first.is_none() && item > state.threshold {
+            state.first = Some((state.index, item));
+        }
+        state.index += 1;
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state.first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_first_exceeding() {
+        let FirstExceeding(first) = [3, 5, 10, 7, 12].into_iter().fold_with(5);
+        assert_eq!(first, Some((2, 10)));
+
+        let FirstExceeding(first) = [30, 5].into_iter().fold_with(5);
+        assert_eq!(first, Some((0, 30)));
+    }
+
+    #[test]
+    fn test_first_exceeding_no_match() {
+        let FirstExceeding(first) = [3, 5, 5, 1].into_iter().fold_with(5);
+        assert_eq!(first, None);
+
+        let FirstExceeding(first) = [f64::NAN, 1.].into_iter().fold_with(0.);
+        assert_eq!(first, Some((1, 1.)));
+
+        let FirstExceeding(first) = std::iter::empty::<u8>().fold_with(0);
+        assert_eq!(first, None);
+    }
+}
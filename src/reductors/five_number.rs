@@ -0,0 +1,125 @@
+use super::state::NonEmptyState;
+use crate::Reductor;
+
+/// Reductor that computes the [five-number summary] of items yielded by an iterator,
+/// the basis for box plots.
+///
+/// Quartiles are computed with the [nearest-rank method]: the `p`-quantile of `n` sorted
+/// items is the item at (1-based) rank `⌈p × n⌉`, so every value of the summary is one of
+/// the items (no interpolation is done, which is why only [`Ord`] is required). For an even
+/// number of items, this means that the median is the lower of the two middle items.
+///
+/// All items are collected, and sorted when the iterator is exhausted, so this reductor uses
+/// `O(n)` memory and takes `O(n log n)` time.
+///
+/// [five-number summary]: https://en.wikipedia.org/wiki/Five-number_summary
+/// [nearest-rank method]: https://en.wikipedia.org/wiki/Percentile#The_nearest-rank_method
+///
+/// # Examples
+/// ```rust
+/// use reductor::{FiveNumberSummary, Reduce};
+///
+/// let FiveNumberSummary { min, q1, median, q3, max } = [7, 15, 36, 39, 40, 41]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!((min, q1, median, q3, max), (7, 15, 36, 40, 41));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiveNumberSummary<T> {
+    /// Smallest item.
+    pub min: T,
+    /// First quartile (25th percentile).
+    pub q1: T,
+    /// Median (50th percentile).
+    pub median: T,
+    /// Third quartile (75th percentile).
+    pub q3: T,
+    /// Largest item.
+    pub max: T,
+}
+
+impl<T> Reductor<T> for FiveNumberSummary<T>
+where
+    T: Ord + Clone,
+{
+    type State = NonEmptyState<Vec<T>>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        NonEmptyState(vec![item])
+    }
+
+    #[inline]
+    fn reduce(NonEmptyState(mut items): Self::State, item: T) -> Self::State {
+        items.push(item);
+        NonEmptyState(items)
+    }
+
+    fn into_result(NonEmptyState(mut items): Self::State) -> Self {
+        items.sort_unstable();
+
+        // `⌈p × n⌉ - 1`, with `p` expressed in quarters.
+        let nearest_rank = |quarters: usize| (quarters * items.len()).div_ceil(4) - 1;
+
+        Self {
+            min: items[0].clone(),
+            q1: items[nearest_rank(1)].clone(),
+            median: items[nearest_rank(2)].clone(),
+            q3: items[nearest_rank(3)].clone(),
+            max: items[items.len() - 1].clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    fn summary(data: &[i32]) -> Option<(i32, i32, i32, i32, i32)> {
+        data.iter()
+            .copied()
+            .reduce_with::<Option<FiveNumberSummary<_>>>()
+            .map(
+                |FiveNumberSummary {
+                     min,
+                     q1,
+                     median,
+                     q3,
+                     max,
+                 }| (min, q1, median, q3, max),
+            )
+    }
+
+    #[test]
+    fn test_five_number_summary() {
+        assert_eq!(
+            summary(&[6, 47, 49, 15, 42, 41, 7, 39, 43, 40, 36]),
+            Some((6, 15, 40, 43, 49))
+        );
+        assert_eq!(summary(&[15, 20, 35, 40, 50]), Some((15, 20, 35, 40, 50)));
+        assert_eq!(
+            summary(&[3, 6, 7, 8, 8, 10, 13, 15, 16, 20]),
+            Some((3, 7, 8, 15, 20))
+        );
+    }
+
+    #[test]
+    fn test_five_number_summary_small() {
+        assert_eq!(summary(&[42]), Some((42, 42, 42, 42, 42)));
+        assert_eq!(summary(&[2, 1]), Some((1, 1, 1, 2, 2)));
+        assert_eq!(summary(&[]), None);
+
+        let FiveNumberSummary {
+            min, median, max, ..
+        } = ["pear", "apple", "fig"]
+            .into_iter()
+            .map(String::from)
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!((&*min, &*median, &*max), ("apple", "fig", "pear"));
+    }
+}
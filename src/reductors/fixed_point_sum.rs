@@ -0,0 +1,161 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that sums the [`f64`] items yielded by an iterator exactly, by converting each of them
+/// to a fixed-point [`i128`] with a given scale (e.g. `100.` for cents, or `1e6` for millionths).
+///
+/// Each item is multiplied by the scale and [rounded](f64::round) to the nearest integer (with
+/// halfway cases rounded away from zero), so anything finer than `1 / scale` is lost, but once
+/// converted, items are summed with no rounding errors at all. Unlike summing floats (e.g. with
+/// [`Sum<f64>`](crate::Sum)), the result is therefore independent of the order of the items,
+/// which makes this well suited for financial amounts.
+///
+/// Scaled items that don't fit in an `i128` saturate (with NaN converted to zero), as does the
+/// sum itself, although that takes about `1.7e38 / scale` to happen.
+///
+/// The scale must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with).
+///
+/// # Panics
+/// Panics if the scale isn't positive and finite.
+///
+/// Panics if reduced without a scale (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{FixedPointSum, Reduce};
+///
+/// let prices = [0.1; 10];
+///
+/// let sum: FixedPointSum = prices.into_iter().fold_with(100.);
+///
+/// assert_eq!(sum.scaled, 100);
+/// assert_eq!(sum.value(), 1.);
+/// assert_ne!(prices.into_iter().sum::<f64>(), 1.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedPointSum {
+    /// Sum of the scaled items.
+    pub scaled: i128,
+    /// Scale that items were multiplied by.
+    pub scale: f64,
+}
+
+impl FixedPointSum {
+    /// Sum of the items, converted back to an `f64`, i.e. `scaled / scale`.
+    pub fn value(&self) -> f64 {
+        self.scaled as f64 / self.scale
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    scale: f64,
+    scaled: i128,
+}
+
+impl From<f64> for State {
+    fn from(scale: f64) -> Self {
+        assert!(
+            scale.is_finite() && scale > 0.,
+            "scale must be positive, got {scale}"
+        );
+
+        Self { scale, scaled: 0 }
+    }
+}
+
+impl<T> Reductor<T> for FixedPointSum
+where
+    T: Into<f64>,
+{
+    type State = State;
+
+    fn new(_: T) -> Self::State {
+        panic!("`FixedPointSum` must be used with `fold_with`, providing a scale")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        // `as` saturates, and converts NaN to zero
+        let scaled = (item.into() * state.scale).round() as i128;
+        state.scaled = state.scaled.saturating_add(scaled);
+        state
+    }
+
+    #[inline]
+    fn into_result(State { scale, scaled }: Self::State) -> Self {
+        Self { scaled, scale }
+    }
+}
+
+impl<T> MergeReductor<T> for FixedPointSum
+where
+    T: Into<f64>,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        State {
+            scale: left.scale,
+            scaled: left.scaled.saturating_add(right.scaled),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Reduce, Sum};
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_point_sum_no_drift() {
+        let amounts = [1e16, 0.01, 0.02, -1e16, 0.03];
+
+        let Sum::<f64>(naive) = amounts.into_iter().reduce_with();
+        assert_ne!(naive, 0.06);
+
+        let sum: FixedPointSum = amounts.into_iter().fold_with(1e6);
+        assert_eq!(sum.scaled, 60_000);
+        assert_eq!(sum.value(), 0.06);
+
+        let mut reversed = amounts;
+        reversed.reverse();
+        let reversed_sum: FixedPointSum = reversed.into_iter().fold_with(1e6);
+        assert_eq!(reversed_sum, sum);
+
+        let state = |items: &[f64]| {
+            items
+                .iter()
+                .copied()
+                .fold(State::from(1e6), <FixedPointSum as Reductor<f64>>::reduce)
+        };
+        let merged = <FixedPointSum as MergeReductor<f64>>::merge(
+            state(&amounts[..2]),
+            state(&amounts[2..]),
+        );
+        let merged = <FixedPointSum as Reductor<f64>>::into_result(merged);
+        assert_eq!(merged, sum);
+    }
+
+    #[test]
+    fn test_fixed_point_sum_rounding() {
+        let round = |x: f64| {
+            let FixedPointSum { scaled, .. } = [x].into_iter().fold_with(100.);
+            scaled
+        };
+        assert_eq!(round(0.004), 0);
+        assert_eq!(round(0.126), 13);
+        // halfway cases round away from zero
+        assert_eq!(round(0.125), 13);
+        assert_eq!(round(-0.125), -13);
+        assert_eq!(round(f64::NAN), 0);
+
+        let sum: FixedPointSum = [f64::INFINITY, 1.].into_iter().fold_with(1.);
+        assert_eq!(sum.scaled, i128::MAX);
+    }
+
+    #[test]
+    #[should_panic = "scale must be positive"]
+    fn test_fixed_point_sum_invalid_scale() {
+        let _: FixedPointSum = [1.].into_iter().fold_with(0.);
+    }
+}
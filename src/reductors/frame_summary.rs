@@ -0,0 +1,102 @@
+use std::borrow::Borrow;
+
+use super::crc32::Crc32;
+use crate::Reductor;
+
+/// Reductor that computes both the length and the [CRC-32] checksum of the bytes (or
+/// references to them) yielded by an iterator, e.g. for validating a received frame against
+/// the length and checksum in its trailer, without iterating over it twice.
+///
+/// The checksum is the common CRC-32 variant (as used by e.g. zlib, PNG and Ethernet).
+///
+/// [CRC-32]: https://en.wikipedia.org/wiki/Cyclic_redundancy_check
+///
+/// # Examples
+/// ```rust
+/// use reductor::{FrameSummary, Reduce};
+///
+/// let FrameSummary { length, crc32 } = b"123456789".iter().reduce_with();
+///
+/// assert_eq!(length, 9);
+/// assert_eq!(crc32, 0xcbf4_3926);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameSummary {
+    /// Number of bytes yielded by iterator.
+    pub length: usize,
+    /// CRC-32 checksum of bytes yielded by iterator.
+    pub crc32: u32,
+}
+
+impl Default for FrameSummary {
+    fn default() -> Self {
+        <Self as Reductor<u8>>::into_result(State::default())
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct State {
+    length: usize,
+    crc: Crc32,
+}
+
+impl<A> Reductor<A> for FrameSummary
+where
+    A: Borrow<u8>,
+{
+    type State = State;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: A) -> Self::State {
+        state.length += 1;
+        state.crc.update(*item.borrow());
+        state
+    }
+
+    #[inline]
+    fn into_result(State { length, crc }: Self::State) -> Self {
+        Self {
+            length,
+            crc32: crc.finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_frame_summary() {
+        let frame = b"The quick brown fox jumps over the lazy dog";
+
+        let summary: FrameSummary = frame.iter().reduce_with();
+        assert_eq!(
+            summary,
+            FrameSummary {
+                length: 43,
+                crc32: 0x414f_a339,
+            }
+        );
+
+        let mut corrupted = *frame;
+        corrupted[4] ^= 0b100;
+        let corrupted: FrameSummary = corrupted.into_iter().reduce_with();
+        assert_eq!(corrupted.length, summary.length);
+        assert_ne!(corrupted.crc32, summary.crc32);
+    }
+
+    #[test]
+    fn test_frame_summary_empty() {
+        let summary: FrameSummary = std::iter::empty::<u8>().reduce_with();
+        assert_eq!(summary, FrameSummary::default());
+        assert_eq!((summary.length, summary.crc32), (0, 0));
+    }
+}
@@ -0,0 +1,139 @@
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the [geometric mean] of items yielded by an iterator, i.e. the `n`-th
+/// root of their product.
+///
+/// Rather than multiplying the items (e.g. with [`Product`](crate::Product)), which easily
+/// overflows (or underflows) even for moderately sized iterators, the natural logarithms of the
+/// items are summed, and the result is the exponent of their mean.
+///
+/// The geometric mean is only defined for non-negative items: following [`f64::ln`], if any of
+/// the items is negative (or NaN), the result is NaN. If any of the items is zero (and none are
+/// negative or infinite), the result is zero, like the root of the product would be.
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and the
+/// iterator's item type can be any type that implements [`Into<F>`].
+///
+/// [geometric mean]: https://en.wikipedia.org/wiki/Geometric_mean
+///
+/// # Examples
+/// ```rust
+/// use reductor::{GeometricMean, Reduce};
+///
+/// // yearly growth factors
+/// let GeometricMean::<f64>(growth) = [1.1, 1.5, 0.8]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+/// assert!((growth - 1.32_f64.cbrt()).abs() < 1e-12);
+///
+/// let GeometricMean::<f64>(growth) = [2., -8.].into_iter().reduce_with::<Option<_>>().unwrap();
+/// assert!(growth.is_nan());
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct GeometricMean<F>(pub F);
+
+macro_rules! impl_geometric_mean {
+    ($f:ty) => {
+        impl<T> Reductor<T> for GeometricMean<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<($f, usize)>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                NonEmptyState((item.into().ln(), 1))
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState((log_sum, count)): Self::State, item: T) -> Self::State {
+                NonEmptyState((log_sum + item.into().ln(), count + 1))
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState((log_sum, count)): Self::State) -> Self {
+                Self((log_sum / count as $f).exp())
+            }
+        }
+
+        impl<T> MergeReductor<T> for GeometricMean<$f>
+        where
+            T: Into<$f>,
+        {
+            #[inline]
+            fn merge(
+                NonEmptyState((left_sum, left_count)): Self::State,
+                NonEmptyState((right_sum, right_count)): Self::State,
+            ) -> Self::State {
+                NonEmptyState((left_sum + right_sum, left_count + right_count))
+            }
+        }
+    };
+}
+
+impl_geometric_mean!(f32);
+impl_geometric_mean!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Product, Reduce, Reductors};
+
+    use super::*;
+
+    #[test]
+    fn test_geometric_mean_product() {
+        let datasets: [&[f64]; 3] = [&[4., 9.], &[1., 3., 9., 27., 81.], &[0.5, 2.5, 7.25, 0.125]];
+
+        for data in datasets {
+            let Reductors((GeometricMean::<f64>(mean), Product::<f64>(product))) =
+                data.iter().copied().reduce_with::<Option<_>>().unwrap();
+            let root = product.powf(1. / data.len() as f64);
+            assert!((mean - root).abs() < 1e-12 * root, "{mean} != {root}");
+
+            let GeometricMean::<f32>(mean) = data
+                .iter()
+                .map(|&x| x as f32)
+                .reduce_with::<Option<_>>()
+                .unwrap();
+            assert!((f64::from(mean) - root).abs() < 1e-5 * root);
+        }
+    }
+
+    #[test]
+    fn test_geometric_mean_overflow() {
+        let data: Vec<f64> = (1..=1000).map(|x| 1e300 + f64::from(x) * 1e297).collect();
+
+        let Reductors((GeometricMean::<f64>(mean), Product::<f64>(product))) =
+            data.iter().copied().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(product, f64::INFINITY);
+        assert!(mean.is_finite());
+        assert!(data[0] < mean && mean < data[999], "{mean}");
+
+        let GeometricMean::<f64>(merged) = par_reduce_slice::<Option<_>, _>(&data, 4, 0).unwrap();
+        assert!((merged - mean).abs() < 1e-12 * mean);
+    }
+
+    #[test]
+    fn test_geometric_mean_non_positive() {
+        let GeometricMean::<f64>(mean) = [3., 0., 12.]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(mean, 0.);
+
+        let GeometricMean::<f64>(mean) = [3., -1., 0.]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert!(mean.is_nan());
+
+        assert_eq!(
+            None::<GeometricMean<f64>>,
+            std::iter::empty::<f64>().reduce_with()
+        );
+    }
+}
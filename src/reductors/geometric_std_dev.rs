@@ -0,0 +1,156 @@
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the [geometric standard deviation] of items yielded by an iterator,
+/// i.e. `exp(σ)`, where `σ` is the (population) standard deviation of the items' logarithms.
+///
+/// This is the multiplicative counterpart of the standard deviation, e.g. for log-normally
+/// distributed data, a geometric standard deviation of `2` means that about 68% of the items
+/// lie within a factor of `2` of the geometric mean. It is always at least `1`.
+///
+/// The mean and variance of the logarithms are updated for each item using
+/// [Welford's online algorithm], which is numerically stable.
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and the
+/// iterator's item type can be any type that implements [`Into<F>`]. The logarithm is only
+/// defined for positive items, so if any item is zero or negative, the result is NaN.
+///
+/// [geometric standard deviation]: https://en.wikipedia.org/wiki/Geometric_standard_deviation
+/// [Welford's online algorithm]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+///
+/// # Examples
+/// ```rust
+/// use reductor::{GeometricStdDev, Reduce};
+///
+/// let GeometricStdDev::<f64>(gsd) = [1, 4, 16].into_iter().reduce_with::<Option<_>>().unwrap();
+/// assert!((gsd - 4f64.powf(2f64.sqrt() / 3f64.sqrt())).abs() < 1e-12);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct GeometricStdDev<F>(pub F);
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<F> {
+    count: usize,
+    /// Mean of the logarithms.
+    mean: F,
+    /// Sum of squared differences of the logarithms from their mean.
+    m2: F,
+}
+
+macro_rules! impl_geometric_std_dev {
+    ($f:ty) => {
+        impl<T> Reductor<T> for GeometricStdDev<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                NonEmptyState(State {
+                    count: 1,
+                    mean: item.into().ln(),
+                    m2: 0.,
+                })
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(mut state): Self::State, item: T) -> Self::State {
+                let ln = item.into().ln();
+                state.count += 1;
+                let delta = ln - state.mean;
+                state.mean += delta / state.count as $f;
+                state.m2 += delta * (ln - state.mean);
+                NonEmptyState(state)
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(state): Self::State) -> Self {
+                Self((state.m2 / state.count as $f).sqrt().exp())
+            }
+        }
+
+        impl<T> MergeReductor<T> for GeometricStdDev<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(
+                NonEmptyState(left): Self::State,
+                NonEmptyState(right): Self::State,
+            ) -> Self::State {
+                let count = left.count + right.count;
+                let (left_count, right_count) = (left.count as $f, right.count as $f);
+                let delta = right.mean - left.mean;
+
+                NonEmptyState(State {
+                    count,
+                    mean: left.mean + delta * right_count / count as $f,
+                    m2: left.m2 + right.m2 + delta * delta * left_count * right_count / count as $f,
+                })
+            }
+        }
+    };
+}
+
+impl_geometric_std_dev!(f32);
+impl_geometric_std_dev!(f64);
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use crate::{parallel::par_reduce_slice, Reduce};
+
+    use super::*;
+
+    #[test]
+    fn test_geometric_std_dev_log_normal() {
+        // Log-normal samples with `σ = ln(2)`, using the Box-Muller transform.
+        let mut rng = SmallRng::seed_from_u64(0);
+        let samples: Vec<f64> = (0..100_000)
+            .map(|_| {
+                let (u1, u2): (f64, f64) = (1. - rng.random::<f64>(), rng.random());
+                let z = (-2. * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+                (3. + 2f64.ln() * z).exp()
+            })
+            .collect();
+
+        let GeometricStdDev::<f64>(gsd) =
+            samples.iter().copied().reduce_with::<Option<_>>().unwrap();
+        assert!((gsd - 2.).abs() < 0.02, "gsd {gsd}");
+
+        let GeometricStdDev::<f64>(merged) =
+            par_reduce_slice::<Option<_>, _>(&samples, 4, 0).unwrap();
+        assert!((merged - gsd).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_geometric_std_dev_scale_invariant() {
+        let GeometricStdDev::<f32>(gsd) = [7u8; 4].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(gsd, 1.);
+
+        let data = [0.5, 2., 8., 1., 3.];
+        let GeometricStdDev::<f64>(gsd) = data.into_iter().reduce_with::<Option<_>>().unwrap();
+        let GeometricStdDev::<f64>(scaled) = data
+            .into_iter()
+            .map(|x| x * 1000.)
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert!((gsd - scaled).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_geometric_std_dev_non_positive() {
+        let GeometricStdDev::<f64>(gsd) =
+            [1., 0., 2.].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert!(gsd.is_nan());
+
+        let GeometricStdDev::<f64>(gsd) = [1., -2.].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert!(gsd.is_nan());
+
+        let empty: Option<GeometricStdDev<f64>> = std::iter::empty::<f64>().reduce_with();
+        assert_eq!(empty, None);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the [Gini impurity] of the class labels yielded by an iterator,
+/// i.e. `1 - Σ pᵢ²`, where `pᵢ` is the fraction of items that belong to the `i`th class.
+///
+/// This is the probability that two items drawn at random (with replacement) belong to different
+/// classes, as used for evaluating splits when training decision trees. It ranges from zero
+/// (a single class, or no items at all) up to `1 - 1/k` for `k` equally frequent classes.
+///
+/// Not to be confused with the [Gini coefficient], which measures the inequality of a distribution
+/// of (numeric) values, rather than the diversity of (categorical) labels.
+///
+/// [Gini impurity]: https://en.wikipedia.org/wiki/Decision_tree_learning#Gini_impurity
+/// [Gini coefficient]: https://en.wikipedia.org/wiki/Gini_coefficient
+///
+/// # Examples
+/// ```rust
+/// use reductor::{GiniImpurity, Reduce};
+///
+/// let GiniImpurity(impurity) = ["spam", "ham", "ham", "ham"].into_iter().reduce_with();
+/// assert_eq!(impurity, 0.375);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct GiniImpurity(pub f64);
+
+#[derive(Debug, Clone)]
+pub struct State<T>(HashMap<T, usize>);
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<T> Reductor<T> for GiniImpurity
+where
+    T: Eq + Hash,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(State(mut counts): Self::State, item: T) -> Self::State {
+        *counts.entry(item).or_default() += 1;
+        State(counts)
+    }
+
+    fn into_result(State(counts): Self::State) -> Self {
+        let total = counts.values().sum::<usize>() as f64;
+        if total == 0. {
+            return Self(0.);
+        }
+
+        Self(
+            1. - counts
+                .values()
+                .map(|&count| (count as f64 / total).powi(2))
+                .sum::<f64>(),
+        )
+    }
+}
+
+impl<T> MergeReductor<T> for GiniImpurity
+where
+    T: Eq + Hash,
+{
+    fn merge(State(mut left): Self::State, State(right): Self::State) -> Self::State {
+        for (item, count) in right {
+            *left.entry(item).or_default() += count;
+        }
+        State(left)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_gini_impurity_split() {
+        let labels = [true, true, true, false, true, false, false, false];
+        let (left, right) = labels.split_at(3);
+
+        let GiniImpurity(parent) = labels.iter().reduce_with();
+        let GiniImpurity(left) = left.iter().reduce_with();
+        let GiniImpurity(right) = right.iter().reduce_with();
+
+        assert_eq!(parent, 0.5);
+        assert_eq!(left, 0.);
+        assert_eq!(right, 1. - (0.2f64.powi(2) + 0.8f64.powi(2)));
+        assert!(3. / 8. * left + 5. / 8. * right < parent);
+    }
+
+    #[test]
+    fn test_gini_impurity_degenerate() {
+        let GiniImpurity(impurity) = std::iter::empty::<u8>().reduce_with();
+        assert_eq!(impurity, 0.);
+
+        let GiniImpurity(impurity) = ["a"; 10].into_iter().reduce_with();
+        assert_eq!(impurity, 0.);
+
+        let GiniImpurity(impurity) = (0..4).reduce_with();
+        assert_eq!(impurity, 0.75);
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "indexmap")]
+use indexmap::IndexMap;
+
+#[cfg(feature = "indexmap")]
+use crate::IndexedCollectMap;
+use crate::{CollectMap, MergeReductor, ReduceValues, Reductor};
+
+/// Reductor that groups the values of the `(key, value)` pairs yielded by an iterator by their
+/// keys, and reduces the values of each group with the [`Reductor`] `R`, i.e. a one-pass
+/// group-by aggregation (e.g. the sum of the values of each key).
+///
+/// Every group has at least one value, so `R` can be a reductor that doesn't support empty
+/// iterators (e.g. [`Mean`](crate::Mean)) without wrapping it in an [`Option`].
+///
+/// This is a shorthand for a [`CollectMap`] with the [`ReduceValues`] policy, without counting
+/// collisions.
+///
+/// See `IndexedGroupReduce` (with the `indexmap` feature) for keeping the groups in the order
+/// their keys were first yielded.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{GroupReduce, Mean, Reduce, Reductors, Sum};
+///
+/// let sales = [("books", 12), ("games", 60), ("books", 8), ("music", 10), ("books", 25)];
+///
+/// let GroupReduce(by_category) = sales
+///     .into_iter()
+///     .reduce_with::<GroupReduce<_, Reductors<(Sum<u32>, Mean<f64>)>>>();
+///
+/// let Reductors((Sum(total), Mean(mean))) = by_category["books"];
+/// assert_eq!((total, mean), (45, 15.));
+/// assert_eq!(by_category.len(), 3);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct GroupReduce<K, R>(pub HashMap<K, R>);
+
+/// Like [`GroupReduce`], but keeps the groups in an [`IndexMap`], so that they are iterated in
+/// the order their keys were first yielded.
+///
+/// Requires the `indexmap` feature.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{IndexedGroupReduce, Reduce, Sum};
+///
+/// let sales = [("games", 60), ("books", 12), ("games", 15)];
+///
+/// let IndexedGroupReduce(by_category) =
+///     sales.into_iter().reduce_with::<IndexedGroupReduce<_, Sum<u32>>>();
+///
+/// assert!(by_category.into_iter().eq([("games", Sum(75)), ("books", Sum(12))]));
+/// ```
+#[cfg(feature = "indexmap")]
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct IndexedGroupReduce<K, R>(pub IndexMap<K, R>);
+
+macro_rules! impl_group_reduce {
+    ($name:ident, $map:ident, $collect:ident) => {
+        impl<K, R> PartialEq for $name<K, R>
+        where
+            $map<K, R>: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<K, R> Default for $name<K, R> {
+            fn default() -> Self {
+                Self($map::default())
+            }
+        }
+
+        impl<K, A, R> Reductor<(K, A)> for $name<K, R>
+        where
+            $collect<K, R, ReduceValues>: Reductor<(K, A)>,
+        {
+            type State = <$collect<K, R, ReduceValues> as Reductor<(K, A)>>::State;
+
+            #[inline]
+            fn new(item: (K, A)) -> Self::State {
+                $collect::<K, R, ReduceValues>::new(item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: (K, A)) -> Self::State {
+                $collect::<K, R, ReduceValues>::reduce(state, item)
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self($collect::<K, R, ReduceValues>::into_result(state).map)
+            }
+        }
+
+        impl<K, A, R> MergeReductor<(K, A)> for $name<K, R>
+        where
+            $collect<K, R, ReduceValues>: MergeReductor<(K, A)>,
+        {
+            #[inline]
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                $collect::<K, R, ReduceValues>::merge(left, right)
+            }
+        }
+    };
+}
+
+impl_group_reduce!(GroupReduce, HashMap, CollectMap);
+#[cfg(feature = "indexmap")]
+impl_group_reduce!(IndexedGroupReduce, IndexMap, IndexedCollectMap);
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Count, Mean, Reduce, Reductors, Sum};
+
+    use super::*;
+
+    const READINGS: [(char, u32); 8] = [
+        ('a', 4),
+        ('b', 2),
+        ('a', 7),
+        ('c', 1),
+        ('b', 9),
+        ('a', 3),
+        ('d', 5),
+        ('a', 2),
+    ];
+
+    #[test]
+    fn test_group_reduce_sums_and_means() {
+        let GroupReduce(groups) = READINGS
+            .into_iter()
+            .reduce_with::<GroupReduce<_, Reductors<(Sum<u32>, Mean<f64>, Count)>>>();
+
+        assert_eq!(groups.len(), 4);
+        for (key, sum, mean, count) in [('a', 16, 4., 4), ('b', 11, 5.5, 2), ('c', 1, 1., 1)] {
+            assert_eq!(
+                groups[&key],
+                Reductors((Sum(sum), Mean(mean), Count(count)))
+            );
+        }
+
+        let GroupReduce(empty) =
+            std::iter::empty::<(char, u32)>().reduce_with::<GroupReduce<_, Sum<u32>>>();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_group_reduce_merge() {
+        type R = GroupReduce<char, Reductors<(Sum<u32>, Mean<f64>)>>;
+
+        let GroupReduce(expected) = READINGS.into_iter().reduce_with::<R>();
+        for threads in 2..=4 {
+            let GroupReduce(merged) = par_reduce_slice::<R, _>(&READINGS, threads, 0);
+            assert_eq!(merged, expected);
+        }
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_group_reduce_indexed() {
+        let IndexedGroupReduce(groups) = READINGS
+            .into_iter()
+            .reduce_with::<IndexedGroupReduce<_, Sum<u32>>>();
+
+        assert!(groups.keys().eq(&['a', 'b', 'c', 'd']));
+        assert!(groups.values().eq(&[Sum(16), Sum(11), Sum(1), Sum(5)]));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that counts how many of the integers yielded by an iterator have each
+/// [Hamming weight] (i.e. number of set bits, see [`u32::count_ones`]).
+///
+/// The resulting histogram is indexed by weight, so for items of a type with `BITS` bits, it
+/// has `BITS + 1` entries (one for every weight between zero and `BITS`). An empty iterator
+/// results in an empty histogram.
+///
+/// Items can be any of the integer types (`u8`, `i8`, ..., `u128`, `i128`), or references to
+/// them. See [`PopcountSum`](crate::PopcountSum) for only counting the total number of set bits.
+///
+/// [Hamming weight]: https://en.wikipedia.org/wiki/Hamming_weight
+///
+/// # Examples
+/// ```rust
+/// use reductor::{HammingWeights, Reduce};
+///
+/// let HammingWeights(weights) = [0b0000_0000u8, 0b0001_0000, 0b1010_0000, 0b0000_0011]
+///     .into_iter()
+///     .reduce_with();
+///
+/// assert_eq!(weights, [1, 1, 2, 0, 0, 0, 0, 0, 0]);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HammingWeights(pub Vec<usize>);
+
+macro_rules! impl_hamming_weights {
+    ($($int:ty),+) => {
+        $(
+            impl Reductor<$int> for HammingWeights {
+                type State = Vec<usize>;
+
+                #[inline]
+                fn new(item: $int) -> Self::State {
+                    Self::reduce(Vec::new(), item)
+                }
+
+                #[inline]
+                fn reduce(mut state: Self::State, item: $int) -> Self::State {
+                    if state.is_empty() {
+                        state.resize(<$int>::BITS as usize + 1, 0);
+                    }
+                    state[item.count_ones() as usize] += 1;
+                    state
+                }
+
+                #[inline]
+                fn into_result(state: Self::State) -> Self {
+                    Self(state)
+                }
+            }
+
+            impl Reductor<&$int> for HammingWeights {
+                type State = Vec<usize>;
+
+                #[inline]
+                fn new(item: &$int) -> Self::State {
+                    <Self as Reductor<$int>>::new(*item)
+                }
+
+                #[inline]
+                fn reduce(state: Self::State, item: &$int) -> Self::State {
+                    <Self as Reductor<$int>>::reduce(state, *item)
+                }
+
+                #[inline]
+                fn into_result(state: Self::State) -> Self {
+                    Self(state)
+                }
+            }
+
+            impl MergeReductor<$int> for HammingWeights {
+                #[inline]
+                fn merge(left: Self::State, right: Self::State) -> Self::State {
+                    merge(left, right)
+                }
+            }
+
+            impl MergeReductor<&$int> for HammingWeights {
+                #[inline]
+                fn merge(left: Self::State, right: Self::State) -> Self::State {
+                    merge(left, right)
+                }
+            }
+        )+
+    };
+}
+
+impl_hamming_weights!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// Either state might be empty, if no items were reduced into it.
+fn merge(mut left: Vec<usize>, right: Vec<usize>) -> Vec<usize> {
+    if left.is_empty() {
+        return right;
+    }
+    for (left, right) in left.iter_mut().zip(right) {
+        *left += right;
+    }
+    left
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Reduce};
+
+    use super::*;
+
+    #[test]
+    fn test_hamming_weights() {
+        macro_rules! test {
+            ($int:ty) => {
+                let HammingWeights(weights) =
+                    [!0 as $int, 0, 0b101, 0b110, 0b1].into_iter().reduce_with();
+
+                let bits = <$int>::BITS as usize;
+                assert_eq!(weights.len(), bits + 1);
+                assert_eq!(
+                    (weights[0], weights[1], weights[2], weights[bits]),
+                    (1, 1, 2, 1)
+                );
+                assert_eq!(weights.iter().sum::<usize>(), 5);
+            };
+        }
+
+        test!(u8);
+        test!(u16);
+        test!(u32);
+        test!(u64);
+        test!(u128);
+        test!(i8);
+        test!(i16);
+        test!(i32);
+        test!(i64);
+        test!(i128);
+    }
+
+    #[test]
+    fn test_hamming_weights_merge() {
+        let data: Vec<u16> = (0..=u16::MAX).collect();
+
+        let HammingWeights(weights) = data.iter().reduce_with();
+        // the number of 16-bit integers with `k` set bits is `16 choose k`
+        let mut binomial = 1;
+        for (k, &count) in weights.iter().enumerate() {
+            assert_eq!(count, binomial);
+            binomial = binomial * (16 - k) / (k + 1);
+        }
+
+        for threads in 2..=4 {
+            let HammingWeights(merged) = par_reduce_slice(&data, threads, 0);
+            assert_eq!(merged, weights);
+        }
+
+        let HammingWeights(empty) = std::iter::empty::<u64>().reduce_with();
+        assert!(empty.is_empty());
+    }
+}
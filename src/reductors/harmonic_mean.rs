@@ -0,0 +1,123 @@
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the [harmonic mean] of items yielded by an iterator, i.e. the
+/// reciprocal of the mean of their reciprocals, which is the appropriate mean for averaging
+/// rates (e.g. the average speed over several legs of the same distance).
+///
+/// If any of the items is zero, its reciprocal is infinite, and the result is zero, unless both
+/// positive and negative zeros appear, in which case their infinite reciprocals cancel out and
+/// the result is NaN.
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and the
+/// iterator's item type can be any type that implements [`Into<F>`].
+///
+/// [harmonic mean]: https://en.wikipedia.org/wiki/Harmonic_mean
+///
+/// # Examples
+/// ```rust
+/// use reductor::{HarmonicMean, Mean, Reduce};
+///
+/// // (speed in km/h, fuel consumption in l/100km) of each 10km leg of a trip
+/// let legs = [(60., 6.5), (120., 8.), (40., 5.5)];
+///
+/// let (average_speed, average_consumption) = legs
+///     .into_iter()
+///     .reduce_with::<(Option<HarmonicMean<f64>>, Option<Mean<f64>>)>();
+///
+/// assert_eq!(average_speed, Some(HarmonicMean(60.)));
+/// assert_eq!(average_consumption, Some(Mean(6.666666666666667)));
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct HarmonicMean<F>(pub F);
+
+macro_rules! impl_harmonic_mean {
+    ($f:ty) => {
+        impl<T> Reductor<T> for HarmonicMean<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<($f, usize)>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                NonEmptyState((item.into().recip(), 1))
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState((recip_sum, count)): Self::State, item: T) -> Self::State {
+                NonEmptyState((recip_sum + item.into().recip(), count + 1))
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState((recip_sum, count)): Self::State) -> Self {
+                Self(count as $f / recip_sum)
+            }
+        }
+
+        impl<T> MergeReductor<T> for HarmonicMean<$f>
+        where
+            T: Into<$f>,
+        {
+            #[inline]
+            fn merge(
+                NonEmptyState((left_sum, left_count)): Self::State,
+                NonEmptyState((right_sum, right_count)): Self::State,
+            ) -> Self::State {
+                NonEmptyState((left_sum + right_sum, left_count + right_count))
+            }
+        }
+    };
+}
+
+impl_harmonic_mean!(f32);
+impl_harmonic_mean!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Reduce};
+
+    use super::*;
+
+    #[test]
+    fn test_harmonic_mean() {
+        macro_rules! test {
+            ($f:ty) => {
+                let HarmonicMean::<$f>(mean) =
+                    [1u8, 2, 4].into_iter().reduce_with::<Option<_>>().unwrap();
+                assert!((mean - 12. / 7.).abs() < <$f>::EPSILON);
+            };
+        }
+
+        test!(f32);
+        test!(f64);
+
+        let data: Vec<f64> = (1..=100).map(f64::from).collect();
+        let HarmonicMean::<f64>(mean) = data.iter().copied().reduce_with::<Option<_>>().unwrap();
+        let HarmonicMean::<f64>(merged) = par_reduce_slice::<Option<_>, _>(&data, 3, 0).unwrap();
+        assert!((merged - mean).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_harmonic_mean_zero() {
+        let HarmonicMean::<f64>(mean) =
+            [3., 0., 5.].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(mean, 0.);
+
+        let HarmonicMean::<f64>(mean) = [3., 0., f64::INFINITY]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(mean, 0.);
+
+        let HarmonicMean::<f64>(mean) = [0., -0.].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert!(mean.is_nan());
+
+        assert_eq!(
+            None::<HarmonicMean<f64>>,
+            std::iter::empty::<f64>().reduce_with()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "indexmap")]
+use indexmap::IndexMap;
+
+use super::map_like::MapLike;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that counts the occurrences of every distinct key yielded by an iterator.
+///
+/// This is the counting equivalent of [`CollectMultiMap`](crate::CollectMultiMap): rather than
+/// keeping all values of each key, only their number is kept. See
+/// [`ModeAndFrequencies`](crate::ModeAndFrequencies) for also tracking the most common key,
+/// and `IndexedHistogram` (with the `indexmap` feature) for keeping the keys in the order
+/// they were first yielded.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Count, Histogram, Reduce, Reductors};
+///
+/// let Reductors((Histogram(counts), Count(total))) = ["red", "green", "red", "blue", "red"]
+///     .into_iter()
+///     .reduce_with();
+///
+/// assert_eq!(counts["red"], 3);
+/// assert_eq!(counts["green"], 1);
+/// assert_eq!(counts.values().sum::<usize>(), total);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct Histogram<K>(pub HashMap<K, usize>);
+
+/// Like [`Histogram`], but counts the keys in an [`IndexMap`], so that they are iterated in the
+/// order they were first yielded.
+///
+/// Requires the `indexmap` feature.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{IndexedHistogram, Reduce};
+///
+/// let IndexedHistogram(counts) = ["red", "green", "red", "blue", "red"].into_iter().reduce_with();
+///
+/// assert!(counts.into_iter().eq([("red", 3), ("green", 1), ("blue", 1)]));
+/// ```
+#[cfg(feature = "indexmap")]
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct IndexedHistogram<K>(pub IndexMap<K, usize>);
+
+macro_rules! impl_histogram {
+    ($name:ident, $map:ident) => {
+        impl<K> PartialEq for $name<K>
+        where
+            $map<K, usize>: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<K> Eq for $name<K> where $map<K, usize>: Eq {}
+
+        impl<K> Default for $name<K> {
+            fn default() -> Self {
+                Self($map::default())
+            }
+        }
+
+        impl<K> Reductor<K> for $name<K>
+        where
+            $map<K, usize>: MapLike<K, usize>,
+        {
+            type State = $map<K, usize>;
+
+            #[inline]
+            fn new(item: K) -> Self::State {
+                Self::reduce($map::default(), item)
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, item: K) -> Self::State {
+                state.upsert(item, (), |()| 1, |count, ()| *count += 1);
+                state
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self(state)
+            }
+        }
+
+        impl<K> MergeReductor<K> for $name<K>
+        where
+            $map<K, usize>: MapLike<K, usize>,
+        {
+            fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+                for (key, count) in right {
+                    left.upsert(key, count, |count| count, |left, right| *left += right);
+                }
+                left
+            }
+        }
+    };
+}
+
+impl_histogram!(Histogram, HashMap);
+#[cfg(feature = "indexmap")]
+impl_histogram!(IndexedHistogram, IndexMap);
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Count, Reduce, Reductors};
+
+    use super::*;
+
+    const SPECIES: [&str; 9] = [
+        "cat", "dog", "cat", "parrot", "dog", "cat", "hamster", "dog", "cat",
+    ];
+
+    #[test]
+    fn test_histogram_with_count() {
+        let Reductors((Histogram(counts), Count(total))) = SPECIES.into_iter().reduce_with();
+
+        assert_eq!(
+            counts,
+            HashMap::from([("cat", 4), ("dog", 3), ("parrot", 1), ("hamster", 1)])
+        );
+        assert_eq!(counts.values().sum::<usize>(), total);
+        assert_eq!(total, SPECIES.len());
+
+        let Histogram(empty) = std::iter::empty::<u8>().reduce_with();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_histogram_merge() {
+        let Histogram(counts) = SPECIES.into_iter().reduce_with();
+
+        for threads in 2..=4 {
+            let Histogram(merged) = par_reduce_slice::<Histogram<_>, _>(&SPECIES, threads, 0);
+            assert_eq!(merged, counts);
+        }
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_histogram_indexed() {
+        let IndexedHistogram(counts) = SPECIES.into_iter().reduce_with();
+
+        assert!(counts.keys().eq(&["cat", "dog", "parrot", "hamster"]));
+        assert!(counts.values().eq(&[4, 3, 1, 1]));
+    }
+}
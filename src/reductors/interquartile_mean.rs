@@ -0,0 +1,127 @@
+use super::state::NonEmptyState;
+use crate::Reductor;
+
+/// Reductor that computes the [interquartile mean] of items yielded by an iterator, i.e. the
+/// mean of the middle 50% of items, after discarding the lowest 25% and the highest 25%.
+///
+/// Like the median, this is a robust estimate of the central value that isn't swayed by
+/// outliers, but being a mean of many items, it is less noisy than the median, e.g. for
+/// reporting benchmark timings.
+///
+/// When the number of items `n` isn't divisible by 4, a quarter of the items isn't a whole
+/// number of items, so the two items on the boundaries of the middle 50% are weighted by the
+/// fraction of them that falls inside it (e.g. for `n = 9`, `2.25` items are discarded from each
+/// end, so the 3rd lowest and 3rd highest items are weighted by `0.75`). This makes the result
+/// a continuous function of the items, and for a single item, the result is that item.
+///
+/// Since the items must be sorted, this reductor buffers all items, so it uses `O(n)` memory.
+/// Items are sorted by [`total_cmp`](f64::total_cmp), so NaNs are sorted to the ends.
+///
+/// [interquartile mean]: https://en.wikipedia.org/wiki/Interquartile_mean
+///
+/// # Examples
+/// ```rust
+/// use reductor::{InterquartileMean, Reduce};
+///
+/// let timings_ms = [12.1, 11.8, 12.4, 95.0, 12.0, 11.9, 3.2, 12.2];
+///
+/// let InterquartileMean::<f64>(iqm) = timings_ms.into_iter().reduce_with::<Option<_>>().unwrap();
+/// assert!((iqm - 12.05).abs() < 1e-12);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct InterquartileMean<F>(pub F);
+
+macro_rules! impl_interquartile_mean {
+    ($f:ty) => {
+        impl<T> Reductor<T> for InterquartileMean<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<Vec<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                NonEmptyState(vec![item.into()])
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(mut items): Self::State, item: T) -> Self::State {
+                items.push(item.into());
+                NonEmptyState(items)
+            }
+
+            fn into_result(NonEmptyState(mut items): Self::State) -> Self {
+                items.sort_unstable_by(<$f>::total_cmp);
+
+                let count = items.len() as $f;
+                let (lower, upper) = (count / 4., count - count / 4.);
+
+                // Weight each item by the overlap of its position `[i, i + 1)`
+                // with the middle 50% `[lower, upper)`.
+                let sum = items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        let i = i as $f;
+                        let weight = (upper.min(i + 1.) - lower.max(i)).max(0.);
+                        weight * item
+                    })
+                    .sum::<$f>();
+
+                Self(sum / (upper - lower))
+            }
+        }
+    };
+}
+
+impl_interquartile_mean!(f32);
+impl_interquartile_mean!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_interquartile_mean_divisible() {
+        let InterquartileMean::<f64>(iqm) = [8, 1, 6, 3, 7, 2, 5, 4]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+
+        // Discards 1, 2 and 7, 8.
+        assert_eq!(iqm, 4.5);
+    }
+
+    #[test]
+    fn test_interquartile_mean_interpolated() {
+        let InterquartileMean::<f64>(iqm) = [200, 1, 13, 3, 100, 5, 11, 7, 9]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+
+        // 2.25 items are discarded from each end, leaving 4.5 items:
+        // (0.75 * 5 + 7 + 9 + 11 + 0.75 * 13) / 4.5 = 40.5 / 4.5
+        assert_eq!(iqm, 9.);
+
+        let InterquartileMean::<f32>(iqm) =
+            [4u8, 1, 3].into_iter().reduce_with::<Option<_>>().unwrap();
+        // 0.75 items are discarded from each end: (0.25 * 1 + 3 + 0.25 * 4) / 1.5
+        assert!((iqm - 4.25 / 1.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_interquartile_mean_small() {
+        let InterquartileMean::<f64>(iqm) = [42].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(iqm, 42.);
+
+        let InterquartileMean::<f64>(iqm) = [1, 2].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(iqm, 1.5);
+
+        let empty: Option<InterquartileMean<f32>> = std::iter::empty::<f32>().reduce_with();
+        assert_eq!(empty, None);
+    }
+}
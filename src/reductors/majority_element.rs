@@ -0,0 +1,133 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that finds the majority element of items yielded by an iterator, i.e. the item that
+/// is equal to strictly more than half of them, using the [Boyer-Moore majority vote algorithm],
+/// in constant memory.
+///
+/// The algorithm only finds a *candidate*: if a majority element exists, it is guaranteed to be
+/// the result, but if none exists, the result may be any of the items (or `None`). A `None`
+/// result always means there is no majority element. To tell the cases apart, the candidate's
+/// occurrences must be counted in a second pass over the items, as shown below.
+///
+/// [Boyer-Moore majority vote algorithm]: https://en.wikipedia.org/wiki/Boyer%E2%80%93Moore_majority_vote_algorithm
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MajorityElement, Reduce};
+///
+/// let votes = ["yes", "no", "yes", "abstain", "yes"];
+///
+/// let MajorityElement(candidate) = votes.iter().reduce_with();
+/// assert_eq!(candidate, Some(&"yes"));
+///
+/// // verify the candidate with a second pass
+/// let count = votes.iter().filter(|&vote| Some(vote) == candidate).count();
+/// assert!(count > votes.len() / 2);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MajorityElement<T>(pub Option<T>);
+
+impl<T> Default for MajorityElement<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    candidate: Option<T>,
+    votes: usize,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            candidate: None,
+            votes: 0,
+        }
+    }
+}
+
+impl<T> Reductor<T> for MajorityElement<T>
+where
+    T: PartialEq,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        State {
+            candidate: Some(item),
+            votes: 1,
+        }
+    }
+
+    #[inline]
+    fn reduce(state: Self::State, item: T) -> Self::State {
+        Self::merge(state, Self::new(item))
+    }
+
+    #[inline]
+    fn into_result(State { candidate, votes }: Self::State) -> Self {
+        Self(candidate.filter(|_| votes > 0))
+    }
+}
+
+impl<T> MergeReductor<T> for MajorityElement<T>
+where
+    T: PartialEq,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        match (left.candidate, right.candidate) {
+            (Some(l), Some(r)) if l == r => State {
+                candidate: Some(l),
+                votes: left.votes + right.votes,
+            },
+            (l, r) if left.votes >= right.votes => State {
+                candidate: l.or(r),
+                votes: left.votes - right.votes,
+            },
+            (_, r) => State {
+                candidate: r,
+                votes: right.votes - left.votes,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Reduce};
+
+    use super::*;
+
+    #[test]
+    fn test_majority_element() {
+        let data = [3, 1, 3, 2, 3, 3, 1, 3, 2];
+
+        let MajorityElement(majority) = data.into_iter().reduce_with();
+        assert_eq!(majority, Some(3));
+
+        for threads in 1..=4 {
+            let MajorityElement(merged) = par_reduce_slice(&data, threads, 0);
+            assert_eq!(merged, Some(3));
+        }
+    }
+
+    #[test]
+    fn test_no_majority_element() {
+        // every candidate is voted out
+        let MajorityElement(majority) = ["a", "b", "a", "b"].into_iter().reduce_with();
+        assert_eq!(majority, None);
+
+        // a candidate survives, but it isn't a majority element
+        let data = ['x', 'y', 'z'];
+        let MajorityElement(candidate) = data.into_iter().reduce_with();
+        let candidate = candidate.unwrap();
+        assert!(data.iter().filter(|&&item| item == candidate).count() <= data.len() / 2);
+
+        let MajorityElement(majority) = std::iter::empty::<u8>().reduce_with();
+        assert_eq!(majority, None);
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    collections::{hash_map, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+#[cfg(feature = "indexmap")]
+use indexmap::{map as index_map, IndexMap};
+
+/// Map that grouping reductors (e.g. [`CollectMultiMap`](crate::CollectMultiMap)) can collect
+/// into, implemented for [`HashMap`] and (with the `indexmap` feature)
+/// [`IndexMap`](https://docs.rs/indexmap).
+pub trait MapLike<K, V>: Default + IntoIterator<Item = (K, V)> + FromIterator<(K, V)> {
+    /// Number of entries in the map.
+    fn len(&self) -> usize;
+
+    /// Whether the map has no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// If `key` isn't in the map, insert it with the value `insert(item)`, and otherwise update
+    /// its value with `update(value, item)`. Returns the inserted or updated value.
+    fn upsert<A>(
+        &mut self,
+        key: K,
+        item: A,
+        insert: impl FnOnce(A) -> V,
+        update: impl FnOnce(&mut V, A),
+    ) -> &mut V;
+}
+
+impl<K, V, S> MapLike<K, V> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn upsert<A>(
+        &mut self,
+        key: K,
+        item: A,
+        insert: impl FnOnce(A) -> V,
+        update: impl FnOnce(&mut V, A),
+    ) -> &mut V {
+        match self.entry(key) {
+            hash_map::Entry::Vacant(entry) => entry.insert(insert(item)),
+            hash_map::Entry::Occupied(entry) => {
+                let value = entry.into_mut();
+                update(value, item);
+                value
+            }
+        }
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> MapLike<K, V> for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn upsert<A>(
+        &mut self,
+        key: K,
+        item: A,
+        insert: impl FnOnce(A) -> V,
+        update: impl FnOnce(&mut V, A),
+    ) -> &mut V {
+        match self.entry(key) {
+            index_map::Entry::Vacant(entry) => entry.insert(insert(item)),
+            index_map::Entry::Occupied(entry) => {
+                let value = entry.into_mut();
+                update(value, item);
+                value
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use super::state::NonEmptyState;
+use crate::Reductor;
+
+/// Reductor that finds the maximum drawdown of a series of values yielded by an iterator
+/// (e.g. prices), i.e. the largest relative decline from a peak to a subsequent trough.
+///
+/// The drawdown is expressed as a fraction of the peak value (`(peak - trough) / peak`),
+/// and is only meaningful for positive values. If the series never declines, the drawdown is
+/// zero, and both indices point at the first item. NaN values are skipped, but still counted
+/// when computing indices.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, MaxDrawdown};
+///
+/// let prices = [100., 120., 90., 110., 130., 78., 95.];
+///
+/// let MaxDrawdown::<f64> { drawdown, peak_index, trough_index } = prices
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(drawdown, 0.4);
+/// assert_eq!((peak_index, trough_index), (4, 5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MaxDrawdown<F> {
+    /// Largest decline from a peak to a subsequent trough, as a fraction of the peak.
+    pub drawdown: F,
+    /// Index of the peak that preceded the largest decline.
+    pub peak_index: usize,
+    /// Index of the trough at the bottom of the largest decline.
+    pub trough_index: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<F> {
+    index: usize,
+    peak: Option<(F, usize)>,
+    max: MaxDrawdown<F>,
+}
+
+macro_rules! impl_max_drawdown {
+    ($f:ty) => {
+        impl<T> Reductor<T> for MaxDrawdown<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                Self::reduce(
+                    NonEmptyState(State {
+                        index: 0,
+                        peak: None,
+                        max: Self {
+                            drawdown: 0.,
+                            peak_index: 0,
+                            trough_index: 0,
+                        },
+                    }),
+                    item,
+                )
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(mut state): Self::State, item: T) -> Self::State {
+                let item = item.into();
+                let index = state.index;
+                state.index += 1;
+
+                match state.peak {
+                    _ if item.is_nan() => {}
+                    Some((peak, peak_index)) if item <= peak => {
+                        let drawdown = (peak - item) / peak;
+                        if drawdown > state.max.drawdown {
+                            state.max = Self {
+                                drawdown,
+                                peak_index,
+                                trough_index: index,
+                            };
+                        }
+                    }
+                    _ => state.peak = Some((item, index)),
+                }
+
+                NonEmptyState(state)
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(state): Self::State) -> Self {
+                state.max
+            }
+        }
+    };
+}
+
+impl_max_drawdown!(f32);
+impl_max_drawdown!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_max_drawdown() {
+        let prices = [
+            50., 52., 55., 51., 48., 53., 60., 58., 45., 47., 62., 57., 59.,
+        ];
+
+        let MaxDrawdown::<f64> {
+            drawdown,
+            peak_index,
+            trough_index,
+        } = prices.into_iter().reduce_with::<Option<_>>().unwrap();
+
+        assert_eq!(drawdown, 0.25);
+        assert_eq!((peak_index, trough_index), (6, 8));
+    }
+
+    #[test]
+    fn test_max_drawdown_monotonic() {
+        let dd: MaxDrawdown<f64> = [1, 2, 3, 5, 8]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(
+            dd,
+            MaxDrawdown {
+                drawdown: 0.,
+                peak_index: 0,
+                trough_index: 0
+            }
+        );
+
+        let dd: MaxDrawdown<f32> = [8., f32::NAN, 4., 2.]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(
+            dd,
+            MaxDrawdown {
+                drawdown: 0.75,
+                peak_index: 0,
+                trough_index: 3
+            }
+        );
+
+        let dd: Option<MaxDrawdown<f64>> = std::iter::empty::<f64>().reduce_with();
+        assert_eq!(dd, None);
+    }
+}
@@ -1,5 +1,5 @@
 use super::state::NonEmptyState;
-use crate::Reductor;
+use crate::{MergeReductor, Reductor};
 
 /// Reductor that computes the [arithmetic mean] of items yielded by an iterator.
 ///
@@ -50,6 +50,23 @@ macro_rules! impl_mean {
                 Self(mean)
             }
         }
+
+        impl<T> MergeReductor<T> for Mean<$f>
+        where
+            T: Into<$f>,
+        {
+            #[inline]
+            fn merge(
+                NonEmptyState((left_mean, left_count)): Self::State,
+                NonEmptyState((right_mean, right_count)): Self::State,
+            ) -> Self::State {
+                let count = left_count + right_count;
+                NonEmptyState((
+                    left_mean + (right_mean - left_mean) * (right_count as $f / count as $f),
+                    count,
+                ))
+            }
+        }
     };
 }
 
@@ -0,0 +1,98 @@
+use super::state::NonEmptyState;
+use crate::Reductor;
+
+/// Reductor that computes the [mean absolute deviation] of items yielded by an iterator
+/// around their mean, i.e. `Σ|xᵢ - mean| / n`.
+///
+/// Since the mean isn't known until the iterator is exhausted, this reductor buffers all items,
+/// so it uses `O(n)` memory, but the result is exact. Being less sensitive to outliers than
+/// the standard deviation, it is a useful measure of spread for noisy data.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MeanAbsDeviation, Reduce};
+///
+/// let MeanAbsDeviation::<f64> { mean, mad } = [2, 2, 3, 4, 14]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(mean, 5.);
+/// assert_eq!(mad, 3.6);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MeanAbsDeviation<F> {
+    /// Arithmetic mean of items yielded by iterator.
+    pub mean: F,
+    /// Mean absolute deviation of items around the mean.
+    pub mad: F,
+}
+
+macro_rules! impl_mean_abs_deviation {
+    ($f:ty) => {
+        impl<T> Reductor<T> for MeanAbsDeviation<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<Vec<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                NonEmptyState(vec![item.into()])
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(mut items): Self::State, item: T) -> Self::State {
+                items.push(item.into());
+                NonEmptyState(items)
+            }
+
+            fn into_result(NonEmptyState(items): Self::State) -> Self {
+                let count = items.len() as $f;
+                let mean = items.iter().sum::<$f>() / count;
+                let mad = items.iter().map(|item| (item - mean).abs()).sum::<$f>() / count;
+                Self { mean, mad }
+            }
+        }
+    };
+}
+
+impl_mean_abs_deviation!(f32);
+impl_mean_abs_deviation!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_mean_abs_deviation_outlier() {
+        let data = [10., 12., 11., 9., 10., 8., 110.];
+
+        let MeanAbsDeviation::<f64> { mean, mad } =
+            data.into_iter().reduce_with::<Option<_>>().unwrap();
+
+        // Six items (summing to 60) are below the mean, and one (110) is above it, so
+        // mad = ((6 * mean - 60) + (110 - mean)) / 7 = (5 * mean + 50) / 7
+        let expected_mean = 170. / 7.;
+        assert!((mean - expected_mean).abs() < 1e-12);
+        assert!((mad - (5. * expected_mean + 50.) / 7.).abs() < 1e-12);
+
+        let std_dev = (data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / 7.).sqrt();
+        assert!(mad < std_dev);
+        assert!((mad - 24.49).abs() < 0.01);
+        assert!((std_dev - 35.01).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_mean_abs_deviation_constant() {
+        let MeanAbsDeviation::<f32> { mean, mad } =
+            [4u8; 5].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!((mean, mad), (4., 0.));
+
+        let empty: Option<MeanAbsDeviation<f32>> = std::iter::empty::<f32>().reduce_with();
+        assert_eq!(empty, None);
+    }
+}
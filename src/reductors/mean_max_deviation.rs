@@ -0,0 +1,148 @@
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the [arithmetic mean] of items yielded by an iterator, along with
+/// the maximum absolute deviation of any item from that mean.
+///
+/// Since the deviations depend on the final mean, this reductor keeps track of the sum, count,
+/// minimum and maximum of the items in a single pass, and the maximum absolute deviation is
+/// computed as `max(|max - mean|, |mean - min|)` when the iterator is exhausted.
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and the
+/// iterator's item type can be any type that implements [`Into<F>`].
+///
+/// [arithmetic mean]: https://en.wikipedia.org/wiki/Arithmetic_mean
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, MeanMaxDeviation};
+///
+/// let MeanMaxDeviation::<f64> { mean, max_abs_deviation } = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(mean, 5.);
+/// assert_eq!(max_abs_deviation, 4.);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeanMaxDeviation<F> {
+    /// Arithmetic mean of items yielded by iterator.
+    pub mean: F,
+    /// Maximum absolute deviation of any item from the mean.
+    pub max_abs_deviation: F,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Summary<F> {
+    sum: F,
+    count: usize,
+    min: F,
+    max: F,
+}
+
+macro_rules! impl_mean_max_deviation {
+    ($f:ty) => {
+        impl<T> Reductor<T> for MeanMaxDeviation<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<Summary<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                let item = item.into();
+                NonEmptyState(Summary {
+                    sum: item,
+                    count: 1,
+                    min: item,
+                    max: item,
+                })
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(summary): Self::State, item: T) -> Self::State {
+                let item = item.into();
+                NonEmptyState(Summary {
+                    sum: summary.sum + item,
+                    count: summary.count + 1,
+                    min: summary.min.min(item),
+                    max: summary.max.max(item),
+                })
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(summary): Self::State) -> Self {
+                let mean = summary.sum / summary.count as $f;
+                Self {
+                    mean,
+                    max_abs_deviation: (summary.max - mean).max(mean - summary.min),
+                }
+            }
+        }
+
+        impl<T> MergeReductor<T> for MeanMaxDeviation<$f>
+        where
+            T: Into<$f>,
+        {
+            #[inline]
+            fn merge(
+                NonEmptyState(left): Self::State,
+                NonEmptyState(right): Self::State,
+            ) -> Self::State {
+                NonEmptyState(Summary {
+                    sum: left.sum + right.sum,
+                    count: left.count + right.count,
+                    min: left.min.min(right.min),
+                    max: left.max.max(right.max),
+                })
+            }
+        }
+    };
+}
+
+impl_mean_max_deviation!(f32);
+impl_mean_max_deviation!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_mean_max_deviation() {
+        let data = [1.5, -3., 10., 4.25, 0.];
+
+        let MeanMaxDeviation::<f64> {
+            mean,
+            max_abs_deviation,
+        } = data.into_iter().reduce_with::<Option<_>>().unwrap();
+
+        let expected_mean = data.iter().sum::<f64>() / data.len() as f64;
+        let expected_deviation = data
+            .iter()
+            .map(|x| (x - expected_mean).abs())
+            .fold(0., f64::max);
+
+        assert!((mean - expected_mean).abs() < f64::EPSILON);
+        assert!((max_abs_deviation - expected_deviation).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_mean_max_deviation_single() {
+        let MeanMaxDeviation::<f32> {
+            mean,
+            max_abs_deviation,
+        } = [7i16].into_iter().reduce_with::<Option<_>>().unwrap();
+
+        assert_eq!(mean, 7.);
+        assert_eq!(max_abs_deviation, 0.);
+
+        assert_eq!(
+            std::iter::empty::<f64>().reduce_with::<Option<MeanMaxDeviation<f64>>>(),
+            None
+        );
+    }
+}
@@ -3,8 +3,11 @@ use std::{
     cmp::{self, Ord},
 };
 
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+
 use super::state::NonEmptyState;
-use crate::{Reductor, Reductors};
+use crate::{MergeReductor, Reductor, Reductors};
 
 macro_rules! impl_min_max {
     ($inner:ident, $cmp:path) => {
@@ -51,6 +54,27 @@ macro_rules! impl_min_max_option {
     };
 }
 
+macro_rules! impl_min_max_merge {
+    ($cmp:path) => {
+        #[inline]
+        fn merge(left: Self::State, right: Self::State) -> Self::State {
+            NonEmptyState($cmp(left.0, right.0))
+        }
+    };
+}
+
+macro_rules! impl_min_max_option_merge {
+    ($cmp:path) => {
+        #[inline]
+        fn merge(left: Self::State, right: Self::State) -> Self::State {
+            NonEmptyState(match (left.0, right.0) {
+                (Some(left), Some(right)) => Some($cmp(left, right)),
+                (left, right) => left.or(right),
+            })
+        }
+    };
+}
+
 /// Reductor that retains the minimum value yielded by an iterator (similarly to [`Iterator::min`]).
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -63,6 +87,13 @@ where
     impl_min_max!(T, cmp::min);
 }
 
+impl<T> MergeReductor<T> for Min<T>
+where
+    T: Ord,
+{
+    impl_min_max_merge!(cmp::min);
+}
+
 impl<T> Reductor<T> for Min<Option<T>>
 where
     T: Ord,
@@ -70,6 +101,13 @@ where
     impl_min_max_option!(T, cmp::min);
 }
 
+impl<T> MergeReductor<T> for Min<Option<T>>
+where
+    T: Ord,
+{
+    impl_min_max_option_merge!(cmp::min);
+}
+
 /// Reductor that retains the maximum value yielded by an iterator (similarly to [`Iterator::max`]).
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -82,6 +120,13 @@ where
     impl_min_max!(T, cmp::max);
 }
 
+impl<T> MergeReductor<T> for Max<T>
+where
+    T: Ord,
+{
+    impl_min_max_merge!(cmp::max);
+}
+
 impl<T> Reductor<T> for Max<Option<T>>
 where
     T: Ord,
@@ -89,6 +134,13 @@ where
     impl_min_max_option!(T, cmp::max);
 }
 
+impl<T> MergeReductor<T> for Max<Option<T>>
+where
+    T: Ord,
+{
+    impl_min_max_option_merge!(cmp::max);
+}
+
 /// Reductor that retains the maximum float value yielded by an iterator (similarly to [`Iterator::max`],
 /// but using [`f64::max`] or [`f32::max`] under the hood).
 #[repr(transparent)]
@@ -100,18 +152,34 @@ impl Reductor<f32> for MaxF<f32> {
     impl_min_max!(f32, f32::max);
 }
 
+impl MergeReductor<f32> for MaxF<f32> {
+    impl_min_max_merge!(f32::max);
+}
+
 impl Reductor<f32> for MaxF<Option<f32>> {
     impl_min_max_option!(f32, f32::max);
 }
 
+impl MergeReductor<f32> for MaxF<Option<f32>> {
+    impl_min_max_option_merge!(f32::max);
+}
+
 impl Reductor<f64> for MaxF<f64> {
     impl_min_max!(f64, f64::max);
 }
 
+impl MergeReductor<f64> for MaxF<f64> {
+    impl_min_max_merge!(f64::max);
+}
+
 impl Reductor<f64> for MaxF<Option<f64>> {
     impl_min_max_option!(f64, f64::max);
 }
 
+impl MergeReductor<f64> for MaxF<Option<f64>> {
+    impl_min_max_option_merge!(f64::max);
+}
+
 /// Reductor that retains the minimum float value yielded by an iterator (similarly to [`Iterator::min`],
 /// but using [`f64::min`] or [`f32::min`] under the hood).
 #[repr(transparent)]
@@ -123,18 +191,34 @@ impl Reductor<f32> for MinF<f32> {
     impl_min_max!(f32, f32::min);
 }
 
+impl MergeReductor<f32> for MinF<f32> {
+    impl_min_max_merge!(f32::min);
+}
+
 impl Reductor<f32> for MinF<Option<f32>> {
     impl_min_max_option!(f32, f32::min);
 }
 
+impl MergeReductor<f32> for MinF<Option<f32>> {
+    impl_min_max_option_merge!(f32::min);
+}
+
 impl Reductor<f64> for MinF<f64> {
     impl_min_max!(f64, f64::min);
 }
 
+impl MergeReductor<f64> for MinF<f64> {
+    impl_min_max_merge!(f64::min);
+}
+
 impl Reductor<f64> for MinF<Option<f64>> {
     impl_min_max_option!(f64, f64::min);
 }
 
+impl MergeReductor<f64> for MinF<Option<f64>> {
+    impl_min_max_option_merge!(f64::min);
+}
+
 macro_rules! impl_minmax {
     ($type:ident, Min: $min:ident, Max: $max:ident) => {
         impl_minmax!(
@@ -199,6 +283,18 @@ macro_rules! impl_minmax_option {
     };
 }
 
+macro_rules! impl_minmax_merge {
+    ($type:ident, Min: $min:ident, Max: $max:ident) => {
+        impl_minmax_merge!($type, Pair: Reductors<($min<$type>, $max<$type>)>);
+    };
+    ($type:ident, Pair: $pair_type:ty) => {
+        #[inline]
+        fn merge(left: Self::State, right: Self::State) -> Self::State {
+            <$pair_type as MergeReductor<$type>>::merge(left, right)
+        }
+    };
+}
+
 /// Reductor that retains both the minimum and the maximum values yielded by an iterator.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MinMax<T> {
@@ -215,6 +311,13 @@ where
     impl_minmax!(A, Min: Min, Max: Max);
 }
 
+impl<A> MergeReductor<A> for MinMax<A>
+where
+    A: Clone + Ord,
+{
+    impl_minmax_merge!(A, Min: Min, Max: Max);
+}
+
 impl<A> Reductor<A> for MinMax<Option<A>>
 where
     A: Clone + Ord,
@@ -222,6 +325,13 @@ where
     impl_minmax_option!(A, Min: Min, Max: Max);
 }
 
+impl<A> MergeReductor<A> for MinMax<Option<A>>
+where
+    A: Clone + Ord,
+{
+    impl_minmax_merge!(A, Pair: Reductors<(Min<Option<A>>, Max<Option<A>>)>);
+}
+
 /// Reductor that retains both the minimum and the maximum float values yielded by an iterator
 /// (using [`f64::min`] and [`f64::max`], or [`f32::min`] and [`f32::max`] under the hood).
 #[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
@@ -237,14 +347,125 @@ impl Reductor<f32> for MinMaxF<f32> {
     impl_minmax!(f32, Min: MinF, Max: MaxF);
 }
 
+impl MergeReductor<f32> for MinMaxF<f32> {
+    impl_minmax_merge!(f32, Min: MinF, Max: MaxF);
+}
+
 impl Reductor<f32> for MinMaxF<Option<f32>> {
     impl_minmax_option!(f32, Min: MinF, Max: MaxF);
 }
 
+impl MergeReductor<f32> for MinMaxF<Option<f32>> {
+    impl_minmax_merge!(f32, Pair: Reductors<(MinF<Option<f32>>, MaxF<Option<f32>>)>);
+}
+
 impl Reductor<f64> for MinMaxF<f64> {
     impl_minmax!(f64, Min: MinF, Max: MaxF);
 }
 
+impl MergeReductor<f64> for MinMaxF<f64> {
+    impl_minmax_merge!(f64, Min: MinF, Max: MaxF);
+}
+
 impl Reductor<f64> for MinMaxF<Option<f64>> {
     impl_minmax_option!(f64, Min: MinF, Max: MaxF);
 }
+
+impl MergeReductor<f64> for MinMaxF<Option<f64>> {
+    impl_minmax_merge!(f64, Pair: Reductors<(MinF<Option<f64>>, MaxF<Option<f64>>)>);
+}
+
+/// Half-precision floats use their own `min` and `max`, which have the same NaN semantics
+/// as [`f32::min`] and [`f32::max`].
+#[cfg(feature = "half")]
+macro_rules! impl_min_max_half {
+    ($($half:ident),+) => {
+        $(
+            impl Reductor<$half> for MaxF<$half> {
+                impl_min_max!($half, $half::max);
+            }
+
+            impl MergeReductor<$half> for MaxF<$half> {
+                impl_min_max_merge!($half::max);
+            }
+
+            impl Reductor<$half> for MaxF<Option<$half>> {
+                impl_min_max_option!($half, $half::max);
+            }
+
+            impl MergeReductor<$half> for MaxF<Option<$half>> {
+                impl_min_max_option_merge!($half::max);
+            }
+
+            impl Reductor<$half> for MinF<$half> {
+                impl_min_max!($half, $half::min);
+            }
+
+            impl MergeReductor<$half> for MinF<$half> {
+                impl_min_max_merge!($half::min);
+            }
+
+            impl Reductor<$half> for MinF<Option<$half>> {
+                impl_min_max_option!($half, $half::min);
+            }
+
+            impl MergeReductor<$half> for MinF<Option<$half>> {
+                impl_min_max_option_merge!($half::min);
+            }
+
+            impl Reductor<$half> for MinMaxF<$half> {
+                impl_minmax!($half, Min: MinF, Max: MaxF);
+            }
+
+            impl MergeReductor<$half> for MinMaxF<$half> {
+                impl_minmax_merge!($half, Min: MinF, Max: MaxF);
+            }
+
+            impl Reductor<$half> for MinMaxF<Option<$half>> {
+                impl_minmax_option!($half, Min: MinF, Max: MaxF);
+            }
+
+            impl MergeReductor<$half> for MinMaxF<Option<$half>> {
+                impl_minmax_merge!($half, Pair: Reductors<(MinF<Option<$half>>, MaxF<Option<$half>>)>);
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "half")]
+impl_min_max_half!(f16, bf16);
+
+#[cfg(all(test, feature = "half"))]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_half_min_max() {
+        let items = [2., f32::NAN, -3.5, 7., 0.].map(f16::from_f32);
+
+        let MinF(min) = items.into_iter().reduce_with::<Option<_>>().unwrap();
+        let MaxF(max) = items.into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!((min, max), (f16::from_f32(-3.5), f16::from_f32(7.)));
+
+        let MinMaxF { min, max } = items.into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!((min, max), (f16::from_f32(-3.5), f16::from_f32(7.)));
+
+        // NaN semantics match the `f32` implementations.
+        let nan_first = [f32::NAN, 1.];
+        let MinMaxF::<f32> { min, max } = nan_first.into_iter().reduce_with::<Option<_>>().unwrap();
+        let MinMaxF::<bf16> {
+            min: half_min,
+            max: half_max,
+        } = nan_first
+            .map(bf16::from_f32)
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!((f32::from(half_min), f32::from(half_max)), (min, max));
+
+        let MaxF::<Option<f16>>(max) = std::iter::empty::<f16>().reduce_with();
+        assert_eq!(max, None);
+    }
+}
@@ -0,0 +1,164 @@
+use std::{cmp::Ordering, marker::PhantomData};
+
+use crate::Reductor;
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T, F> {
+    compare: F,
+    value: Option<T>,
+}
+
+macro_rules! impl_min_max_by {
+    ($name:ident, $replace:expr) => {
+        impl<T, F> $name<T, F>
+        where
+            F: FnMut(&T, &T) -> Ordering,
+        {
+            /// Create the initial state of this reductor, from the comparison function,
+            /// to be passed to [`fold_with`](crate::Reduce::fold_with).
+            pub fn new_with(compare: F) -> State<T, F> {
+                State {
+                    compare,
+                    value: None,
+                }
+            }
+        }
+
+        impl<T, F> Reductor<T> for $name<T, F>
+        where
+            F: FnMut(&T, &T) -> Ordering,
+        {
+            type State = State<T, F>;
+
+            fn new(_: T) -> Self::State {
+                panic!(concat!(
+                    "`",
+                    stringify!($name),
+                    "` must be used with `fold_with`, providing a comparison function"
+                ))
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, item: T) -> Self::State {
+                state.value = Some(match state.value {
+                    Some(value) if !$replace((state.compare)(&item, &value)) => value,
+                    _ => item,
+                });
+                state
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self {
+                    value: state.value,
+                    compare: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+/// Reductor that retains the minimum value yielded by an iterator, with respect to a comparison
+/// function (similarly to [`Iterator::min_by`]).
+///
+/// Unlike [`Min`](crate::Min), this doesn't require items to implement [`Ord`], e.g. floats can
+/// be compared with [`f64::total_cmp`]. If several items are equally minimal, the first one is
+/// retained, like [`Iterator::min_by`]. The value is `None` if the iterator yields no items.
+///
+/// Since the comparison function must be stored in the reductor's state, this reductor can only
+/// be used with [`fold_with`](crate::Reduce::fold_with), by passing the initial state created by
+/// [`new_with`](MinBy::new_with). That state is already enough for reducing an empty iterator,
+/// so there's no need to wrap this reductor in an [`Option`].
+///
+/// # Panics
+/// Panics if reduced without a comparison function (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MinBy, Reduce};
+///
+/// let temperatures = [21.5, -3.25, 8., -3.25];
+///
+/// let MinBy { value, .. } = temperatures.into_iter().fold_with(MinBy::new_with(f64::total_cmp));
+/// assert_eq!(value, Some(-3.25));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MinBy<T, F> {
+    /// Minimum value yielded by iterator.
+    pub value: Option<T>,
+    compare: PhantomData<F>,
+}
+
+impl_min_max_by!(MinBy, |ordering| ordering == Ordering::Less);
+
+/// Reductor that retains the maximum value yielded by an iterator, with respect to a comparison
+/// function (similarly to [`Iterator::max_by`]).
+///
+/// If several items are equally maximal, the last one is retained, like [`Iterator::max_by`].
+/// See [`MinBy`] for more details.
+///
+/// # Panics
+/// Panics if reduced without a comparison function (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MaxBy, Reduce};
+///
+/// let words = ["apple", "fig", "cherry", "banana"];
+///
+/// let MaxBy { value, .. } = words
+///     .into_iter()
+///     .fold_with(MaxBy::new_with(|a: &&str, b: &&str| a.len().cmp(&b.len())));
+/// assert_eq!(value, Some("banana"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaxBy<T, F> {
+    /// Maximum value yielded by iterator.
+    pub value: Option<T>,
+    compare: PhantomData<F>,
+}
+
+impl_min_max_by!(MaxBy, |ordering| ordering != Ordering::Less);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_min_max_by_matches_iterator() {
+        // ties are broken by the second element, which the comparison ignores
+        let data = [(3, 'a'), (1, 'b'), (4, 'c'), (1, 'd'), (4, 'e'), (2, 'f')];
+        let compare = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+
+        let MinBy { value, .. } = data.into_iter().fold_with(MinBy::new_with(compare));
+        assert_eq!(value, data.into_iter().min_by(compare));
+        assert_eq!(value, Some((1, 'b')));
+
+        let MaxBy { value, .. } = data.into_iter().fold_with(MaxBy::new_with(compare));
+        assert_eq!(value, data.into_iter().max_by(compare));
+        assert_eq!(value, Some((4, 'e')));
+    }
+
+    #[test]
+    fn test_min_max_by_floats() {
+        let data = [2.5, f64::NAN, -1., 7.25, -0.];
+
+        let MinBy { value, .. } = data.into_iter().fold_with(MinBy::new_with(f64::total_cmp));
+        assert_eq!(value, Some(-1.));
+
+        let MaxBy { value, .. } =
+            data.into_iter()
+                .fold_with(MaxBy::new_with(|a: &f64, b: &f64| {
+                    a.partial_cmp(b).unwrap_or(Ordering::Less)
+                }));
+        assert_eq!(value, Some(7.25));
+    }
+
+    #[test]
+    fn test_min_max_by_empty() {
+        let MinBy { value, .. } = std::iter::empty::<u8>().fold_with(MinBy::new_with(u8::cmp));
+        assert_eq!(value, None);
+    }
+}
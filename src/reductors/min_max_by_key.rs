@@ -0,0 +1,171 @@
+use std::marker::PhantomData;
+
+use crate::Reductor;
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T, K, F> {
+    key: F,
+    best: Option<(K, T)>,
+}
+
+macro_rules! impl_min_max_by_key {
+    ($name:ident, $replace:expr) => {
+        impl<T, K, F> $name<T, K, F>
+        where
+            F: FnMut(&T) -> K,
+            K: Ord,
+        {
+            /// Create the initial state of this reductor, from the key function,
+            /// to be passed to [`fold_with`](crate::Reduce::fold_with).
+            pub fn new_with(key: F) -> State<T, K, F> {
+                State { key, best: None }
+            }
+        }
+
+        impl<T, K, F> Reductor<T> for $name<T, K, F>
+        where
+            F: FnMut(&T) -> K,
+            K: Ord,
+        {
+            type State = State<T, K, F>;
+
+            fn new(_: T) -> Self::State {
+                panic!(concat!(
+                    "`",
+                    stringify!($name),
+                    "` must be used with `fold_with`, providing a key function"
+                ))
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, item: T) -> Self::State {
+                let key = (state.key)(&item);
+                state.best = Some(match state.best {
+                    Some((best_key, best)) if !$replace(&key, &best_key) => (best_key, best),
+                    _ => (key, item),
+                });
+                state
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self {
+                    value: state.best.map(|(_, value)| value),
+                    key: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+/// Reductor that retains the value yielded by an iterator which has the minimum key, with respect
+/// to a key function (similarly to [`Iterator::min_by_key`]).
+///
+/// The key of the current minimum is kept in the reductor's state, so the key function is only
+/// called once per item. If several items have equally minimal keys, the first one is retained,
+/// like [`Iterator::min_by_key`]. The value is `None` if the iterator yields no items.
+///
+/// Since the key function must be stored in the reductor's state, this reductor can only
+/// be used with [`fold_with`](crate::Reduce::fold_with), by passing the initial state created by
+/// [`new_with`](MinByKey::new_with). See [`MinBy`](crate::MinBy) for comparing items directly.
+///
+/// # Panics
+/// Panics if reduced without a key function (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MinByKey, Reduce};
+///
+/// let words = ["cherry", "fig", "apple", "kiwi", "pea"];
+///
+/// let MinByKey { value, .. } = words.into_iter().fold_with(MinByKey::new_with(|word: &&str| word.len()));
+/// assert_eq!(value, Some("fig"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MinByKey<T, K, F> {
+    /// Value with minimum key yielded by iterator.
+    pub value: Option<T>,
+    key: PhantomData<(K, F)>,
+}
+
+impl_min_max_by_key!(MinByKey, |key: &K, best: &K| key < best);
+
+/// Reductor that retains the value yielded by an iterator which has the maximum key, with respect
+/// to a key function (similarly to [`Iterator::max_by_key`]).
+///
+/// If several items have equally maximal keys, the last one is retained, like
+/// [`Iterator::max_by_key`]. See [`MinByKey`] for more details.
+///
+/// # Panics
+/// Panics if reduced without a key function (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MaxByKey, Reduce};
+///
+/// let readings = [(1, -4_i32), (2, 3), (3, 4), (4, -2)];
+///
+/// let MaxByKey { value, .. } = readings
+///     .into_iter()
+///     .fold_with(MaxByKey::new_with(|&(_, reading): &(u32, i32)| reading.abs()));
+/// assert_eq!(value, Some((3, 4)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaxByKey<T, K, F> {
+    /// Value with maximum key yielded by iterator.
+    pub value: Option<T>,
+    key: PhantomData<(K, F)>,
+}
+
+impl_min_max_by_key!(MaxByKey, |key: &K, best: &K| key >= best);
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_min_max_by_key_matches_iterator() {
+        // ties are broken by the second element, which the key ignores
+        let data = [(3, 'a'), (1, 'b'), (4, 'c'), (1, 'd'), (4, 'e'), (2, 'f')];
+        let key = |&(n, _): &(i32, char)| n;
+
+        let MinByKey { value, .. } = data.into_iter().fold_with(MinByKey::new_with(key));
+        assert_eq!(value, data.into_iter().min_by_key(key));
+        assert_eq!(value, Some((1, 'b')));
+
+        let MaxByKey { value, .. } = data.into_iter().fold_with(MaxByKey::new_with(key));
+        assert_eq!(value, data.into_iter().max_by_key(key));
+        assert_eq!(value, Some((4, 'e')));
+    }
+
+    #[test]
+    fn test_min_max_by_key_calls_key_once() {
+        let calls = Cell::new(0);
+        let key = |n: &i32| {
+            calls.set(calls.get() + 1);
+            -n
+        };
+
+        let MinByKey { value, .. } = (0..10).fold_with(MinByKey::new_with(key));
+        assert_eq!(value, Some(9));
+        assert_eq!(calls.get(), 10);
+    }
+
+    #[test]
+    fn test_min_max_by_key_empty() {
+        let MaxByKey { value, .. } =
+            std::iter::empty::<u8>().fold_with(MaxByKey::new_with(|&n: &u8| n));
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    #[should_panic = "`MinByKey` must be used with `fold_with`, providing a key function"]
+    fn test_min_by_key_reduce_with() {
+        type KeyFn = fn(&u8) -> u8;
+        let _: Option<MinByKey<u8, u8, KeyFn>> = [1_u8].into_iter().reduce_with();
+    }
+}
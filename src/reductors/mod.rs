@@ -4,7 +4,13 @@ mod count;
 pub use self::count::{Count, CountNonZero};
 
 mod sum;
-pub use self::sum::Sum;
+pub use self::sum::{Sum, SumAssign};
+
+mod alternating_sum;
+pub use self::alternating_sum::AlternatingSum;
+
+mod consecutive_product_sum;
+pub use self::consecutive_product_sum::ConsecutiveProductSum;
 
 mod product;
 pub use self::product::Product;
@@ -12,7 +18,449 @@ pub use self::product::Product;
 mod min_max;
 pub use self::min_max::{Max, MaxF, Min, MinF, MinMax, MinMaxF};
 
+mod mean_max_deviation;
+pub use self::mean_max_deviation::MeanMaxDeviation;
+
+mod windowed_mean;
+pub use self::windowed_mean::WindowedMean;
+
+mod all_equal;
+pub use self::all_equal::AllEqual;
+
+mod second;
+pub use self::second::{SecondMax, SecondMin};
+
+mod streak;
+pub use self::streak::StreakStats;
+
+mod concat_bytes;
+pub use self::concat_bytes::ConcatBytes;
+
 mod mean;
 pub use self::mean::Mean;
 
+mod auto_histogram;
+pub use self::auto_histogram::AutoHistogram;
+
+mod popcount;
+pub use self::popcount::PopcountSum;
+
+mod byte_histogram;
+pub use self::byte_histogram::ByteHistogram;
+
+mod with_count;
+pub use self::with_count::{MaxWithCount, MinWithCount};
+
+mod first_exceeding;
+pub use self::first_exceeding::FirstExceeding;
+
+#[cfg(feature = "rand")]
+mod weighted_reservoir;
+#[cfg(feature = "rand")]
+pub use self::weighted_reservoir::WeightedReservoir;
+
+mod fingerprint;
+pub use self::fingerprint::SumAndFingerprint;
+
+#[cfg(feature = "rand")]
+mod bernoulli_sample;
+#[cfg(feature = "rand")]
+pub use self::bernoulli_sample::BernoulliSample;
+
+mod robust_slope;
+pub use self::robust_slope::RobustSlope;
+
+mod run_length;
+pub use self::run_length::RunLengthEncode;
+
+mod count_min_sketch;
+pub use self::count_min_sketch::CountMinSketch;
+
+mod max_drawdown;
+pub use self::max_drawdown::MaxDrawdown;
+
+mod streaming_histogram;
+pub use self::streaming_histogram::StreamingHistogram;
+
+mod weighted_sum;
+pub use self::weighted_sum::WeightedSumAndWeight;
+
+mod exp_histogram;
+pub use self::exp_histogram::ExpHistogram;
+
+mod gini_impurity;
+pub use self::gini_impurity::GiniImpurity;
+
+mod circular_mean;
+pub use self::circular_mean::CircularMean;
+
+mod capped_distinct;
+pub use self::capped_distinct::CappedDistinct;
+
+mod mean_abs_deviation;
+pub use self::mean_abs_deviation::MeanAbsDeviation;
+
+mod contraharmonic_mean;
+pub use self::contraharmonic_mean::ContraharmonicMean;
+
+mod proportion;
+pub use self::proportion::Proportion;
+
+mod mode;
+#[cfg(feature = "indexmap")]
+pub use self::mode::IndexedModeAndFrequencies;
+pub use self::mode::ModeAndFrequencies;
+
+mod sse;
+pub use self::sse::{SseInit, SumSquaredError};
+
+mod five_number;
+pub use self::five_number::FiveNumberSummary;
+
+#[cfg(feature = "half")]
+mod wide_sum;
+#[cfg(feature = "half")]
+pub use self::wide_sum::WideSum;
+
+mod monotonic_breaks;
+pub use self::monotonic_breaks::MonotonicBreaks;
+
+mod sparse_cosine;
+pub use self::sparse_cosine::SparseCosine;
+
+mod count_distinct;
+pub use self::count_distinct::CountDistinct;
+
+mod collect_multi_map;
+pub use self::collect_multi_map::CollectMultiMap;
+#[cfg(feature = "indexmap")]
+pub use self::collect_multi_map::IndexedCollectMultiMap;
+
+mod cdf_at;
+pub use self::cdf_at::CdfAt;
+
+mod collect_map;
+#[cfg(feature = "indexmap")]
+pub use self::collect_map::IndexedCollectMap;
+pub use self::collect_map::{
+    CollectMap, DuplicateKeyPolicy, KeepFirst, KeepLast, PanicOnDuplicate, ReduceValues,
+};
+
+mod running_total_peak;
+pub use self::running_total_peak::RunningTotalPeak;
+
+mod interquartile_mean;
+pub use self::interquartile_mean::InterquartileMean;
+
+mod map_like;
+pub use self::map_like::MapLike;
+
+mod bit_majority;
+pub use self::bit_majority::BitMajority;
+
+#[cfg(feature = "rand")]
+mod morris_count;
+#[cfg(feature = "rand")]
+pub use self::morris_count::MorrisCount;
+
+mod within_tolerance;
+pub use self::within_tolerance::WithinTolerance;
+
+mod windowed_min_max;
+pub use self::windowed_min_max::{WindowedMax, WindowedMin};
+
+mod sparse_vector;
+pub use self::sparse_vector::SparseVector;
+
+mod covariance_matrix;
+pub use self::covariance_matrix::CovarianceMatrix;
+
+mod event_window;
+pub use self::event_window::EventWindow;
+
+mod exact_sum;
+pub use self::exact_sum::ExactSum;
+
+mod geometric_std_dev;
+pub use self::geometric_std_dev::GeometricStdDev;
+
+mod capped_group_by;
+pub use self::capped_group_by::{CappedGroupBy, CountEvicted, EvictionPolicy, MergeEvicted};
+
+mod top_contributor;
+pub use self::top_contributor::SumWithTopContributor;
+
+mod decayed;
+pub use self::decayed::{DecayedCount, DecayedSum};
+
+mod diversity;
+pub use self::diversity::DiversitySummary;
+
+mod sessions;
+pub use self::sessions::Sessions;
+
+mod duration_sum;
+pub use self::duration_sum::DurationSum;
+
+mod throughput;
+pub use self::throughput::{Throughput, Timestamp};
+
+mod duration_stats;
+pub use self::duration_stats::DurationStats;
+
+mod benford;
+pub use self::benford::{BenfordDigits, LeadingDigit};
+
+mod range_buckets;
+pub use self::range_buckets::RangeBuckets;
+
+mod common_prefix;
+pub use self::common_prefix::CommonPrefix;
+
+mod extreme_frequencies;
+pub use self::extreme_frequencies::ExtremeFrequencies;
+
+mod two_sample;
+pub use self::two_sample::{GroupStats, TwoSampleStats};
+
+mod fixed_point_sum;
+pub use self::fixed_point_sum::FixedPointSum;
+
+#[cfg(feature = "num-rational")]
+mod exact_mean;
+#[cfg(feature = "num-rational")]
+pub use self::exact_mean::ExactMean;
+
+mod most_common_length;
+pub use self::most_common_length::{ByLen, MostCommonLength};
+
+mod variance;
+pub use self::variance::{MeanVariance, SampleStdDev, SampleVariance, StdDev, Variance};
+
+mod frame_summary;
+pub use self::frame_summary::FrameSummary;
+
+mod multiset_hash;
+pub use self::multiset_hash::MultisetHash;
+
+mod arg_min_max;
+pub use self::arg_min_max::{ArgMax, ArgMin};
+
+mod segmented_sums;
+pub use self::segmented_sums::SegmentedSums;
+
+mod min_max_by;
+pub use self::min_max_by::{MaxBy, MinBy};
+
+mod byte_entropy;
+pub use self::byte_entropy::ByteEntropy;
+
+mod min_max_by_key;
+pub use self::min_max_by_key::{MaxByKey, MinByKey};
+
+mod moments;
+pub use self::moments::{CentralMoments, Kurtosis, Skewness};
+
+mod sum_and_sign_changes;
+pub use self::sum_and_sign_changes::SumAndSignChanges;
+
+mod collect;
+pub use self::collect::{Collect, CollectVec};
+
+mod majority_element;
+pub use self::majority_element::MajorityElement;
+
+mod histogram;
+pub use self::histogram::Histogram;
+#[cfg(feature = "indexmap")]
+pub use self::histogram::IndexedHistogram;
+
+mod group_reduce;
+pub use self::group_reduce::GroupReduce;
+#[cfg(feature = "indexmap")]
+pub use self::group_reduce::IndexedGroupReduce;
+
+mod geometric_mean;
+pub use self::geometric_mean::GeometricMean;
+
+mod hamming_weights;
+pub use self::hamming_weights::HammingWeights;
+
+mod harmonic_mean;
+pub use self::harmonic_mean::HarmonicMean;
+
+mod weighted_mode;
+pub use self::weighted_mode::WeightedMode;
+
+mod const_of;
+
+mod crc32;
+
+mod fnv;
+
 mod state;
+pub use self::state::NonEmptyState;
+
+// Type aliases for the [`State`](crate::Reductor::State)s of the reductors above, so that they
+// can be named (e.g. for storing states, or for implementing `From` conversions to use with
+// `fold_with`). The states of reductors that are missing here are already nameable.
+
+/// [`State`](crate::Reductor::State) of [`AllEqual`].
+pub type AllEqualState<T> = all_equal::State<T>;
+/// [`State`](crate::Reductor::State) of [`AlternatingSum`].
+pub type AlternatingSumState<T> = alternating_sum::State<T>;
+/// [`State`](crate::Reductor::State) of [`ArgMax`].
+pub type ArgMaxState<T> = NonEmptyState<arg_min_max::State<T>>;
+/// [`State`](crate::Reductor::State) of [`ArgMin`].
+pub type ArgMinState<T> = NonEmptyState<arg_min_max::State<T>>;
+/// [`State`](crate::Reductor::State) of [`AutoHistogram`].
+pub type AutoHistogramState<F> = auto_histogram::State<F>;
+/// [`State`](crate::Reductor::State) of [`BernoulliSample`].
+#[cfg(feature = "rand")]
+pub type BernoulliSampleState<T, R> = bernoulli_sample::State<T, R>;
+/// [`State`](crate::Reductor::State) of [`BitMajority`].
+pub type BitMajorityState<const BITS: usize> = bit_majority::State<BITS>;
+/// [`State`](crate::Reductor::State) of [`ByteHistogram`].
+pub type ByteHistogramState = byte_histogram::State;
+/// [`State`](crate::Reductor::State) of [`CappedDistinct`].
+pub type CappedDistinctState<T> = capped_distinct::State<T>;
+/// [`State`](crate::Reductor::State) of [`CappedGroupBy`].
+pub type CappedGroupByState<K, S> = capped_group_by::State<K, S>;
+/// [`State`](crate::Reductor::State) of [`CdfAt`].
+pub type CdfAtState<F> = cdf_at::State<F>;
+/// [`State`](crate::Reductor::State) of [`CentralMoments`].
+pub type CentralMomentsState<F, const K: usize> = NonEmptyState<moments::State<F, K>>;
+/// [`State`](crate::Reductor::State) of [`CircularMean`].
+pub type CircularMeanState<F> = circular_mean::State<F>;
+/// [`State`](crate::Reductor::State) of [`CollectMap`].
+pub type CollectMapState<K, S> = collect_map::State<std::collections::HashMap<K, S>>;
+/// [`State`](crate::Reductor::State) of [`ConcatBytes`].
+pub type ConcatBytesState = concat_bytes::State;
+/// [`State`](crate::Reductor::State) of [`ConsecutiveProductSum`].
+pub type ConsecutiveProductSumState<T> = consecutive_product_sum::State<T>;
+/// [`State`](crate::Reductor::State) of [`CountDistinct`].
+pub type CountDistinctState<T> = count_distinct::State<T>;
+/// [`State`](crate::Reductor::State) of [`CovarianceMatrix`].
+pub type CovarianceMatrixState<const D: usize> = NonEmptyState<covariance_matrix::State<D>>;
+/// [`State`](crate::Reductor::State) of [`DecayedCount`].
+pub type DecayedCountState = decayed::State<f64>;
+/// [`State`](crate::Reductor::State) of [`DecayedSum`].
+pub type DecayedSumState<F> = decayed::State<F>;
+/// [`State`](crate::Reductor::State) of [`DiversitySummary`].
+pub type DiversitySummaryState<T> = diversity::State<T>;
+/// [`State`](crate::Reductor::State) of [`DurationStats`].
+pub type DurationStatsState = duration_stats::State;
+/// [`State`](crate::Reductor::State) of [`EventWindow`].
+pub type EventWindowState<T> = event_window::State<T>;
+/// [`State`](crate::Reductor::State) of [`ExactMean`].
+#[cfg(feature = "num-rational")]
+pub type ExactMeanState = NonEmptyState<exact_mean::State>;
+/// [`State`](crate::Reductor::State) of [`ExactSum`].
+pub type ExactSumState<F> = exact_sum::State<F>;
+/// [`State`](crate::Reductor::State) of [`ExpHistogram`].
+pub type ExpHistogramState = exp_histogram::State;
+/// [`State`](crate::Reductor::State) of [`ExtremeFrequencies`].
+pub type ExtremeFrequenciesState<T> = extreme_frequencies::State<T>;
+/// [`State`](crate::Reductor::State) of [`FirstExceeding`].
+pub type FirstExceedingState<T> = first_exceeding::State<T>;
+/// [`State`](crate::Reductor::State) of [`FixedPointSum`].
+pub type FixedPointSumState = fixed_point_sum::State;
+/// [`State`](crate::Reductor::State) of [`FrameSummary`].
+pub type FrameSummaryState = frame_summary::State;
+/// [`State`](crate::Reductor::State) of [`GeometricStdDev`].
+pub type GeometricStdDevState<F> = NonEmptyState<geometric_std_dev::State<F>>;
+/// [`State`](crate::Reductor::State) of [`GiniImpurity`].
+pub type GiniImpurityState<T> = gini_impurity::State<T>;
+/// [`State`](crate::Reductor::State) of [`GroupReduce`].
+pub type GroupReduceState<K, S> = CollectMapState<K, Option<S>>;
+/// [`State`](crate::Reductor::State) of [`IndexedCollectMap`].
+#[cfg(feature = "indexmap")]
+pub type IndexedCollectMapState<K, S> = collect_map::State<indexmap::IndexMap<K, S>>;
+/// [`State`](crate::Reductor::State) of [`IndexedGroupReduce`].
+#[cfg(feature = "indexmap")]
+pub type IndexedGroupReduceState<K, S> = IndexedCollectMapState<K, Option<S>>;
+/// [`State`](crate::Reductor::State) of [`IndexedModeAndFrequencies`].
+#[cfg(feature = "indexmap")]
+pub type IndexedModeAndFrequenciesState<T> = mode::State<T, indexmap::IndexMap<T, usize>>;
+/// [`State`](crate::Reductor::State) of [`Kurtosis`].
+pub type KurtosisState<F> = NonEmptyState<moments::State<F, 4>>;
+/// [`State`](crate::Reductor::State) of [`MajorityElement`].
+pub type MajorityElementState<T> = majority_element::State<T>;
+/// [`State`](crate::Reductor::State) of [`MaxByKey`].
+pub type MaxByKeyState<T, K, F> = min_max_by_key::State<T, K, F>;
+/// [`State`](crate::Reductor::State) of [`MaxBy`].
+pub type MaxByState<T, F> = min_max_by::State<T, F>;
+/// [`State`](crate::Reductor::State) of [`MaxDrawdown`].
+pub type MaxDrawdownState<F> = NonEmptyState<max_drawdown::State<F>>;
+/// [`State`](crate::Reductor::State) of [`MeanMaxDeviation`].
+pub type MeanMaxDeviationState<F> = NonEmptyState<mean_max_deviation::Summary<F>>;
+/// [`State`](crate::Reductor::State) of [`MeanVariance`].
+pub type MeanVarianceState<F> = NonEmptyState<variance::State<F>>;
+/// [`State`](crate::Reductor::State) of [`MinByKey`].
+pub type MinByKeyState<T, K, F> = min_max_by_key::State<T, K, F>;
+/// [`State`](crate::Reductor::State) of [`MinBy`].
+pub type MinByState<T, F> = min_max_by::State<T, F>;
+/// [`State`](crate::Reductor::State) of [`ModeAndFrequencies`].
+pub type ModeAndFrequenciesState<T> = mode::State<T, std::collections::HashMap<T, usize>>;
+/// [`State`](crate::Reductor::State) of [`MonotonicBreaks`].
+pub type MonotonicBreaksState<T> = monotonic_breaks::State<T>;
+/// [`State`](crate::Reductor::State) of [`MorrisCount`].
+#[cfg(feature = "rand")]
+pub type MorrisCountState<R> = morris_count::State<R>;
+/// [`State`](crate::Reductor::State) of [`MostCommonLength`].
+pub type MostCommonLengthState<L = ByLen> = most_common_length::State<L>;
+/// [`State`](crate::Reductor::State) of [`Product`].
+pub type ProductState<T> = product::State<T>;
+/// [`State`](crate::Reductor::State) of [`Proportion`].
+pub type ProportionState<P> = proportion::State<P>;
+/// [`State`](crate::Reductor::State) of [`RangeBuckets`].
+pub type RangeBucketsState<F, const N: usize> = range_buckets::State<F, N>;
+/// [`State`](crate::Reductor::State) of [`RobustSlope`].
+pub type RobustSlopeState<F> = robust_slope::State<F>;
+/// [`State`](crate::Reductor::State) of [`RunLengthEncode`].
+pub type RunLengthEncodeState<T> = run_length::State<T>;
+/// [`State`](crate::Reductor::State) of [`SampleStdDev`].
+pub type SampleStdDevState<F> = NonEmptyState<variance::State<F>>;
+/// [`State`](crate::Reductor::State) of [`SampleVariance`].
+pub type SampleVarianceState<F> = NonEmptyState<variance::State<F>>;
+/// [`State`](crate::Reductor::State) of [`SegmentedSums`].
+pub type SegmentedSumsState<T> = segmented_sums::State<T>;
+/// [`State`](crate::Reductor::State) of [`Sessions`].
+pub type SessionsState<T, D> = sessions::State<T, D>;
+/// [`State`](crate::Reductor::State) of [`Skewness`].
+pub type SkewnessState<F> = NonEmptyState<moments::State<F, 3>>;
+/// [`State`](crate::Reductor::State) of [`SparseCosine`].
+pub type SparseCosineState<F> = sparse_cosine::State<F>;
+/// [`State`](crate::Reductor::State) of [`SparseVector`].
+pub type SparseVectorState<F> = sparse_vector::State<F>;
+/// [`State`](crate::Reductor::State) of [`StdDev`].
+pub type StdDevState<F> = NonEmptyState<variance::State<F>>;
+/// [`State`](crate::Reductor::State) of [`StreakStats`].
+pub type StreakStatsState<P> = streak::State<P>;
+/// [`State`](crate::Reductor::State) of [`StreamingHistogram`].
+pub type StreamingHistogramState<F> = streaming_histogram::State<F>;
+/// [`State`](crate::Reductor::State) of [`SumAndSignChanges`].
+pub type SumAndSignChangesState<T> = sum_and_sign_changes::State<T>;
+/// [`State`](crate::Reductor::State) of [`SumSquaredError`].
+pub type SumSquaredErrorState<F> = sse::State<F>;
+/// [`State`](crate::Reductor::State) of [`SumAndFingerprint`].
+pub type SumAndFingerprintState<T> = fingerprint::State<T>;
+/// [`State`](crate::Reductor::State) of [`Throughput`].
+pub type ThroughputState<T> = throughput::State<T>;
+/// [`State`](crate::Reductor::State) of [`TwoSampleStats`].
+pub type TwoSampleStatsState<F> = two_sample::State<F>;
+/// [`State`](crate::Reductor::State) of [`Variance`].
+pub type VarianceState<F> = NonEmptyState<variance::State<F>>;
+/// [`State`](crate::Reductor::State) of [`WeightedMode`].
+pub type WeightedModeState<T> = weighted_mode::State<T>;
+/// [`State`](crate::Reductor::State) of [`WeightedReservoir`].
+#[cfg(feature = "rand")]
+pub type WeightedReservoirState<T, R> = weighted_reservoir::State<T, R>;
+/// [`State`](crate::Reductor::State) of [`WindowedMean`].
+pub type WindowedMeanState<F, const N: usize> = NonEmptyState<windowed_mean::Window<F, N>>;
+/// [`State`](crate::Reductor::State) of [`WindowedMax`].
+pub type WindowedMaxState<T, const N: usize> = NonEmptyState<windowed_min_max::Window<T, N>>;
+/// [`State`](crate::Reductor::State) of [`WindowedMin`].
+pub type WindowedMinState<T, const N: usize> = NonEmptyState<windowed_min_max::Window<T, N>>;
+/// [`State`](crate::Reductor::State) of [`WithinTolerance`].
+pub type WithinToleranceState<F> = within_tolerance::State<F>;
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "indexmap")]
+use indexmap::IndexMap;
+
+use super::map_like::MapLike;
+use crate::Reductor;
+
+/// Reductor that counts the occurrences of every distinct item yielded by an iterator,
+/// while also tracking the most common item (the [mode]).
+///
+/// If several items are tied for the highest count, the mode is the one that reached that
+/// count first. For example, the mode of `[a, b, b, a]` is `b`, since it was the first to
+/// occur twice. The mode is `None` if the iterator yields no items.
+///
+/// See `IndexedModeAndFrequencies` (with the `indexmap` feature) for keeping the items in the
+/// order they were first yielded.
+///
+/// [mode]: https://en.wikipedia.org/wiki/Mode_(statistics)
+///
+/// # Examples
+/// ```rust
+/// use reductor::{ModeAndFrequencies, Reduce};
+///
+/// let ModeAndFrequencies { mode, frequencies } = "mississippi".chars().reduce_with();
+///
+/// assert_eq!(mode, Some('s'));
+/// assert_eq!(frequencies[&'i'], 4);
+/// assert_eq!(frequencies[&'p'], 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ModeAndFrequencies<T> {
+    /// Most common item.
+    pub mode: Option<T>,
+    /// Number of occurrences of each distinct item.
+    pub frequencies: HashMap<T, usize>,
+}
+
+/// Like [`ModeAndFrequencies`], but counts the items in an [`IndexMap`], so that they are
+/// iterated in the order they were first yielded.
+///
+/// Requires the `indexmap` feature.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{IndexedModeAndFrequencies, Reduce};
+///
+/// let IndexedModeAndFrequencies { mode, frequencies } = "mississippi".chars().reduce_with();
+///
+/// assert_eq!(mode, Some('s'));
+/// assert!(frequencies.keys().eq(&['m', 'i', 's', 'p']));
+/// ```
+#[cfg(feature = "indexmap")]
+#[derive(Debug, Clone)]
+pub struct IndexedModeAndFrequencies<T> {
+    /// Most common item.
+    pub mode: Option<T>,
+    /// Number of occurrences of each distinct item, in the order they were first yielded.
+    pub frequencies: IndexMap<T, usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct State<T, F> {
+    frequencies: F,
+    leader: Option<(T, usize)>,
+}
+
+impl<T, F> Default for State<T, F>
+where
+    F: Default,
+{
+    fn default() -> Self {
+        Self {
+            frequencies: F::default(),
+            leader: None,
+        }
+    }
+}
+
+macro_rules! impl_mode_and_frequencies {
+    ($name:ident, $map:ident) => {
+        impl<T> Default for $name<T> {
+            fn default() -> Self {
+                Self {
+                    mode: None,
+                    frequencies: $map::default(),
+                }
+            }
+        }
+
+        impl<T> PartialEq for $name<T>
+        where
+            T: PartialEq,
+            $map<T, usize>: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.mode == other.mode && self.frequencies == other.frequencies
+            }
+        }
+
+        impl<T> Eq for $name<T>
+        where
+            T: Eq,
+            $map<T, usize>: Eq,
+        {
+        }
+
+        impl<T> Reductor<T> for $name<T>
+        where
+            T: PartialEq + Clone,
+            $map<T, usize>: MapLike<T, usize>,
+        {
+            type State = State<T, $map<T, usize>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, item: T) -> Self::State {
+                let count =
+                    state
+                        .frequencies
+                        .upsert(item.clone(), (), |()| 1, |count, ()| *count += 1);
+
+                match &mut state.leader {
+                    Some((_, leader_count)) if *leader_count >= *count => {}
+                    leader => *leader = Some((item, *count)),
+                }
+                state
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self {
+                    mode: state.leader.map(|(mode, _)| mode),
+                    frequencies: state.frequencies,
+                }
+            }
+        }
+    };
+}
+
+impl_mode_and_frequencies!(ModeAndFrequencies, HashMap);
+#[cfg(feature = "indexmap")]
+impl_mode_and_frequencies!(IndexedModeAndFrequencies, IndexMap);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_mode_and_frequencies() {
+        let ModeAndFrequencies { mode, frequencies } = [3, 1, 3, 2, 1, 3].into_iter().reduce_with();
+
+        assert_eq!(mode, Some(3));
+        assert_eq!(frequencies, HashMap::from([(1, 2), (2, 1), (3, 3)]));
+    }
+
+    #[test]
+    fn test_mode_tie() {
+        let ModeAndFrequencies { mode, .. } = ["a", "b", "b", "a"].into_iter().reduce_with();
+        assert_eq!(mode, Some("b"));
+
+        let ModeAndFrequencies { mode, .. } = ["a", "b", "c"].into_iter().reduce_with();
+        assert_eq!(mode, Some("a"));
+
+        let empty: ModeAndFrequencies<u8> = std::iter::empty().reduce_with();
+        assert_eq!(empty, ModeAndFrequencies::default());
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_mode_and_frequencies_indexed() {
+        let text = "the quick brown fox jumps over the lazy dog";
+
+        let hashed: ModeAndFrequencies<_> = text.chars().reduce_with();
+        let indexed: IndexedModeAndFrequencies<_> = text.chars().reduce_with();
+
+        let mut first_seen = Vec::new();
+        for c in text.chars() {
+            if !first_seen.contains(&c) {
+                first_seen.push(c);
+            }
+        }
+
+        assert!(indexed.frequencies.keys().eq(&first_seen));
+        assert_eq!(indexed.mode, hashed.mode);
+        assert_eq!(
+            indexed.frequencies.into_iter().collect::<HashMap<_, _>>(),
+            hashed.frequencies
+        );
+    }
+}
@@ -0,0 +1,534 @@
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the (adjusted) [sample skewness] of items yielded by an iterator,
+/// i.e. a measure of the asymmetry of their distribution around their mean.
+///
+/// The mean and the second and third central moments are updated for each item using the
+/// [online update formulas] generalizing Welford's algorithm (see [`Variance`](crate::Variance)),
+/// which avoids the catastrophic cancellation of accumulating raw powers of the items (see
+/// [`CentralMoments`]).
+/// The result is the adjusted Fisher-Pearson coefficient `G₁` (as computed by e.g. spreadsheet
+/// software), which is only defined for three or more items - with one or two items,
+/// the result is NaN. It is also NaN when all items are equal, since their variance is then zero.
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and the
+/// iterator's item type can be any type that implements [`Into<F>`].
+///
+/// [sample skewness]: https://en.wikipedia.org/wiki/Skewness#Sample_skewness
+/// [online update formulas]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Higher-order_statistics
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, Skewness};
+///
+/// let Skewness::<f64>(skewness) = [1, 2, 3, 10].into_iter().reduce_with::<Option<_>>().unwrap();
+/// assert!((skewness - 1.763).abs() < 1e-3);
+///
+/// let Skewness::<f64>(skewness) = [1, 2].into_iter().reduce_with::<Option<_>>().unwrap();
+/// assert!(skewness.is_nan());
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Skewness<F>(pub F);
+
+/// Reductor that computes the (population) [excess kurtosis] of items yielded by an iterator,
+/// i.e. a measure of how heavy the tails of their distribution are, relative to a normal
+/// distribution (whose excess kurtosis is zero).
+///
+/// Like [`Skewness`], this updates the central moments of the items (up to the fourth) for each
+/// item, see it for details. The result is `g₂ = m₄ / m₂² - 3`, where `mₖ` is the `k`-th
+/// central moment of the items. It is NaN when all items are equal (including when there's a
+/// single item), since their variance is then zero.
+///
+/// [excess kurtosis]: https://en.wikipedia.org/wiki/Kurtosis#Excess_kurtosis
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Kurtosis, Mean, Reduce, Reductors};
+///
+/// let Reductors((Mean::<f64>(mean), Kurtosis::<f64>(kurtosis))) = [1, 2, 3, 4, 5]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(mean, 3.);
+/// assert!((kurtosis + 1.3).abs() < 1e-12);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Kurtosis<F>(pub F);
+
+/// Reductor that computes the first `K` [central moments] of items yielded by an iterator,
+/// along with their count and mean, in a single pass.
+///
+/// The moments are kept as sums, rather than averages, i.e. `moments[p - 1]` is the sum of the
+/// differences of the items from their mean, raised to the `p`-th power (so the first moment is
+/// always zero, and the second is `count` times the [`Variance`](crate::Variance)). Dividing by
+/// `count` gives the (population) central moments.
+///
+/// The moments are updated for each item (and merged) using the [general online update
+/// formulas], which avoid the catastrophic cancellation of accumulating raw powers of the items.
+/// [`Skewness`] and [`Kurtosis`] are thin wrappers over this reductor.
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and the
+/// iterator's item type can be any type that implements [`Into<F>`].
+///
+/// [central moments]: https://en.wikipedia.org/wiki/Central_moment
+/// [general online update formulas]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Higher-order_statistics
+///
+/// # Examples
+/// ```rust
+/// use reductor::{CentralMoments, Reduce};
+///
+/// let CentralMoments::<f64, 3> { count, mean, moments } = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!((count, mean), (8, 5.));
+/// assert_eq!(moments, [0., 32., 42.]);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CentralMoments<F, const K: usize> {
+    /// Number of items yielded by iterator.
+    pub count: usize,
+    /// Mean of items yielded by iterator.
+    pub mean: F,
+    /// Sums of the differences of items yielded by iterator from their mean, raised to the
+    /// powers `1..=K`.
+    pub moments: [F; K],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<F, const K: usize> {
+    count: usize,
+    mean: F,
+    /// Sums of the differences from the mean, raised to the powers `1..=K`.
+    sums: [F; K],
+}
+
+macro_rules! impl_moments {
+    ($f:ty) => {
+        impl<const K: usize> State<$f, K> {
+            #[inline]
+            fn new(item: $f) -> Self {
+                Self {
+                    count: 1,
+                    mean: item,
+                    sums: [0.; K],
+                }
+            }
+
+            /// Equivalent to merging with `Self::new(item)`, but since the new item's sums are all
+            /// zero, and its count is one, most terms of the merge vanish.
+            #[inline]
+            fn push(mut self, item: $f) -> Self {
+                let prev_count = self.count as $f;
+                self.count += 1;
+                let delta_n = (item - self.mean) / self.count as $f;
+
+                // powers `0..K` of `delta_n` and of the previous count
+                let mut delta_n_pows = [1.; K];
+                let mut count_pows = [1.; K];
+                for k in 1..K {
+                    delta_n_pows[k] = delta_n_pows[k - 1] * delta_n;
+                    count_pows[k] = count_pows[k - 1] * prev_count;
+                }
+
+                // go from the highest power down, so that the lower sums each sum is computed
+                // from are still those from before the item was pushed
+                for p in (2..=K).rev() {
+                    let mut sum = self.sums[p - 1];
+
+                    let mut binomial = 1.;
+                    for k in 1..=p - 2 {
+                        binomial = binomial * (p - k + 1) as $f / k as $f;
+                        let term = binomial * delta_n_pows[k] * self.sums[p - k - 1];
+                        sum += if k % 2 == 0 { term } else { -term };
+                    }
+
+                    let sign = if p % 2 == 0 { 1. } else { -1. };
+                    sum += prev_count * delta_n_pows[p - 1] * delta_n * (count_pows[p - 1] + sign);
+                    self.sums[p - 1] = sum;
+                }
+
+                self.mean += delta_n;
+                self
+            }
+
+            fn merge(self, other: Self) -> Self {
+                let count = self.count + other.count;
+                let n = count as $f;
+                let (self_count, other_count) = (self.count as $f, other.count as $f);
+                let delta = other.mean - self.mean;
+
+                // sums of powers `p` only depend on sums of lower powers (the first is always
+                // zero), so each sum is computed from the sums of both sides, before merging
+                let mut sums = [0.; K];
+                for p in 2..=K {
+                    let mut sum = self.sums[p - 1] + other.sums[p - 1];
+
+                    let mut binomial = 1.;
+                    for k in 1..=p - 2 {
+                        binomial = binomial * (p - k + 1) as $f / k as $f;
+                        sum += binomial
+                            * delta.powi(k as i32)
+                            * ((-other_count / n).powi(k as i32) * self.sums[p - k - 1]
+                                + (self_count / n).powi(k as i32) * other.sums[p - k - 1]);
+                    }
+
+                    sum += (self_count * other_count * delta / n).powi(p as i32)
+                        * (other_count.powi(1 - p as i32) - (-self_count).powi(1 - p as i32));
+                    sums[p - 1] = sum;
+                }
+
+                Self {
+                    count,
+                    mean: self.mean + delta * other_count / n,
+                    sums,
+                }
+            }
+        }
+
+        impl<T, const K: usize> Reductor<T> for CentralMoments<$f, K>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f, K>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                NonEmptyState(State::<$f, K>::new(item.into()))
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(state): Self::State, item: T) -> Self::State {
+                NonEmptyState(state.push(item.into()))
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(state): Self::State) -> Self {
+                Self {
+                    count: state.count,
+                    mean: state.mean,
+                    moments: state.sums,
+                }
+            }
+        }
+
+        impl<T, const K: usize> MergeReductor<T> for CentralMoments<$f, K>
+        where
+            T: Into<$f>,
+        {
+            fn merge(
+                NonEmptyState(left): Self::State,
+                NonEmptyState(right): Self::State,
+            ) -> Self::State {
+                NonEmptyState(left.merge(right))
+            }
+        }
+
+        impl<T> Reductor<T> for Skewness<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f, 3>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                <CentralMoments<$f, 3> as Reductor<T>>::new(item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: T) -> Self::State {
+                <CentralMoments<$f, 3> as Reductor<T>>::reduce(state, item)
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                let CentralMoments {
+                    count,
+                    moments: [_, m2, m3],
+                    ..
+                } = <CentralMoments<$f, 3> as Reductor<T>>::into_result(state);
+                if count < 3 {
+                    return Self(<$f>::NAN);
+                }
+
+                let n = count as $f;
+                let biased = n.sqrt() * m3 / m2.powf(1.5);
+                Self(biased * (n * (n - 1.)).sqrt() / (n - 2.))
+            }
+        }
+
+        impl<T> MergeReductor<T> for Skewness<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                <CentralMoments<$f, 3> as MergeReductor<T>>::merge(left, right)
+            }
+        }
+
+        impl<T> Reductor<T> for Kurtosis<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f, 4>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                <CentralMoments<$f, 4> as Reductor<T>>::new(item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: T) -> Self::State {
+                <CentralMoments<$f, 4> as Reductor<T>>::reduce(state, item)
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                let CentralMoments {
+                    count,
+                    moments: [_, m2, _, m4],
+                    ..
+                } = <CentralMoments<$f, 4> as Reductor<T>>::into_result(state);
+                Self(count as $f * m4 / (m2 * m2) - 3.)
+            }
+        }
+
+        impl<T> MergeReductor<T> for Kurtosis<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                <CentralMoments<$f, 4> as MergeReductor<T>>::merge(left, right)
+            }
+        }
+    };
+}
+
+impl_moments!(f32);
+impl_moments!(f64);
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use crate::{parallel::par_reduce_slice, Reduce, Reductor, Reductors, Variance};
+
+    use super::*;
+
+    #[test]
+    fn test_central_moments_variance() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        let data: Vec<f64> = (0..500).map(|_| rng.random_range(-20. ..80.)).collect();
+
+        let Reductors((
+            CentralMoments::<f64, 2> {
+                count,
+                mean,
+                moments: [m1, m2],
+            },
+            Variance::<f64>(variance),
+        )) = data.iter().copied().reduce_with::<Option<_>>().unwrap();
+
+        assert_eq!(count, data.len());
+        assert!((mean - data.iter().sum::<f64>() / count as f64).abs() < 1e-12);
+        assert_eq!(m1, 0.);
+        assert!((m2 - variance * count as f64).abs() < 1e-9 * m2);
+    }
+
+    #[test]
+    fn test_central_moments_fixed() {
+        let data = [-4.5, 1., 2.25, -0.5, 8., 3., -1.25, 0.];
+        let n = data.len() as f64;
+        let mean = data.iter().sum::<f64>() / n;
+        let expected: [f64; 6] =
+            std::array::from_fn(|p| data.iter().map(|x| (x - mean).powi(p as i32 + 1)).sum());
+
+        let CentralMoments::<f64, 6> { count, moments, .. } =
+            data.iter().copied().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(count, data.len());
+        assert_eq!(moments[0], 0.);
+        for (moment, expected) in moments.into_iter().zip(expected).skip(1) {
+            assert!(
+                (moment - expected).abs() < 1e-10 * expected.abs().max(1.),
+                "{moment} != {expected}"
+            );
+        }
+
+        for threads in 2..=4 {
+            let CentralMoments::<f64, 6> {
+                moments: merged, ..
+            } = par_reduce_slice::<Option<_>, _>(&data, threads, 0).unwrap();
+            for (moment, expected) in merged.into_iter().zip(expected).skip(1) {
+                assert!(
+                    (moment - expected).abs() < 1e-10 * expected.abs().max(1.),
+                    "{moment} != {expected}"
+                );
+            }
+        }
+
+        // resuming from the state of the first item
+        type R = CentralMoments<f64, 6>;
+        let resumed: R = data[1..]
+            .iter()
+            .copied()
+            .fold_with(<R as Reductor<f64>>::new(data[0]));
+        assert_eq!(resumed.count, count);
+        assert_eq!(resumed.moments, moments);
+    }
+
+    /// Two-pass adjusted Fisher-Pearson skewness.
+    fn reference_skewness(data: &[f64]) -> f64 {
+        let n = data.len() as f64;
+        let mean = data.iter().sum::<f64>() / n;
+        let m2 = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let m3 = data.iter().map(|x| (x - mean).powi(3)).sum::<f64>() / n;
+        m3 / m2.powf(1.5) * (n * (n - 1.)).sqrt() / (n - 2.)
+    }
+
+    #[test]
+    fn test_skewness_reference() {
+        let datasets: [&[f64]; 4] = [
+            &[1., 2., 3., 10.],
+            &[-7.5, -3., -2.25, -1., 0., 0.5, 0.75],
+            &[-100., 2., 3., 3.5, 4., 4., 5.],
+            &[1e6 + 1., 1e6 + 2., 1e6 + 4., 1e6 + 8., 1e6 + 16.],
+        ];
+
+        for data in datasets {
+            let expected = reference_skewness(data);
+
+            let Skewness::<f64>(skewness) =
+                data.iter().copied().reduce_with::<Option<_>>().unwrap();
+            assert!(
+                (skewness - expected).abs() < 1e-9,
+                "{skewness} != {expected}"
+            );
+
+            let Skewness::<f64>(merged) = par_reduce_slice::<Option<_>, _>(data, 2, 0).unwrap();
+            assert!((merged - expected).abs() < 1e-9, "{merged} != {expected}");
+
+            let Skewness::<f32>(skewness) = data
+                .iter()
+                .map(|&x| x as f32)
+                .reduce_with::<Option<_>>()
+                .unwrap();
+            // the large offset of the last dataset leaves `f32` with little precision to spare
+            assert!((f64::from(skewness) - expected).abs() < 1e-2 * expected.abs().max(1.));
+        }
+    }
+
+    #[test]
+    fn test_skewness_symmetric() {
+        let Skewness::<f64>(skewness) = [-3, -1, 0, 1, 3]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(skewness, 0.);
+    }
+
+    #[test]
+    fn test_skewness_degenerate() {
+        assert_eq!(
+            None::<Skewness<f64>>,
+            std::iter::empty::<f64>().reduce_with()
+        );
+
+        for data in [&[4.][..], &[4., 5.], &[2., 2., 2., 2.]] {
+            let Skewness::<f64>(skewness) =
+                data.iter().copied().reduce_with::<Option<_>>().unwrap();
+            assert!(skewness.is_nan());
+        }
+    }
+
+    /// Two-pass population excess kurtosis.
+    fn reference_kurtosis(data: &[f64]) -> f64 {
+        let n = data.len() as f64;
+        let mean = data.iter().sum::<f64>() / n;
+        let m2 = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let m4 = data.iter().map(|x| (x - mean).powi(4)).sum::<f64>() / n;
+        m4 / (m2 * m2) - 3.
+    }
+
+    #[test]
+    fn test_kurtosis_reference() {
+        let datasets: [&[f64]; 3] = [
+            &[-7.5, -3., -2.25, -1., 0., 0.5, 0.75],
+            &[-100., 2., 3., 3.5, 4., 4., 5.],
+            &[1e8 + 1., 1e8 + 2., 1e8 + 4., 1e8 + 8., 1e8 + 16.],
+        ];
+
+        // the large offset of the last dataset costs both implementations some precision
+        for data in datasets {
+            let expected = reference_kurtosis(data);
+
+            let Reductors((Skewness::<f64>(skewness), Kurtosis::<f64>(kurtosis))) =
+                data.iter().copied().reduce_with::<Option<_>>().unwrap();
+            assert!(
+                (kurtosis - expected).abs() < 1e-8,
+                "{kurtosis} != {expected}"
+            );
+            assert!((skewness - reference_skewness(data)).abs() < 1e-8);
+
+            for threads in [2, 3] {
+                let Kurtosis::<f64>(merged) =
+                    par_reduce_slice::<Option<_>, _>(data, threads, 0).unwrap();
+                assert!((merged - expected).abs() < 1e-8, "{merged} != {expected}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_kurtosis_distributions() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        // the sum of 12 uniform variables is nearly normal, with an excess kurtosis of -0.1
+        let normalish: Vec<f64> = (0..100_000)
+            .map(|_| (0..12).map(|_| rng.random::<f64>()).sum())
+            .collect();
+        let Kurtosis::<f64>(kurtosis) = normalish
+            .iter()
+            .copied()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert!(kurtosis.abs() < 0.15, "{kurtosis}");
+
+        // the Laplace distribution has an excess kurtosis of 3
+        let laplace: Vec<f64> = (0..100_000)
+            .map(|_| {
+                let magnitude = -(1. - rng.random::<f64>()).ln();
+                if rng.random() {
+                    magnitude
+                } else {
+                    -magnitude
+                }
+            })
+            .collect();
+        let Kurtosis::<f64>(kurtosis) = laplace.iter().copied().reduce_with::<Option<_>>().unwrap();
+        assert!((kurtosis - 3.).abs() < 0.5, "{kurtosis}");
+
+        let Kurtosis::<f32>(merged) = par_reduce_slice::<Option<_>, _>(
+            &laplace.iter().map(|&x| x as f32).collect::<Vec<_>>(),
+            4,
+            0,
+        )
+        .unwrap();
+        assert!((f64::from(merged) - kurtosis).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_kurtosis_degenerate() {
+        for data in [&[4.][..], &[-2.5, -2.5, -2.5]] {
+            let Kurtosis::<f64>(kurtosis) =
+                data.iter().copied().reduce_with::<Option<_>>().unwrap();
+            assert!(kurtosis.is_nan());
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::cmp::Ordering;
+
+use crate::Reductor;
+
+/// Reductor that counts how many times the direction of change between consecutive items
+/// yielded by an iterator flips (from increasing to decreasing, or vice versa), a measure of
+/// oscillation.
+///
+/// Plateaus (consecutive equal items) neither change nor reset the direction, so
+/// `[1, 2, 2, 3]` has no breaks, while `[1, 2, 2, 1]` has one. Items that can't be compared
+/// with the previous item (e.g. NaN) are skipped. Iterators that yield fewer than three
+/// items have no breaks.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MonotonicBreaks, Reduce};
+///
+/// let readings = [1.0, 1.4, 1.2, 1.2, 1.5, 1.9, 1.7];
+///
+/// let MonotonicBreaks(breaks) = readings.into_iter().reduce_with();
+/// assert_eq!(breaks, 3);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MonotonicBreaks(pub usize);
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    prev: Option<T>,
+    direction: Option<Ordering>,
+    breaks: usize,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            prev: None,
+            direction: None,
+            breaks: 0,
+        }
+    }
+}
+
+impl<T> Reductor<T> for MonotonicBreaks
+where
+    T: PartialOrd,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        let Some(prev) = &state.prev else {
+            state.prev = Some(item);
+            return state;
+        };
+
+        match item.partial_cmp(prev) {
+            None => return state,
+            Some(Ordering::Equal) => {}
+            Some(direction) => {
+                if state.direction.is_some_and(|prev| prev != direction) {
+                    state.breaks += 1;
+                }
+                state.direction = Some(direction);
+            }
+        }
+
+        state.prev = Some(item);
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state.breaks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_monotonic_breaks_zig_zag() {
+        let MonotonicBreaks(breaks) = [0, 5, 1, 6, 2, 7, 3].into_iter().reduce_with();
+        assert_eq!(breaks, 5);
+
+        let MonotonicBreaks(breaks) = (0..100).reduce_with();
+        assert_eq!(breaks, 0);
+    }
+
+    #[test]
+    fn test_monotonic_breaks_plateaus() {
+        let MonotonicBreaks(breaks) = [1, 2, 2, 3].into_iter().reduce_with();
+        assert_eq!(breaks, 0);
+
+        let MonotonicBreaks(breaks) = [1, 2, 2, 1, 1, 1, 4].into_iter().reduce_with();
+        assert_eq!(breaks, 2);
+
+        let MonotonicBreaks(breaks) = [3, 3, 3, 1, 1, 2].into_iter().reduce_with();
+        assert_eq!(breaks, 1);
+
+        let MonotonicBreaks(breaks) = [1., f64::NAN, 2., 1.].into_iter().reduce_with();
+        assert_eq!(breaks, 1);
+    }
+
+    #[test]
+    fn test_monotonic_breaks_short() {
+        let MonotonicBreaks(breaks) = std::iter::empty::<u8>().reduce_with();
+        assert_eq!(breaks, 0);
+
+        let MonotonicBreaks(breaks) = [2, 1].into_iter().reduce_with();
+        assert_eq!(breaks, 0);
+    }
+}
@@ -0,0 +1,140 @@
+use std::marker::PhantomData;
+
+use rand::Rng;
+
+use crate::Reductor;
+
+/// Reductor that approximately counts the number of items yielded by an iterator, using a
+/// [Morris counter], which only stores a single byte (and a random number generator).
+///
+/// Instead of the count `n`, the counter stores a register of roughly `log2(n)`, which is
+/// incremented with probability `2^-register` for every item, and the count is estimated as
+/// `2^register - 1`. The estimate is unbiased, but its standard deviation is roughly `n / √2`,
+/// so single estimates are only accurate to within an order of magnitude, while averaging
+/// many independent counters gives an accurate estimate.
+///
+/// The random number generator must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with), so that counting can be made deterministic by using
+/// a seeded generator.
+///
+/// Requires the `rand` feature.
+///
+/// [Morris counter]: https://en.wikipedia.org/wiki/Approximate_counting_algorithm
+///
+/// # Panics
+/// Panics if reduced without an RNG (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use rand::{rngs::SmallRng, SeedableRng};
+/// use reductor::{MorrisCount, Reduce};
+///
+/// let MorrisCount { estimate, register, .. } = (0..10_000).fold_with(SmallRng::seed_from_u64(7));
+///
+/// assert!(register < 20);
+/// assert_eq!(estimate, 2f64.powi(register.into()) - 1.);
+/// assert!(estimate > 1_000. && estimate < 100_000.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MorrisCount<R> {
+    /// Unbiased estimate of the number of items yielded by the iterator.
+    pub estimate: f64,
+    /// The raw register of the counter, roughly `log2` of the count.
+    pub register: u8,
+    rng: PhantomData<R>,
+}
+
+#[derive(Debug, Clone)]
+pub struct State<R> {
+    rng: R,
+    register: u8,
+}
+
+impl<R> From<R> for State<R> {
+    fn from(rng: R) -> Self {
+        Self { rng, register: 0 }
+    }
+}
+
+impl<A, R> Reductor<A> for MorrisCount<R>
+where
+    R: Rng,
+{
+    type State = State<R>;
+
+    fn new(_: A) -> Self::State {
+        panic!("`MorrisCount` must be used with `fold_with`, providing a random number generator")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, _: A) -> Self::State {
+        // Incrementing with probability `2^-register` means that the lowest `register` bits of
+        // a random number are all zero. Past 64 bits, the probability is negligible anyway.
+        if let Some(mask) = 1u64.checked_shl(state.register.into()) {
+            if state.rng.next_u64() & (mask - 1) == 0 {
+                state.register += 1;
+            }
+        }
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            estimate: 2f64.powi(state.register.into()) - 1.,
+            register: state.register,
+            rng: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_morris_count_unbiased() {
+        for count in [10, 100, 1_000, 10_000, 100_000, 1_000_000] {
+            // Keep the total amount of work roughly constant.
+            let trials = (10_000_000 / count).min(2_000);
+
+            let mean = (0..trials)
+                .map(|seed| {
+                    let MorrisCount { estimate, .. } =
+                        (0..count).fold_with(SmallRng::seed_from_u64(seed as u64));
+                    estimate
+                })
+                .sum::<f64>()
+                / trials as f64;
+
+            // The relative standard deviation of a single estimate is at most `1 / √2`,
+            // so allow four standard deviations of the mean of all trials.
+            let tolerance = 4. * std::f64::consts::FRAC_1_SQRT_2 / (trials as f64).sqrt();
+            let relative_error = (mean / count as f64 - 1.).abs();
+            assert!(
+                relative_error < tolerance,
+                "count {count}: mean estimate {mean}, relative error {relative_error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_morris_count_register() {
+        let MorrisCount {
+            estimate, register, ..
+        } = std::iter::empty::<()>().fold_with(SmallRng::seed_from_u64(0));
+        assert_eq!((estimate, register), (0., 0));
+
+        let MorrisCount {
+            estimate, register, ..
+        } = std::iter::once(()).fold_with(SmallRng::seed_from_u64(0));
+        assert_eq!((estimate, register), (1., 1));
+
+        let MorrisCount { register, .. } = (0..1_000_000).fold_with(SmallRng::seed_from_u64(0));
+        assert!((15..=25).contains(&register), "register {register}");
+    }
+}
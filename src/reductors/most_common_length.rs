@@ -0,0 +1,188 @@
+use std::{collections::HashMap, marker::PhantomData};
+
+use crate::Reductor;
+
+/// Length extractor of [`MostCommonLength`] that takes the [`len`](ExactSizeIterator::len) of
+/// items' iterators, e.g. of [`Vec`]s, slices or arrays.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByLen;
+
+/// Reductor that finds the most common length of the collections yielded by an iterator,
+/// along with the number of collections of that length.
+///
+/// By default, the length of items whose iterators are [`ExactSizeIterator`]s (e.g. [`Vec`]s,
+/// slices or arrays) is taken from [`ExactSizeIterator::len`], without iterating over them.
+/// For other items (e.g. strings), a length extractor (`L`) of type `FnMut(&A) -> usize`
+/// can be provided as the initial state, by calling [`fold_with`](crate::Reduce::fold_with).
+///
+/// If several lengths are tied for the highest count, the one that reached that count first
+/// wins, like the mode of [`ModeAndFrequencies`](crate::ModeAndFrequencies).
+/// The length is `None` if the iterator yields no items.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MostCommonLength, Reduce};
+///
+/// let records = [vec![1, 2, 3], vec![4, 5], vec![6, 7, 8], vec![]];
+///
+/// let MostCommonLength { length, count, .. } = records.iter().reduce_with::<MostCommonLength>();
+/// assert_eq!((length, count), (Some(3), 2));
+///
+/// let MostCommonLength { length, count, .. } =
+///     ["GET", "PUT", "POST"].into_iter().fold_with(|s: &&str| s.len());
+/// assert_eq!((length, count), (Some(3), 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MostCommonLength<L = ByLen> {
+    /// Most common length.
+    pub length: Option<usize>,
+    /// Number of items of the most common length.
+    pub count: usize,
+    length_of: PhantomData<L>,
+}
+
+impl<L> Default for MostCommonLength<L> {
+    fn default() -> Self {
+        Self {
+            length: None,
+            count: 0,
+            length_of: PhantomData,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State<L> {
+    length_of: L,
+    frequencies: HashMap<usize, usize>,
+    leader: Option<(usize, usize)>,
+}
+
+impl<L> From<L> for State<L> {
+    fn from(length_of: L) -> Self {
+        Self {
+            length_of,
+            frequencies: HashMap::new(),
+            leader: None,
+        }
+    }
+}
+
+impl Default for State<ByLen> {
+    fn default() -> Self {
+        Self::from(ByLen)
+    }
+}
+
+impl<L> State<L> {
+    #[inline]
+    fn count(mut self, length: usize) -> Self {
+        let count = self.frequencies.entry(length).or_default();
+        *count += 1;
+
+        match &mut self.leader {
+            Some((_, leader_count)) if *leader_count >= *count => {}
+            leader => *leader = Some((length, *count)),
+        }
+        self
+    }
+
+    #[inline]
+    fn into_result(self) -> MostCommonLength<L> {
+        let (length, count) = self.leader.unzip();
+        MostCommonLength {
+            length,
+            count: count.unwrap_or_default(),
+            length_of: PhantomData,
+        }
+    }
+}
+
+impl<A> Reductor<A> for MostCommonLength<ByLen>
+where
+    A: IntoIterator,
+    A::IntoIter: ExactSizeIterator,
+{
+    type State = State<ByLen>;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(state: Self::State, item: A) -> Self::State {
+        state.count(item.into_iter().len())
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        state.into_result()
+    }
+}
+
+impl<A, L> Reductor<A> for MostCommonLength<L>
+where
+    L: FnMut(&A) -> usize,
+{
+    type State = State<L>;
+
+    fn new(_: A) -> Self::State {
+        panic!("`MostCommonLength` must be used with `fold_with`, providing a length extractor")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: A) -> Self::State {
+        let length = (state.length_of)(&item);
+        state.count(length)
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        state.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_most_common_length() {
+        let records: Vec<Vec<u8>> = [4, 2, 4, 7, 2, 2, 4, 0]
+            .into_iter()
+            .map(|len| vec![0; len])
+            .collect();
+
+        let MostCommonLength { length, count, .. } =
+            records.iter().reduce_with::<MostCommonLength>();
+        // `2` and `4` both occur three times, but `2` got there first
+        assert_eq!((length, count), (Some(2), 3));
+
+        let MostCommonLength { length, count, .. } =
+            [[0u8; 3]; 5].into_iter().reduce_with::<MostCommonLength>();
+        assert_eq!((length, count), (Some(3), 5));
+    }
+
+    #[test]
+    fn test_most_common_length_extractor() {
+        let MostCommonLength { length, count, .. } = "a bb cc d ee"
+            .split(' ')
+            .fold_with(|word: &&str| word.len());
+        assert_eq!((length, count), (Some(2), 3));
+
+        let MostCommonLength { length, count, .. } = ["日本", "ab", "xyz"]
+            .into_iter()
+            .fold_with(|s: &&str| s.chars().count());
+        assert_eq!((length, count), (Some(2), 2));
+    }
+
+    #[test]
+    fn test_most_common_length_empty() {
+        let result = std::iter::empty::<Vec<u8>>().reduce_with::<MostCommonLength>();
+        assert_eq!(result, MostCommonLength::default());
+        assert_eq!((result.length, result.count), (None, 0));
+    }
+}
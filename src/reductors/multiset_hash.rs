@@ -0,0 +1,122 @@
+use std::hash::{Hash, Hasher};
+
+use super::fnv::{mix, Fnv1a};
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes an order-insensitive hash of the multiset of items yielded by an
+/// iterator, i.e. two iterators yielding the same items the same number of times (in any order)
+/// have equal hashes.
+///
+/// Each item is hashed separately (using its [`Hash`] implementation) with the 64-bit [FNV-1a]
+/// hash, whose output is then mixed with the [SplitMix64] finalizer, and the per-item hashes are
+/// combined with wrapping addition, which is commutative, but still sensitive to multiplicity.
+/// Like [`SumAndFingerprint`](crate::SumAndFingerprint)'s fingerprint, the hash is stable across
+/// runs, but not across platforms with different endianness. It also differs between 32-bit and
+/// 64-bit targets, since [`Hash`] implementations feed lengths (e.g. of strings and slices) to the
+/// hasher as `usize`s, and the standard library doesn't guarantee that its `Hash` implementations
+/// feed the same data to the hasher across Rust versions, so hashes shouldn't be persisted.
+///
+/// This is not a cryptographic hash: equal hashes strongly suggest, but don't prove, that the
+/// multisets are equal, and it's easy to deliberately construct different multisets with equal
+/// hashes. Also note that since the combination is additive, any item repeated `2^64` times
+/// cancels out.
+///
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MultisetHash, Reduce};
+///
+/// let MultisetHash(a) = ["x", "y", "y"].into_iter().reduce_with();
+/// let MultisetHash(b) = ["y", "x", "y"].into_iter().reduce_with();
+/// let MultisetHash(c) = ["x", "x", "y"].into_iter().reduce_with();
+///
+/// assert_eq!(a, b);
+/// assert_ne!(a, c);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MultisetHash(pub u64);
+
+impl<A> Reductor<A> for MultisetHash
+where
+    A: Hash,
+{
+    type State = u64;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        let mut hasher = Fnv1a::default();
+        item.hash(&mut hasher);
+        mix(hasher.finish())
+    }
+
+    #[inline]
+    fn reduce(state: Self::State, item: A) -> Self::State {
+        state.wrapping_add(<Self as Reductor<A>>::new(item))
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state)
+    }
+}
+
+impl<A> MergeReductor<A> for MultisetHash
+where
+    A: Hash,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        left.wrapping_add(right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_multiset_hash_permutations() {
+        let data = [17u64, 4, 99, 4, 250];
+        let MultisetHash(original) = data.iter().reduce_with();
+
+        let mut permuted = data;
+        for i in 0..data.len() {
+            permuted.rotate_left(1);
+            permuted.swap(0, i);
+            let MultisetHash(hash) = permuted.iter().reduce_with();
+            assert_eq!(hash, original);
+        }
+
+        let MultisetHash(merged) = crate::parallel::par_reduce_slice(&data, 2, 0);
+        assert_eq!(merged, original);
+    }
+
+    #[test]
+    fn test_multiset_hash_changes() {
+        let data = [17u64, 4, 99, 4, 250];
+        let MultisetHash(original) = data.iter().reduce_with();
+
+        let mut changed = data;
+        changed[2] += 1;
+        let MultisetHash(hash) = changed.iter().reduce_with();
+        assert_ne!(hash, original);
+
+        // same distinct items, different multiplicities
+        let MultisetHash(hash) = [17u64, 4, 99, 99, 250].iter().reduce_with();
+        assert_ne!(hash, original);
+
+        let MultisetHash(hash) = data[..4].iter().reduce_with();
+        assert_ne!(hash, original);
+    }
+
+    #[test]
+    fn test_multiset_hash_empty() {
+        let MultisetHash(hash) = std::iter::empty::<&str>().reduce_with();
+        assert_eq!(hash, 0);
+        assert_eq!(MultisetHash::default(), MultisetHash(0));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that counts the total number of set bits (see [`u32::count_ones`]) in the
+/// integers yielded by an iterator.
+///
+/// Items can be any of the unsigned integer types (`u8`, `u16`, `u32`, `u64` and `u128`),
+/// or references to them.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, PopcountSum, Count};
+///
+/// let data = [0b1011u8, 0xff, 0x00, 0b1];
+///
+/// let (PopcountSum(ones), Count(count)) = data.iter().map(|x| (x, ())).reduce_with();
+///
+/// assert_eq!(ones, 3 + 8 + 0 + 1);
+/// assert_eq!(ones as f64 / (count * 8) as f64, 0.375);
+/// ```
+#[repr(transparent)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PopcountSum(pub u64);
+
+macro_rules! impl_popcount_sum {
+    ($($int:ty),+) => {
+        $(
+            impl Reductor<$int> for PopcountSum {
+                type State = u64;
+
+                #[inline]
+                fn new(item: $int) -> Self::State {
+                    item.count_ones().into()
+                }
+
+                #[inline]
+                fn reduce(state: Self::State, item: $int) -> Self::State {
+                    state + <Self as Reductor<$int>>::new(item)
+                }
+
+                #[inline]
+                fn into_result(state: Self::State) -> Self {
+                    Self(state)
+                }
+            }
+
+            impl Reductor<&$int> for PopcountSum {
+                type State = u64;
+
+                #[inline]
+                fn new(item: &$int) -> Self::State {
+                    <Self as Reductor<$int>>::new(*item)
+                }
+
+                #[inline]
+                fn reduce(state: Self::State, item: &$int) -> Self::State {
+                    <Self as Reductor<$int>>::reduce(state, *item)
+                }
+
+                #[inline]
+                fn into_result(state: Self::State) -> Self {
+                    Self(state)
+                }
+            }
+
+            impl MergeReductor<$int> for PopcountSum {
+                #[inline]
+                fn merge(left: Self::State, right: Self::State) -> Self::State {
+                    left + right
+                }
+            }
+
+            impl MergeReductor<&$int> for PopcountSum {
+                #[inline]
+                fn merge(left: Self::State, right: Self::State) -> Self::State {
+                    left + right
+                }
+            }
+        )+
+    };
+}
+
+impl_popcount_sum!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_popcount_sum() {
+        macro_rules! test {
+            ($int:ty) => {
+                let PopcountSum(ones) = [<$int>::MAX, 0, 0b101].into_iter().reduce_with();
+                assert_eq!(ones, u64::from(<$int>::BITS) + 2);
+            };
+        }
+
+        test!(u8);
+        test!(u16);
+        test!(u32);
+        test!(u64);
+        test!(u128);
+    }
+
+    #[test]
+    fn test_popcount_sum_borrowed() {
+        let data: Vec<u32> = (0..1000).collect();
+
+        let PopcountSum(ones) = data.iter().reduce_with();
+        assert_eq!(ones, data.iter().map(|x| u64::from(x.count_ones())).sum());
+
+        let PopcountSum(ones) = std::iter::empty::<&u16>().reduce_with();
+        assert_eq!(ones, 0);
+    }
+}
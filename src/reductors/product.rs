@@ -1,6 +1,6 @@
 use std::iter::{self, empty, once};
 
-use crate::Reductor;
+use crate::{MergeReductor, Reductor};
 
 /// Reductor that multiplies items yielded by an iterator by one another (similarly to [`Iterator::product`]).
 #[repr(transparent)]
@@ -54,6 +54,15 @@ where
     }
 }
 
+impl<A, T> MergeReductor<A> for Product<T>
+where
+    T: iter::Product + iter::Product<A>,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        State(once(left.0).chain(once(right.0)).product())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Reduce;
@@ -0,0 +1,128 @@
+use std::marker::PhantomData;
+
+use crate::Reductor;
+
+/// Reductor that counts the items yielded by an iterator that satisfy a predicate,
+/// along with the total number of items, in a single pass.
+///
+/// The predicate is called exactly once per item. Since it must be stored in the reductor's
+/// state, this reductor can only be used with [`fold_with`](crate::Reduce::fold_with), by
+/// passing the predicate as the initial state.
+///
+/// # Panics
+/// Panics if reduced without a predicate (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{MinMax, Proportion, Reduce};
+///
+/// let log = "\
+///     GET /index.html 200 12ms
+///     GET /missing 404 3ms
+///     POST /login 500 250ms
+///     GET /index.html 200 9ms";
+///
+/// let (error_rate, latency): (Proportion<_>, Option<MinMax<u32>>) = log
+///     .lines()
+///     .map(|line| {
+///         let fields: Vec<&str> = line.split_whitespace().collect();
+///         let status: u16 = fields[2].parse().unwrap();
+///         let latency_ms = fields[3].trim_end_matches("ms").parse().unwrap();
+///         (status, latency_ms)
+///     })
+///     .fold_with(((|&status: &u16| status >= 500).into(), None));
+///
+/// assert_eq!((error_rate.matched, error_rate.total), (1, 4));
+/// assert_eq!(error_rate.ratio(), 0.25);
+/// assert_eq!(latency, Some(MinMax { min: 3, max: 250 }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Proportion<P> {
+    /// Number of items that satisfied the predicate.
+    pub matched: usize,
+    /// Total number of items.
+    pub total: usize,
+    predicate: PhantomData<P>,
+}
+
+impl<P> Proportion<P> {
+    /// Fraction of items that satisfied the predicate.
+    ///
+    /// If the iterator yielded no items, this is NaN.
+    pub fn ratio(&self) -> f64 {
+        self.matched as f64 / self.total as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<P> {
+    predicate: P,
+    matched: usize,
+    total: usize,
+}
+
+impl<P> From<P> for State<P> {
+    fn from(predicate: P) -> Self {
+        Self {
+            predicate,
+            matched: 0,
+            total: 0,
+        }
+    }
+}
+
+impl<A, P> Reductor<A> for Proportion<P>
+where
+    P: FnMut(&A) -> bool,
+{
+    type State = State<P>;
+
+    fn new(_: A) -> Self::State {
+        panic!("`Proportion` must be used with `fold_with`, providing a predicate")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: A) -> Self::State {
+        state.matched += usize::from((state.predicate)(&item));
+        state.total += 1;
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            matched: state.matched,
+            total: state.total,
+            predicate: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_proportion() {
+        let calls = Cell::new(0);
+        let proportion: Proportion<_> = (1..=10).fold_with(|&x: &i32| {
+            calls.set(calls.get() + 1);
+            x % 4 == 0
+        });
+
+        assert_eq!((proportion.matched, proportion.total), (2, 10));
+        assert_eq!(proportion.ratio(), 0.2);
+        assert_eq!(calls.get(), 10);
+    }
+
+    #[test]
+    fn test_proportion_empty() {
+        let proportion: Proportion<_> = std::iter::empty::<i32>().fold_with(|_: &i32| true);
+        assert_eq!((proportion.matched, proportion.total), (0, 0));
+        assert!(proportion.ratio().is_nan());
+    }
+}
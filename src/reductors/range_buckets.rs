@@ -0,0 +1,169 @@
+use std::marker::PhantomData;
+
+use crate::Reductor;
+
+/// Reductor that counts the items yielded by an iterator into `N` consecutive ranges,
+/// delimited by `N + 1` fixed boundaries.
+///
+/// The boundaries must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with) with a `(lower, [F; N])` tuple of the lower boundary
+/// of the first range, followed by the upper boundary of each range, in ascending order.
+/// Every range includes its lower boundary and excludes its upper boundary, so the `i`th range
+/// is `edges[i]..edges[i + 1]`. Items below the lower boundary are counted as underflow,
+/// and items at or above the last boundary are counted as overflow. Items that can't be
+/// compared with the boundaries (e.g. NaN) are ignored.
+///
+/// Unlike [`ExpHistogram`](crate::ExpHistogram), the boundaries can be arbitrary, and the counts
+/// are a fixed-size array, so the ranges can be destructured into named bands.
+///
+/// # Panics
+/// Panics if the boundaries aren't in strictly ascending order.
+///
+/// Panics if reduced without boundaries (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{RangeBuckets, Reduce};
+///
+/// let temperatures = [-3.5, 4., 12.5, 18., 21., 25., 31., 38.5, 45.];
+///
+/// let RangeBuckets {
+///     counts: [cold, mild, warm, hot],
+///     underflow,
+///     overflow,
+///     ..
+/// } = temperatures.into_iter().fold_with((0., [10., 20., 30., 40.]));
+///
+/// assert_eq!((cold, mild, warm, hot), (1, 2, 2, 2));
+/// assert_eq!((underflow, overflow), (1, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeBuckets<F, const N: usize> {
+    /// Number of items in each range.
+    pub counts: [usize; N],
+    /// Number of items below the lower boundary of the first range.
+    pub underflow: usize,
+    /// Number of items at or above the upper boundary of the last range.
+    pub overflow: usize,
+    boundary: PhantomData<F>,
+}
+
+impl<F, const N: usize> RangeBuckets<F, N> {
+    /// Total number of items counted, including underflow and overflow.
+    pub fn total(&self) -> usize {
+        self.underflow + self.counts.iter().sum::<usize>() + self.overflow
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<F, const N: usize> {
+    lower: F,
+    upper: [F; N],
+    counts: [usize; N],
+    underflow: usize,
+    overflow: usize,
+}
+
+impl<F, const N: usize> From<(F, [F; N])> for State<F, N>
+where
+    F: PartialOrd,
+{
+    fn from((lower, upper): (F, [F; N])) -> Self {
+        assert!(
+            upper.first().is_none_or(|first| lower < *first)
+                && upper.windows(2).all(|pair| pair[0] < pair[1]),
+            "boundaries must be in strictly ascending order"
+        );
+
+        Self {
+            lower,
+            upper,
+            counts: [0; N],
+            underflow: 0,
+            overflow: 0,
+        }
+    }
+}
+
+impl<F, const N: usize> Reductor<F> for RangeBuckets<F, N>
+where
+    F: PartialOrd,
+{
+    type State = State<F, N>;
+
+    fn new(_: F) -> Self::State {
+        panic!("`RangeBuckets` must be used with `fold_with`, providing the range boundaries")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: F) -> Self::State {
+        if item.partial_cmp(&state.lower).is_none() {
+            return state;
+        }
+
+        if item < state.lower {
+            state.underflow += 1;
+        } else {
+            // Number of upper boundaries that are lower than or equal to `item`.
+            match state.upper.partition_point(|upper| *upper <= item) {
+                n if n == N => state.overflow += 1,
+                n => state.counts[n] += 1,
+            }
+        }
+
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            counts: state.counts,
+            underflow: state.underflow,
+            overflow: state.overflow,
+            boundary: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_range_buckets_inclusivity() {
+        let buckets: RangeBuckets<_, 3> = [-1, 0, 1, 9, 10, 11, 49, 50, 99, 100, 1000]
+            .into_iter()
+            .fold_with((0, [10, 50, 100]));
+
+        assert_eq!(buckets.counts, [3, 3, 2]);
+        assert_eq!((buckets.underflow, buckets.overflow), (1, 2));
+        assert_eq!(buckets.total(), 11);
+    }
+
+    #[test]
+    fn test_range_buckets_ignores_nan() {
+        let buckets: RangeBuckets<_, 2> = [0.5, f64::NAN, 1.5, f64::INFINITY, f64::NEG_INFINITY]
+            .into_iter()
+            .fold_with((0., [1., 2.]));
+
+        assert_eq!(buckets.counts, [1, 1]);
+        assert_eq!((buckets.underflow, buckets.overflow), (1, 1));
+        assert_eq!(buckets.total(), 4);
+    }
+
+    #[test]
+    fn test_range_buckets_no_ranges() {
+        let buckets: RangeBuckets<_, 0> = [1, 2, 3].into_iter().fold_with((2, []));
+
+        assert_eq!(buckets.counts, []);
+        assert_eq!((buckets.underflow, buckets.overflow), (1, 2));
+    }
+
+    #[test]
+    #[should_panic = "boundaries must be in strictly ascending order"]
+    fn test_range_buckets_unsorted() {
+        let _: RangeBuckets<_, 3> = [1.].into_iter().fold_with((0., [1., 3., 2.]));
+    }
+}
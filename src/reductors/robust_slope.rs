@@ -0,0 +1,178 @@
+use crate::Reductor;
+
+/// Reductor that estimates the slope of the trend line of `(x, y)` pairs yielded by an iterator,
+/// using the [Theil–Sen estimator] (the median of the slopes between all pairs of points).
+///
+/// Unlike a least-squares fit, this estimate is robust to outliers: up to ~29% of the points
+/// can be arbitrarily corrupted without affecting it significantly.
+///
+/// To bound memory usage, at most `capacity` points are retained. Once the reservoir is full,
+/// every other retained point is discarded, and from then on only every other point yielded by
+/// the iterator is considered (and so on, doubling the stride each time the reservoir fills up).
+/// This sampling is deterministic, and retains points that are evenly spread over the whole
+/// iterator. Pairs with a NaN coordinate are ignored.
+///
+/// The capacity defaults to `256`, but can be set by calling
+/// [`fold_with`](crate::Reduce::fold_with) with the desired capacity (values lower than two are
+/// treated as two). Reducing uses `O(capacity)` memory, but computing the result materializes the
+/// slopes between all pairs of retained points, so it peaks at `O(capacity²)` memory (about
+/// 32,640 slopes for the default capacity), and takes `O(capacity² log capacity)` time.
+///
+/// The result is `None` if there are no two retained points with distinct `x` coordinates.
+///
+/// [Theil–Sen estimator]: https://en.wikipedia.org/wiki/Theil%E2%80%93Sen_estimator
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, RobustSlope};
+///
+/// let points = [(0., 1.), (1., 3.), (2., 5.), (3., 1000.), (4., 9.)];
+///
+/// let RobustSlope::<f64>(slope) = points.into_iter().reduce_with();
+/// assert_eq!(slope, Some(2.));
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct RobustSlope<F>(pub Option<F>);
+
+#[derive(Debug, Clone)]
+pub struct State<F> {
+    capacity: usize,
+    stride: usize,
+    index: usize,
+    points: Vec<(F, F)>,
+}
+
+impl<F> From<usize> for State<F> {
+    fn from(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(2),
+            stride: 1,
+            index: 0,
+            points: Vec::new(),
+        }
+    }
+}
+
+impl<F> Default for State<F> {
+    fn default() -> Self {
+        Self::from(256)
+    }
+}
+
+macro_rules! impl_robust_slope {
+    ($f:ty) => {
+        impl<X, Y> Reductor<(X, Y)> for RobustSlope<$f>
+        where
+            X: Into<$f>,
+            Y: Into<$f>,
+        {
+            type State = State<$f>;
+
+            #[inline]
+            fn new(item: (X, Y)) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            fn reduce(mut state: Self::State, (x, y): (X, Y)) -> Self::State {
+                let (x, y) = (x.into(), y.into());
+                if x.is_nan() || y.is_nan() {
+                    return state;
+                }
+
+                if state.index % state.stride == 0 && state.points.len() == state.capacity {
+                    let mut keep = false;
+                    state.points.retain(|_| {
+                        keep = !keep;
+                        keep
+                    });
+                    state.stride *= 2;
+                }
+                if state.index % state.stride == 0 {
+                    state.points.push((x, y));
+                }
+                state.index += 1;
+
+                state
+            }
+
+            fn into_result(State { points, .. }: Self::State) -> Self {
+                let mut slopes =
+                    Vec::with_capacity(points.len() * points.len().saturating_sub(1) / 2);
+                for (i, &(x1, y1)) in points.iter().enumerate() {
+                    for &(x2, y2) in &points[i + 1..] {
+                        if x1 != x2 {
+                            slopes.push((y2 - y1) / (x2 - x1));
+                        }
+                    }
+                }
+
+                if slopes.is_empty() {
+                    return Self(None);
+                }
+
+                slopes.sort_unstable_by(<$f>::total_cmp);
+                let mid = slopes.len() / 2;
+                Self(Some(if slopes.len() % 2 == 0 {
+                    (slopes[mid - 1] + slopes[mid]) / 2.
+                } else {
+                    slopes[mid]
+                }))
+            }
+        }
+    };
+}
+
+impl_robust_slope!(f32);
+impl_robust_slope!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_robust_slope_outliers() {
+        let points = (0..100).map(|x| {
+            let x = x as f64;
+            let y = if x as usize % 10 == 3 {
+                -1e6
+            } else {
+                -0.5 * x + 7.
+            };
+            (x, y)
+        });
+
+        let RobustSlope::<f64>(slope) = points.clone().reduce_with();
+        assert_eq!(slope, Some(-0.5));
+
+        let RobustSlope::<f64>(slope) = points.fold_with(16);
+        assert_eq!(slope, Some(-0.5));
+    }
+
+    #[test]
+    fn test_robust_slope_reservoir() {
+        let State { points, stride, .. } = (0..1000)
+            .map(|x| (x as f32, 0.))
+            .fold(State::<f32>::from(10), <RobustSlope<f32>>::reduce);
+
+        assert!(points.len() <= 10);
+        assert_eq!(stride, 128);
+        assert!(points
+            .iter()
+            .map(|&(x, _)| x as usize)
+            .eq((0..1000).step_by(128)));
+    }
+
+    #[test]
+    fn test_robust_slope_degenerate() {
+        let RobustSlope::<f64>(slope) = std::iter::empty::<(f64, f64)>().reduce_with();
+        assert_eq!(slope, None);
+
+        let RobustSlope::<f64>(slope) = [(1., 2.), (1., 5.), (f64::NAN, 0.)]
+            .into_iter()
+            .reduce_with();
+        assert_eq!(slope, None);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that compresses consecutive equal items yielded by an iterator into
+/// `(item, run length)` pairs.
+///
+/// The first item of each run is retained. An iterator that yields no items reduces to an empty
+/// encoding.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, RunLengthEncode};
+///
+/// let states = ["idle", "idle", "busy", "idle", "idle", "idle"];
+///
+/// let RunLengthEncode(runs) = states.into_iter().reduce_with();
+/// assert_eq!(runs, [("idle", 2), ("busy", 1), ("idle", 3)]);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RunLengthEncode<T>(pub Vec<(T, usize)>);
+
+impl<T> Default for RunLengthEncode<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    runs: Vec<(T, usize)>,
+    current: Option<(T, usize)>,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            runs: Vec::new(),
+            current: None,
+        }
+    }
+}
+
+impl<T> Reductor<T> for RunLengthEncode<T>
+where
+    T: PartialEq,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        State {
+            runs: Vec::new(),
+            current: Some((item, 1)),
+        }
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        match &mut state.current {
+            Some((value, len)) if *value == item => *len += 1,
+            current => state.runs.extend(current.replace((item, 1))),
+        }
+        state
+    }
+
+    #[inline]
+    fn into_result(State { mut runs, current }: Self::State) -> Self {
+        runs.extend(current);
+        Self(runs)
+    }
+}
+
+impl<T> MergeReductor<T> for RunLengthEncode<T>
+where
+    T: PartialEq,
+{
+    fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+        let mut right_runs = right.runs.into_iter().chain(right.current);
+        let Some((first, first_len)) = right_runs.next() else {
+            return left;
+        };
+
+        match &mut left.current {
+            Some((value, len)) if *value == first => *len += first_len,
+            current => left.runs.extend(current.replace((first, first_len))),
+        }
+
+        for run in right_runs {
+            left.runs.extend(left.current.replace(run));
+        }
+
+        left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_run_length_encode() {
+        let RunLengthEncode(runs) = ['a', 'a', 'b', 'c', 'c', 'c'].into_iter().reduce_with();
+        assert_eq!(runs, [('a', 2), ('b', 1), ('c', 3)]);
+
+        let RunLengthEncode(runs) = std::iter::empty::<char>().reduce_with();
+        assert_eq!(runs, []);
+    }
+
+    #[test]
+    fn test_run_length_encode_merge() {
+        type R = RunLengthEncode<u8>;
+
+        let data = [1, 1, 2, 2, 2, 3, 1, 1];
+        for split in 0..=data.len() {
+            let (left, right) = data.split_at(split);
+            let fold = |items: &[u8]| items.iter().copied().fold(State::default(), R::reduce);
+
+            assert_eq!(
+                R::into_result(R::merge(fold(left), fold(right))),
+                data.into_iter().reduce_with::<R>(),
+                "split at {split}"
+            );
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    iter::{self, empty, once},
+    ops::Add,
+};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that sums the items yielded by an iterator (like [`Sum`](crate::Sum)), while also
+/// tracking the highest value that the running total ever reached, e.g. the peak balance of an
+/// account given its deposits and withdrawals.
+///
+/// The running total starts at zero, which counts as a value it reached, so `peak` is never
+/// negative: if the running total never rises above zero (including for an empty iterator),
+/// `peak` is zero.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, RunningTotalPeak};
+///
+/// let cash_flow = [500, -120, 300, -900, 150];
+///
+/// let RunningTotalPeak { total, peak } = cash_flow.into_iter().reduce_with::<RunningTotalPeak<i64>>();
+/// assert_eq!(total, -70);
+/// assert_eq!(peak, 680);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RunningTotalPeak<T> {
+    /// Sum of all items yielded by iterator.
+    pub total: T,
+    /// Highest value the running total reached (but no less than zero).
+    pub peak: T,
+}
+
+impl<T> Default for RunningTotalPeak<T>
+where
+    T: iter::Sum,
+{
+    fn default() -> Self {
+        Self {
+            total: empty::<T>().sum(),
+            peak: empty::<T>().sum(),
+        }
+    }
+}
+
+impl<A, T> Reductor<A> for RunningTotalPeak<T>
+where
+    T: iter::Sum + iter::Sum<A> + Add<Output = T> + PartialOrd + Copy,
+{
+    type State = Self;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        Self::reduce(Self::default(), item)
+    }
+
+    #[inline]
+    fn reduce(Self { total, peak }: Self::State, item: A) -> Self::State {
+        let total = total + once(item).sum();
+        Self {
+            total,
+            peak: if total > peak { total } else { peak },
+        }
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        state
+    }
+}
+
+impl<A, T> MergeReductor<A> for RunningTotalPeak<T>
+where
+    T: iter::Sum + iter::Sum<A> + Add<Output = T> + PartialOrd + Copy,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        // The right half's running totals all start from the left half's total.
+        let right_peak = left.total + right.peak;
+        Self {
+            total: left.total + right.total,
+            peak: if right_peak > left.peak {
+                right_peak
+            } else {
+                left.peak
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_running_total_peak_deposits_and_withdrawals() {
+        let transactions = [100, 250, -75, -300, 400, 20, -600, 50];
+
+        let RunningTotalPeak { total, peak } = transactions
+            .into_iter()
+            .reduce_with::<RunningTotalPeak<i32>>();
+        assert_eq!(total, -155);
+        assert_eq!(peak, 395);
+
+        let (left, right) = transactions.split_at(3);
+        let merged = <RunningTotalPeak<i32> as MergeReductor<i32>>::merge(
+            left.iter().copied().reduce_with(),
+            right.iter().copied().reduce_with(),
+        );
+        assert_eq!(merged, RunningTotalPeak { total, peak });
+    }
+
+    #[test]
+    fn test_running_total_peak_never_positive() {
+        let RunningTotalPeak { total, peak } = [-5, -10, 3]
+            .into_iter()
+            .reduce_with::<RunningTotalPeak<i32>>();
+        assert_eq!((total, peak), (-12, 0));
+
+        let empty = std::iter::empty::<f64>().reduce_with::<RunningTotalPeak<f64>>();
+        assert_eq!(empty, RunningTotalPeak::default());
+    }
+}
@@ -0,0 +1,189 @@
+use std::cmp::{Ord, Ordering};
+
+use crate::{MergeReductor, Reductor};
+
+macro_rules! impl_second {
+    ($better:path) => {
+        type State = (Option<T>, Option<T>);
+
+        #[inline]
+        fn new(item: T) -> Self::State {
+            (Some(item), None)
+        }
+
+        #[inline]
+        fn reduce((first, second): Self::State, item: T) -> Self::State {
+            let first = match first {
+                Some(first) => first,
+                None => return Self::new(item),
+            };
+
+            match item.cmp(&first) {
+                $better => (Some(item), Some(first)),
+                Ordering::Equal if DISTINCT => (Some(first), second),
+                _ => match second {
+                    Some(second) if item.cmp(&second) != $better => (Some(first), Some(second)),
+                    _ => (Some(first), Some(item)),
+                },
+            }
+        }
+
+        #[inline]
+        fn into_result((_, second): Self::State) -> Self {
+            Self(second)
+        }
+    };
+}
+
+macro_rules! impl_second_merge {
+    () => {
+        #[inline]
+        fn merge(left: Self::State, (first, second): Self::State) -> Self::State {
+            first
+                .into_iter()
+                .chain(second)
+                .fold(left, <Self as Reductor<T>>::reduce)
+        }
+    };
+}
+
+/// Reductor that retains the second-largest value yielded by an iterator, in a single pass.
+///
+/// The result is `None` if the iterator yielded fewer than two items.
+///
+/// By default, duplicates are counted, i.e. if the largest value was yielded twice,
+/// it is also the second-largest value. Setting the `DISTINCT` parameter to `true`
+/// ignores duplicates of the largest value, so that the result is the largest value
+/// strictly smaller than the maximum (or `None` if there is no such value).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SecondMax};
+///
+/// let iter = [3, 7, 1, 7, 5].into_iter();
+///
+/// let SecondMax(second) = iter.clone().reduce_with::<SecondMax<i32>>();
+/// assert_eq!(second, Some(7));
+///
+/// let SecondMax(second) = iter.reduce_with::<SecondMax<i32, true>>();
+/// assert_eq!(second, Some(5));
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SecondMax<T, const DISTINCT: bool = false>(pub Option<T>);
+
+impl<T, const DISTINCT: bool> Reductor<T> for SecondMax<T, DISTINCT>
+where
+    T: Ord,
+{
+    impl_second!(Ordering::Greater);
+}
+
+impl<T, const DISTINCT: bool> MergeReductor<T> for SecondMax<T, DISTINCT>
+where
+    T: Ord,
+{
+    impl_second_merge!();
+}
+
+/// Reductor that retains the second-smallest value yielded by an iterator, in a single pass.
+///
+/// See [`SecondMax`] for the handling of duplicates, and of iterators yielding fewer than two items.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SecondMin};
+///
+/// let iter = [3, 1, 7, 1, 5].into_iter();
+///
+/// let SecondMin(second) = iter.clone().reduce_with::<SecondMin<i32>>();
+/// assert_eq!(second, Some(1));
+///
+/// let SecondMin(second) = iter.reduce_with::<SecondMin<i32, true>>();
+/// assert_eq!(second, Some(3));
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SecondMin<T, const DISTINCT: bool = false>(pub Option<T>);
+
+impl<T, const DISTINCT: bool> Reductor<T> for SecondMin<T, DISTINCT>
+where
+    T: Ord,
+{
+    impl_second!(Ordering::Less);
+}
+
+impl<T, const DISTINCT: bool> MergeReductor<T> for SecondMin<T, DISTINCT>
+where
+    T: Ord,
+{
+    impl_second_merge!();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    fn sorted(data: &[i32], distinct: bool) -> Vec<i32> {
+        let mut data = data.to_vec();
+        data.sort_unstable();
+        if distinct {
+            data.dedup();
+        }
+        data
+    }
+
+    #[test]
+    fn test_second_against_sort() {
+        let datasets: &[&[i32]] = &[
+            &[5, 3, 9, 1, 9, 2],
+            &[9, 9, 9, 1],
+            &[1, 9, 9, 9],
+            &[4, 4],
+            &[2, 1],
+            &[-3, 8, 0, 8, -3, 7, 1],
+        ];
+
+        for data in datasets {
+            let iter = data.iter().copied();
+
+            let sorted_all = sorted(data, false);
+            let sorted_distinct = sorted(data, true);
+
+            let SecondMax(max) = iter.clone().reduce_with::<SecondMax<i32>>();
+            assert_eq!(max, sorted_all.iter().rev().nth(1).copied());
+            let SecondMax(max) = iter.clone().reduce_with::<SecondMax<i32, true>>();
+            assert_eq!(max, sorted_distinct.iter().rev().nth(1).copied());
+
+            let SecondMin(min) = iter.clone().reduce_with::<SecondMin<i32>>();
+            assert_eq!(min, sorted_all.get(1).copied());
+            let SecondMin(min) = iter.clone().reduce_with::<SecondMin<i32, true>>();
+            assert_eq!(min, sorted_distinct.get(1).copied());
+        }
+    }
+
+    #[test]
+    fn test_second_duplicate_max() {
+        let SecondMax(second) = [1, 9, 3, 9].into_iter().reduce_with::<SecondMax<i32>>();
+        assert_eq!(second, Some(9));
+
+        let SecondMax(second) = [1, 9, 3, 9]
+            .into_iter()
+            .reduce_with::<SecondMax<i32, true>>();
+        assert_eq!(second, Some(3));
+
+        let SecondMax(second) = [9, 9, 9].into_iter().reduce_with::<SecondMax<i32, true>>();
+        assert_eq!(second, None);
+    }
+
+    #[test]
+    fn test_second_too_few() {
+        let SecondMax(second) = [].into_iter().reduce_with::<SecondMax<i32>>();
+        assert_eq!(second, None);
+
+        let SecondMin(second) = [1].into_iter().reduce_with::<SecondMin<i32>>();
+        assert_eq!(second, None);
+    }
+}
@@ -0,0 +1,110 @@
+use std::iter::{self, empty, once};
+
+use crate::Reductor;
+
+/// Reductor that sums the segments of items yielded by an iterator, where segments are delimited
+/// by reset markers.
+///
+/// The iterator must yield [`Option`]s: `Some(item)` adds `item` to the current segment's sum
+/// (like [`Sum`](crate::Sum)), while `None` marks a reset, which ends the current segment and
+/// starts a new one. Similarly to [`str::split`], `n` resets always yield `n + 1` segment sums,
+/// so a reset at the very start or end (or two consecutive resets) yields an empty segment,
+/// whose sum is zero, and an empty iterator yields a single empty segment.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SegmentedSums};
+///
+/// let readings = [Some(3), Some(4), None, Some(10), Some(-2), Some(1)];
+///
+/// let SegmentedSums(sums) = readings.into_iter().reduce_with::<SegmentedSums<i32>>();
+/// assert_eq!(sums, [7, 9]);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SegmentedSums<T>(pub Vec<T>);
+
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    sums: Vec<T>,
+    current: T,
+}
+
+impl<T> Default for State<T>
+where
+    T: iter::Sum,
+{
+    fn default() -> Self {
+        Self {
+            sums: Vec::new(),
+            current: empty::<T>().sum(),
+        }
+    }
+}
+
+impl<A, T> Reductor<Option<A>> for SegmentedSums<T>
+where
+    T: iter::Sum + iter::Sum<A>,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: Option<A>) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(State { mut sums, current }: Self::State, item: Option<A>) -> Self::State {
+        match item {
+            Some(item) => State {
+                sums,
+                current: once(current).chain(once(once(item).sum())).sum(),
+            },
+            None => {
+                sums.push(current);
+                State {
+                    sums,
+                    current: empty::<T>().sum(),
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn into_result(State { mut sums, current }: Self::State) -> Self {
+        sums.push(current);
+        Self(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_segmented_sums() {
+        let SegmentedSums(sums) = [Some(1.5), Some(2.), None, Some(-0.5), Some(4.)]
+            .into_iter()
+            .reduce_with::<SegmentedSums<f64>>();
+        assert_eq!(sums, [3.5, 3.5]);
+
+        let items = [1u32, 2, 0, 0, 5, 0];
+        let SegmentedSums(sums) = items
+            .iter()
+            .map(|x| (*x != 0).then_some(x))
+            .reduce_with::<SegmentedSums<u32>>();
+        assert_eq!(sums, [3, 0, 5, 0]);
+    }
+
+    #[test]
+    fn test_segmented_sums_empty() {
+        let SegmentedSums(sums) =
+            std::iter::empty::<Option<u8>>().reduce_with::<SegmentedSums<u8>>();
+        assert_eq!(sums, [0]);
+
+        let SegmentedSums(sums) = [None::<u8>].into_iter().reduce_with::<SegmentedSums<u8>>();
+        assert_eq!(sums, [0, 0]);
+    }
+}
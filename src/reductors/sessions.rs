@@ -0,0 +1,199 @@
+use std::ops::Sub;
+
+use crate::Reductor;
+
+/// Reductor that splits the ascending timestamps yielded by an iterator into sessions, where
+/// a gap between consecutive timestamps that is larger than a threshold starts a new session.
+///
+/// Timestamps can be any type whose difference (of type `D`) can be compared with the gap
+/// threshold, e.g. integers, floats, or [`Instant`](std::time::Instant)s with a
+/// [`Duration`](std::time::Duration) threshold. The threshold must be provided as the initial
+/// state, by calling [`fold_with`](crate::Reduce::fold_with).
+///
+/// A timestamp that is earlier than the one before it is treated as part of the current session
+/// (without extending it), and is counted in `out_of_order`, so that unsorted input can be detected.
+///
+/// # Panics
+/// Panics if reduced without a gap threshold (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, Sessions};
+///
+/// // seconds since midnight
+/// let clicks = [100_u32, 130, 200, 4000, 4010, 9000];
+///
+/// let sessions: Sessions<u32> = clicks.into_iter().fold_with(30 * 60);
+///
+/// assert_eq!(sessions.sessions, 3);
+/// assert_eq!(sessions.longest, Some(100));
+/// assert_eq!(sessions.mean_events(), Some(2.));
+/// assert_eq!(sessions.out_of_order, 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sessions<D> {
+    /// Number of sessions.
+    pub sessions: usize,
+    /// Number of timestamps yielded by iterator.
+    pub events: usize,
+    /// Duration of the longest session, from its first timestamp to its last,
+    /// or `None` if there were no timestamps.
+    pub longest: Option<D>,
+    /// Number of timestamps that were earlier than the timestamp before them.
+    pub out_of_order: usize,
+}
+
+impl<D> Sessions<D> {
+    /// Mean number of timestamps per session, or `None` if there were no sessions.
+    pub fn mean_events(&self) -> Option<f64> {
+        (self.sessions != 0).then(|| self.events as f64 / self.sessions as f64)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T, D> {
+    gap: D,
+    /// Start of the current session, and the latest timestamp in it.
+    current: Option<(T, T)>,
+    sessions: usize,
+    events: usize,
+    longest: Option<D>,
+    out_of_order: usize,
+}
+
+impl<T, D> From<D> for State<T, D> {
+    fn from(gap: D) -> Self {
+        Self {
+            gap,
+            current: None,
+            sessions: 0,
+            events: 0,
+            longest: None,
+            out_of_order: 0,
+        }
+    }
+}
+
+impl<T, D> State<T, D>
+where
+    T: Copy + Sub<Output = D>,
+    D: PartialOrd,
+{
+    fn close_session(&mut self, (start, latest): (T, T)) {
+        let duration = latest - start;
+        if self
+            .longest
+            .as_ref()
+            .is_none_or(|longest| duration > *longest)
+        {
+            self.longest = Some(duration);
+        }
+    }
+}
+
+impl<T, D> Reductor<T> for Sessions<D>
+where
+    T: Copy + PartialOrd + Sub<Output = D>,
+    D: PartialOrd,
+{
+    type State = State<T, D>;
+
+    fn new(_: T) -> Self::State {
+        panic!("`Sessions` must be used with `fold_with`, providing a gap threshold")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        state.events += 1;
+        match state.current {
+            Some((_, latest)) if item < latest => state.out_of_order += 1,
+            Some((start, latest)) if item - latest <= state.gap => {
+                state.current = Some((start, item));
+            }
+            current => {
+                if let Some(session) = current {
+                    state.close_session(session);
+                }
+                state.sessions += 1;
+                state.current = Some((item, item));
+            }
+        }
+        state
+    }
+
+    #[inline]
+    fn into_result(mut state: Self::State) -> Self {
+        if let Some(session) = state.current {
+            state.close_session(session);
+        }
+        Self {
+            sessions: state.sessions,
+            events: state.events,
+            longest: state.longest,
+            out_of_order: state.out_of_order,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_sessions_single_burst() {
+        let start = Instant::now();
+        let sessions: Sessions<Duration> = (0..50)
+            .map(|i| start + Duration::from_millis(i * 10))
+            .fold_with(Duration::from_secs(1));
+
+        assert_eq!(sessions.sessions, 1);
+        assert_eq!(sessions.events, 50);
+        assert_eq!(sessions.longest, Some(Duration::from_millis(490)));
+        assert_eq!(sessions.mean_events(), Some(50.));
+    }
+
+    #[test]
+    fn test_sessions_evenly_spaced() {
+        let timestamps = (0_i64..10).map(|i| i * 60);
+
+        // gaps equal to the threshold don't split sessions
+        let below: Sessions<i64> = timestamps.clone().fold_with(60);
+        assert_eq!(below.sessions, 1);
+        assert_eq!(below.longest, Some(540));
+
+        let above: Sessions<i64> = timestamps.fold_with(59);
+        assert_eq!(above.sessions, 10);
+        assert_eq!(above.longest, Some(0));
+        assert_eq!(above.mean_events(), Some(1.));
+        assert_eq!(above.out_of_order, 0);
+    }
+
+    #[test]
+    fn test_sessions_out_of_order() {
+        let sessions: Sessions<f64> = [0., 1., 0.5, 2., 10., 9., 11.5].into_iter().fold_with(1.5);
+
+        assert_eq!(sessions.sessions, 2);
+        assert_eq!(sessions.out_of_order, 2);
+        assert_eq!(sessions.longest, Some(2.));
+        assert_eq!(sessions.mean_events(), Some(3.5));
+    }
+
+    #[test]
+    fn test_sessions_empty() {
+        let sessions: Sessions<u64> = std::iter::empty::<u64>().fold_with(10);
+        assert_eq!(
+            sessions,
+            Sessions {
+                sessions: 0,
+                events: 0,
+                longest: None,
+                out_of_order: 0,
+            }
+        );
+        assert_eq!(sessions.mean_events(), None);
+    }
+}
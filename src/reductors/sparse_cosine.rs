@@ -0,0 +1,157 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the [cosine similarity] of two sparse vectors, whose components
+/// are yielded by an iterator as `(Option<a>, Option<b>)` pairs.
+///
+/// Only positions where both components are present (`Some`) contribute to the dot product
+/// *and* to the norms of both vectors, so the similarity is that of the two vectors restricted
+/// to their common support. Positions where either component is missing are skipped entirely,
+/// but the number of co-present positions is reported as `overlap_count`, so that similarities
+/// that are based on very few positions can be discounted.
+///
+/// The cosine is `None` if there are no co-present positions, or if either restricted vector
+/// has a norm of zero.
+///
+/// [cosine similarity]: https://en.wikipedia.org/wiki/Cosine_similarity
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SparseCosine};
+///
+/// let ratings_a = [Some(5.), None, Some(3.), Some(1.), None];
+/// let ratings_b = [Some(4.), Some(2.), None, Some(2.), None];
+///
+/// let SparseCosine::<f64> { cosine, overlap_count } = ratings_a
+///     .into_iter()
+///     .zip(ratings_b)
+///     .reduce_with();
+///
+/// assert_eq!(overlap_count, 2);
+/// assert!((cosine.unwrap() - 22. / (26f64.sqrt() * 20f64.sqrt())).abs() < 1e-12);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SparseCosine<F> {
+    /// Cosine similarity over co-present positions, or `None` if undefined.
+    pub cosine: Option<F>,
+    /// Number of positions where both components were present.
+    pub overlap_count: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct State<F> {
+    dot: F,
+    norm_a: F,
+    norm_b: F,
+    overlap_count: usize,
+}
+
+macro_rules! impl_sparse_cosine {
+    ($f:ty) => {
+        impl<A, B> Reductor<(Option<A>, Option<B>)> for SparseCosine<$f>
+        where
+            A: Into<$f>,
+            B: Into<$f>,
+        {
+            type State = State<$f>;
+
+            #[inline]
+            fn new(item: (Option<A>, Option<B>)) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: (Option<A>, Option<B>)) -> Self::State {
+                let (Some(a), Some(b)) = item else {
+                    return state;
+                };
+                let (a, b): ($f, $f) = (a.into(), b.into());
+
+                State {
+                    dot: a.mul_add(b, state.dot),
+                    norm_a: a.mul_add(a, state.norm_a),
+                    norm_b: b.mul_add(b, state.norm_b),
+                    overlap_count: state.overlap_count + 1,
+                }
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                let norms = (state.norm_a * state.norm_b).sqrt();
+                Self {
+                    cosine: (norms > 0.).then(|| state.dot / norms),
+                    overlap_count: state.overlap_count,
+                }
+            }
+        }
+
+        impl<A, B> MergeReductor<(Option<A>, Option<B>)> for SparseCosine<$f>
+        where
+            A: Into<$f>,
+            B: Into<$f>,
+        {
+            #[inline]
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                State {
+                    dot: left.dot + right.dot,
+                    norm_a: left.norm_a + right.norm_a,
+                    norm_b: left.norm_b + right.norm_b,
+                    overlap_count: left.overlap_count + right.overlap_count,
+                }
+            }
+        }
+    };
+}
+
+impl_sparse_cosine!(f32);
+impl_sparse_cosine!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_sparse_cosine() {
+        // The missing positions would make the vectors orthogonal, if they were treated as zeroes.
+        let pairs = [
+            (Some(1), Some(2)),
+            (Some(9), None),
+            (None, Some(9)),
+            (Some(2), Some(4)),
+            (None, None),
+        ];
+
+        let SparseCosine::<f64> {
+            cosine,
+            overlap_count,
+        } = pairs.into_iter().reduce_with();
+        assert_eq!(overlap_count, 2);
+        assert!((cosine.unwrap() - 1.).abs() < 1e-12);
+
+        let SparseCosine::<f32> { cosine, .. } = [(Some(1.), Some(-1.)), (Some(-2.), Some(2.))]
+            .into_iter()
+            .reduce_with();
+        assert!((cosine.unwrap() + 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sparse_cosine_undefined() {
+        let SparseCosine::<f64> {
+            cosine,
+            overlap_count,
+        } = [(Some(1.), None), (None, Some(1.))]
+            .into_iter()
+            .reduce_with();
+        assert_eq!((cosine, overlap_count), (None, 0));
+
+        let SparseCosine::<f64> {
+            cosine,
+            overlap_count,
+        } = [(Some(0.), Some(1.)), (Some(0.), Some(3.))]
+            .into_iter()
+            .reduce_with();
+        assert_eq!((cosine, overlap_count), (None, 2));
+    }
+}
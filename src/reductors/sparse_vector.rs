@@ -0,0 +1,145 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    iter::{self, empty},
+    ops::Add,
+};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that builds a compressed sparse vector out of the `(index, value)` pairs yielded
+/// by an iterator.
+///
+/// Values yielded for the same index are accumulated by adding them together, so the pairs can
+/// be yielded in any order, and may repeat indices. The result is sorted by index, and only
+/// contains the indices whose accumulated value is nonzero (including indices whose values
+/// canceled each other out).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SparseVector};
+///
+/// let SparseVector(entries) = [(7, 1.5), (2, 3.), (7, 0.5), (4, 0.), (9, 2.), (9, -2.)]
+///     .into_iter()
+///     .reduce_with::<SparseVector<f64>>();
+///
+/// assert_eq!(entries, [(2, 3.), (7, 2.)]);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct SparseVector<F>(pub Vec<(usize, F)>);
+
+impl<F> Default for SparseVector<F> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State<F>(HashMap<usize, F>);
+
+impl<F> Default for State<F> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<F> State<F>
+where
+    F: Add<Output = F> + Copy,
+{
+    #[inline]
+    fn accumulate(&mut self, index: usize, value: F) {
+        match self.0.entry(index) {
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+            Entry::Occupied(entry) => {
+                let sum = entry.into_mut();
+                *sum = *sum + value;
+            }
+        }
+    }
+}
+
+impl<F> Reductor<(usize, F)> for SparseVector<F>
+where
+    F: iter::Sum + Add<Output = F> + PartialEq + Copy,
+{
+    type State = State<F>;
+
+    #[inline]
+    fn new(item: (usize, F)) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, (index, value): (usize, F)) -> Self::State {
+        state.accumulate(index, value);
+        state
+    }
+
+    fn into_result(State(entries): Self::State) -> Self {
+        let zero: F = empty().sum();
+
+        let mut entries: Vec<_> = entries
+            .into_iter()
+            .filter(|&(_, value)| value != zero)
+            .collect();
+        entries.sort_unstable_by_key(|&(index, _)| index);
+        Self(entries)
+    }
+}
+
+impl<F> MergeReductor<(usize, F)> for SparseVector<F>
+where
+    F: iter::Sum + Add<Output = F> + PartialEq + Copy,
+{
+    fn merge(mut left: Self::State, State(right): Self::State) -> Self::State {
+        for (index, value) in right {
+            left.accumulate(index, value);
+        }
+        left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_sparse_vector() {
+        let pairs = [
+            (3, 4),
+            (1000, -1),
+            (0, 2),
+            (3, 1),
+            (42, 0),
+            (1000, 1),
+            (0, 5),
+        ];
+
+        let SparseVector(entries) = pairs.into_iter().reduce_with::<SparseVector<i32>>();
+        assert_eq!(entries, [(0, 7), (3, 5)]);
+
+        // Accumulation doesn't depend on the order of the pairs.
+        let SparseVector(reversed) = pairs.into_iter().rev().reduce_with::<SparseVector<i32>>();
+        assert_eq!(reversed, entries);
+
+        let SparseVector(empty) = std::iter::empty().reduce_with::<SparseVector<f32>>();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_sparse_vector_dense() {
+        let dense = [0., 1.5, 0., 0., -2., 0., 3.];
+
+        let SparseVector(entries) = dense
+            .into_iter()
+            .enumerate()
+            .reduce_with::<SparseVector<f64>>();
+        assert_eq!(entries, [(1, 1.5), (4, -2.), (6, 3.)]);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the sum of squared errors (deviations) of items yielded by an iterator
+/// from a known target value, i.e. `Σ(x - target)²`.
+///
+/// The target defaults to zero (so reducing computes the sum of squares), and can be set by calling
+/// [`fold_with`](crate::Reduce::fold_with) with an [`SseInit`]. If the target is the mean of
+/// the items, the result is the (population) variance of the items multiplied by their count.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SseInit, SumSquaredError};
+///
+/// let measurements = [9.8, 9.7, 10.1, 9.9];
+///
+/// let SumSquaredError::<f64>(sse) = measurements.into_iter().fold_with(SseInit { target: 9.81 });
+/// assert!((sse - 0.1044).abs() < 1e-12);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SumSquaredError<F>(pub F);
+
+/// Initial state for [`SumSquaredError`], setting the target value that errors are measured from.
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SseInit<F> {
+    /// Value that errors are measured from.
+    pub target: F,
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct State<F> {
+    target: F,
+    sse: F,
+}
+
+impl<F> From<SseInit<F>> for State<F>
+where
+    F: Default,
+{
+    fn from(SseInit { target }: SseInit<F>) -> Self {
+        Self {
+            target,
+            sse: F::default(),
+        }
+    }
+}
+
+macro_rules! impl_sse {
+    ($f:ty) => {
+        impl<T> Reductor<T> for SumSquaredError<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = State<$f>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: T) -> Self::State {
+                let error = item.into() - state.target;
+                State {
+                    sse: error.mul_add(error, state.sse),
+                    ..state
+                }
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self(state.sse)
+            }
+        }
+
+        /// Both states are assumed to have the same target.
+        impl<T> MergeReductor<T> for SumSquaredError<$f>
+        where
+            T: Into<$f>,
+        {
+            #[inline]
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                State {
+                    sse: left.sse + right.sse,
+                    ..left
+                }
+            }
+        }
+    };
+}
+
+impl_sse!(f32);
+impl_sse!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mean, Reduce};
+
+    use super::*;
+
+    #[test]
+    fn test_sum_squared_error() {
+        let SumSquaredError::<f64>(sse) = [1, 2, 3].into_iter().reduce_with();
+        assert_eq!(sse, 14.);
+
+        let SumSquaredError::<f64>(sse) = [1, 2, 3].into_iter().fold_with(SseInit { target: 2. });
+        assert_eq!(sse, 2.);
+
+        let SumSquaredError::<f32>(sse) = std::iter::empty::<f32>().reduce_with();
+        assert_eq!(sse, 0.);
+    }
+
+    #[test]
+    fn test_sum_squared_error_around_mean() {
+        let data = [2., 4., 4., 4., 5., 5., 7., 9.];
+
+        let Mean::<f64>(mean) = data.into_iter().reduce_with::<Option<_>>().unwrap();
+        let SumSquaredError::<f64>(sse) = data.into_iter().fold_with(SseInit { target: mean });
+
+        // Population variance of the data is 4.
+        assert_eq!(sse, 4. * data.len() as f64);
+    }
+}
@@ -1,8 +1,83 @@
 /// Wrapper around `T` that pointedly does NOT implement [`Default`], even though `T` might.
+///
+/// [`reduce_with`](crate::Reduce::reduce_with) requires the [`State`](crate::Reductor::State)
+/// to implement [`Default`], so that it has something to start from when the iterator is empty.
+/// Some reductions have no sensible result for an empty iterator (e.g. the minimum of no items),
+/// so wrapping their state in `NonEmptyState` makes `reduce_with` reject them at compile time,
+/// forcing users to wrap the reductor in an [`Option`] instead (whose state is always `Default`).
+///
+/// Reductors that are implemented outside of this crate can use this wrapper for their own
+/// states in the same manner.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{NonEmptyState, Reduce, Reductor};
+///
+/// /// Reductor that retains the first item yielded by an iterator.
+/// struct First<T>(T);
+///
+/// impl<T> Reductor<T> for First<T> {
+///     type State = NonEmptyState<T>;
+///
+///     fn new(item: T) -> Self::State {
+///         NonEmptyState::new(item)
+///     }
+///
+///     fn reduce(state: Self::State, _: T) -> Self::State {
+///         state
+///     }
+///
+///     fn into_result(state: Self::State) -> Self {
+///         Self(state.into_inner())
+///     }
+/// }
+///
+/// let First(first) = [3, 1, 2].into_iter().reduce_with::<Option<_>>().unwrap();
+/// assert_eq!(first, 3);
+/// ```
+///
+/// Trying to reduce without an [`Option`] fails to compile:
+/// ```rust,compile_fail
+/// # use reductor::{NonEmptyState, Reduce, Reductor};
+/// # struct First<T>(T);
+/// # impl<T> Reductor<T> for First<T> {
+/// #     type State = NonEmptyState<T>;
+/// #     fn new(item: T) -> Self::State { NonEmptyState::new(item) }
+/// #     fn reduce(state: Self::State, _: T) -> Self::State { state }
+/// #     fn into_result(state: Self::State) -> Self { Self(state.into_inner()) }
+/// # }
+/// let First(first): First<i32> = [3, 1, 2].into_iter().reduce_with();
+/// ```
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy)]
 pub struct NonEmptyState<T>(pub(super) T);
 
+impl<T> NonEmptyState<T> {
+    /// Wrap a state.
+    #[inline]
+    pub const fn new(state: T) -> Self {
+        Self(state)
+    }
+
+    /// Borrow the wrapped state.
+    #[inline]
+    pub const fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Mutably borrow the wrapped state.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    /// Unwrap the wrapped state.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
 impl<T> From<T> for NonEmptyState<T> {
     fn from(v: T) -> Self {
         Self(v)
@@ -0,0 +1,142 @@
+use std::marker::PhantomData;
+
+use crate::Reductor;
+
+/// Reductor that tracks runs of consecutive items yielded by an iterator that satisfy a predicate.
+///
+/// Since the predicate must be stored in the reductor's state, this reductor can only be used
+/// with [`fold_with`](crate::Reduce::fold_with), by passing the predicate as the initial state.
+///
+/// # Panics
+/// Panics if reduced without a predicate (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, StreakStats};
+///
+/// let latencies_ms = [20, 35, 250, 40, 45, 60, 300, 310, 80];
+///
+/// let StreakStats { longest, current, runs, .. } = latencies_ms
+///     .into_iter()
+///     .fold_with(|&latency: &u32| latency < 100);
+///
+/// assert_eq!(longest, 3);
+/// assert_eq!(current, 1);
+/// assert_eq!(runs, 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StreakStats<P> {
+    /// Length of the longest run of items satisfying the predicate.
+    pub longest: usize,
+    /// Length of the run that was in progress when the iterator was exhausted
+    /// (zero if the last item did not satisfy the predicate).
+    pub current: usize,
+    /// Total number of runs of items satisfying the predicate.
+    pub runs: usize,
+    predicate: PhantomData<P>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<P> {
+    predicate: P,
+    longest: usize,
+    current: usize,
+    runs: usize,
+}
+
+impl<P> From<P> for State<P> {
+    fn from(predicate: P) -> Self {
+        Self {
+            predicate,
+            longest: 0,
+            current: 0,
+            runs: 0,
+        }
+    }
+}
+
+impl<A, P> Reductor<A> for StreakStats<P>
+where
+    P: FnMut(&A) -> bool,
+{
+    type State = State<P>;
+
+    fn new(_: A) -> Self::State {
+        panic!("`StreakStats` must be used with `fold_with`, providing a predicate")
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: A) -> Self::State {
+        if (state.predicate)(&item) {
+            if state.current == 0 {
+                state.runs += 1;
+            }
+            state.current += 1;
+            state.longest = state.longest.max(state.current);
+        } else {
+            state.current = 0;
+        }
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            longest: state.longest,
+            current: state.current,
+            runs: state.runs,
+            predicate: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Count, Reduce, Reductors};
+
+    use super::*;
+
+    fn streaks(data: &[i32]) -> (usize, usize, usize) {
+        let StreakStats {
+            longest,
+            current,
+            runs,
+            ..
+        } = data.iter().fold_with(|&&x: &&i32| x > 0);
+        (longest, current, runs)
+    }
+
+    #[test]
+    fn test_streak_ends_mid_run() {
+        assert_eq!(streaks(&[1, 2, -1, 3, 4, 5, 0, 6, 7]), (3, 2, 3));
+    }
+
+    #[test]
+    fn test_streak_no_matches() {
+        assert_eq!(streaks(&[-1, 0, -5]), (0, 0, 0));
+        assert_eq!(streaks(&[]), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_streak_all_matching() {
+        assert_eq!(streaks(&[1, 2, 3, 4]), (4, 4, 1));
+    }
+
+    #[test]
+    fn test_streak_with_count() {
+        let Reductors((StreakStats { longest, .. }, Count(count))) = [5, 150, 20, 30, 40, 200]
+            .into_iter()
+            .fold_with(((|&x: &u32| x < 100).into(), 0));
+
+        assert_eq!(longest, 3);
+        assert_eq!(count, 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_streak_reduce_with() {
+        let _ = [1, 2]
+            .into_iter()
+            .reduce_with::<Option<StreakStats<fn(&i32) -> bool>>>();
+    }
+}
@@ -0,0 +1,338 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that builds an approximate histogram of values yielded by an iterator, using a
+/// bounded number of bins and without requiring the range of values to be known in advance.
+///
+/// This implements the streaming histogram of [Ben-Haim and Tom-Tov]: each bin is a
+/// `(centroid, count)` pair, and whenever a new value would exceed the bin limit, the two bins
+/// with the closest centroids are merged into one (at their weighted mean). The minimum and
+/// maximum values are tracked exactly, and are used as the outer bounds when interpolating.
+///
+/// The bin limit defaults to `64`, but can be set by calling [`fold_with`](crate::Reduce::fold_with)
+/// with the desired limit (zero is treated as one). Each value takes `O(limit)` time to add.
+/// Non-finite values are ignored.
+///
+/// Estimates are most accurate where values are dense: with the default limit, quantile
+/// estimates of smooth distributions are typically within 1-2% of the true rank.
+///
+/// [Ben-Haim and Tom-Tov]: https://www.jmlr.org/papers/volume11/ben-haim10a/ben-haim10a.pdf
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, StreamingHistogram};
+///
+/// let hist: StreamingHistogram<f64> = (1..=1000).map(f64::from).fold_with(32);
+///
+/// assert_eq!(hist.bins().len(), 32);
+/// assert_eq!(hist.total(), 1000);
+///
+/// let median = hist.quantile(0.5).unwrap();
+/// assert!((median - 500.).abs() < 10.);
+/// assert!((hist.count_below(250.) - 250.).abs() < 10.);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamingHistogram<F> {
+    bins: Vec<(F, u64)>,
+    bounds: Option<(F, F)>,
+    total: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct State<F> {
+    limit: usize,
+    hist: StreamingHistogram<F>,
+}
+
+impl<F> From<usize> for State<F> {
+    fn from(limit: usize) -> Self {
+        Self {
+            limit: limit.max(1),
+            hist: StreamingHistogram {
+                bins: Vec::new(),
+                bounds: None,
+                total: 0,
+            },
+        }
+    }
+}
+
+impl<F> Default for State<F> {
+    fn default() -> Self {
+        Self::from(64)
+    }
+}
+
+impl<F> StreamingHistogram<F> {
+    /// The `(centroid, count)` bins of the histogram, in ascending order of centroids.
+    pub fn bins(&self) -> &[(F, u64)] {
+        &self.bins
+    }
+
+    /// Total number of values in the histogram.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+macro_rules! impl_streaming_histogram {
+    ($f:ty) => {
+        impl StreamingHistogram<$f> {
+            /// Exact minimum and maximum values in the histogram, or `None` if it is empty.
+            pub fn bounds(&self) -> Option<($f, $f)> {
+                self.bounds
+            }
+
+            /// Estimated number of values that are less than or equal to `x`.
+            pub fn count_below(&self, x: $f) -> $f {
+                let points = self.points();
+                let Some(i) = points.iter().rposition(|&(p, _)| p <= x) else {
+                    return 0.;
+                };
+                if i == points.len() - 1 {
+                    return self.total as $f;
+                }
+
+                let ((p_i, m_i), (p_j, m_j)) = (points[i], points[i + 1]);
+                let below: $f = points[..i].iter().map(|&(_, m)| m).sum();
+                let z = (x - p_i) / (p_j - p_i);
+                below + m_i / 2. + m_i * z + (m_j - m_i) * z * z / 2.
+            }
+
+            /// Estimated `q`-quantile of the values in the histogram (`q` is clamped to `[0, 1]`),
+            /// or `None` if the histogram is empty.
+            pub fn quantile(&self, q: $f) -> Option<$f> {
+                let (min, max) = self.bounds?;
+                let target = q.clamp(0., 1.) * self.total as $f;
+
+                let mut cumulative = 0.;
+                for pair in self.points().windows(2) {
+                    let [(p_i, m_i), (p_j, m_j)] = *pair else {
+                        unreachable!()
+                    };
+                    let mass = (m_i + m_j) / 2.;
+                    if cumulative + mass >= target && mass > 0. {
+                        if p_j == p_i {
+                            return Some(p_i);
+                        }
+
+                        // Solve `m_i * z + (m_j - m_i) * z² / 2 = d` for `z`, the same
+                        // trapezoid that `count_below` integrates.
+                        let d = target - cumulative;
+                        let a = (m_j - m_i) / 2.;
+                        let z = if a.abs() < <$f>::EPSILON {
+                            d / m_i
+                        } else {
+                            (-m_i + (m_i * m_i + 4. * a * d).max(0.).sqrt()) / (2. * a)
+                        };
+                        return Some((p_i + z.clamp(0., 1.) * (p_j - p_i)).clamp(min, max));
+                    }
+                    cumulative += mass;
+                }
+
+                Some(max)
+            }
+
+            /// Bins, surrounded by the exact bounds as empty bins.
+            fn points(&self) -> Vec<($f, $f)> {
+                let Some((min, max)) = self.bounds else {
+                    return Vec::new();
+                };
+
+                Some((min, 0.))
+                    .into_iter()
+                    .chain(self.bins.iter().map(|&(c, m)| (c, m as $f)))
+                    .chain(Some((max, 0.)))
+                    .collect()
+            }
+        }
+
+        impl State<$f> {
+            fn insert(&mut self, centroid: $f, count: u64) {
+                let bins = &mut self.hist.bins;
+                match bins.binary_search_by(|(c, _)| c.total_cmp(&centroid)) {
+                    Ok(idx) => bins[idx].1 += count,
+                    Err(idx) => bins.insert(idx, (centroid, count)),
+                }
+            }
+
+            fn shrink(&mut self) {
+                let bins = &mut self.hist.bins;
+                while bins.len() > self.limit {
+                    let idx = (0..bins.len() - 1)
+                        .min_by(|&a, &b| {
+                            let gap = |i: usize| bins[i + 1].0 - bins[i].0;
+                            gap(a).total_cmp(&gap(b))
+                        })
+                        .unwrap();
+
+                    let ((c1, m1), (c2, m2)) = (bins[idx], bins.remove(idx + 1));
+                    let count = m1 + m2;
+                    bins[idx] = ((c1 * m1 as $f + c2 * m2 as $f) / count as $f, count);
+                }
+            }
+        }
+
+        impl<T> Reductor<T> for StreamingHistogram<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = State<$f>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            fn reduce(mut state: Self::State, item: T) -> Self::State {
+                let item = item.into();
+                if !item.is_finite() {
+                    return state;
+                }
+
+                let hist = &mut state.hist;
+                hist.total += 1;
+                hist.bounds = Some(match hist.bounds {
+                    Some((min, max)) => (min.min(item), max.max(item)),
+                    None => (item, item),
+                });
+
+                state.insert(item, 1);
+                state.shrink();
+                state
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                state.hist
+            }
+        }
+
+        impl<T> MergeReductor<T> for StreamingHistogram<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+                let hist = &mut left.hist;
+                hist.total += right.hist.total;
+                hist.bounds = match (hist.bounds, right.hist.bounds) {
+                    (Some((l_min, l_max)), Some((r_min, r_max))) => {
+                        Some((l_min.min(r_min), l_max.max(r_max)))
+                    }
+                    (bounds, None) | (None, bounds) => bounds,
+                };
+
+                for (centroid, count) in right.hist.bins {
+                    left.insert(centroid, count);
+                }
+                left.shrink();
+                left
+            }
+        }
+    };
+}
+
+impl_streaming_histogram!(f32);
+impl_streaming_histogram!(f64);
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use crate::Reduce;
+
+    use super::*;
+
+    /// Normally distributed values, using the Box-Muller transform.
+    fn normal(rng: &mut SmallRng, mean: f64, std_dev: f64) -> f64 {
+        let (u1, u2): (f64, f64) = (1. - rng.random::<f64>(), rng.random());
+        mean + std_dev * (-2. * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+
+    /// Checks that the estimated quantiles are within 2% of the true rank.
+    fn assert_quantiles(mut values: Vec<f64>) {
+        let hist: StreamingHistogram<f64> = values.iter().copied().reduce_with();
+        values.sort_unstable_by(f64::total_cmp);
+
+        for q in [0.01, 0.05, 0.1, 0.25, 0.5, 0.75, 0.9, 0.95, 0.99] {
+            let estimate = hist.quantile(q).unwrap();
+            let rank = values.partition_point(|&v| v <= estimate) as f64 / values.len() as f64;
+            assert!(
+                (rank - q).abs() < 0.02,
+                "q = {q}: estimated {estimate} has rank {rank}"
+            );
+
+            let below = hist.count_below(values[(q * values.len() as f64) as usize]);
+            assert!(
+                (below / values.len() as f64 - q).abs() < 0.02,
+                "q = {q}: {below} estimated below"
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaming_histogram_unimodal() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_quantiles((0..20_000).map(|_| normal(&mut rng, 10., 3.)).collect());
+    }
+
+    #[test]
+    fn test_streaming_histogram_bimodal() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        assert_quantiles(
+            (0..20_000)
+                .map(|_| {
+                    if rng.random_bool(0.3) {
+                        normal(&mut rng, -50., 5.)
+                    } else {
+                        normal(&mut rng, 100., 10.)
+                    }
+                })
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn test_streaming_histogram_bin_limit() {
+        let hist: StreamingHistogram<f64> = (0..10_000)
+            .map(|i| ((i * 7919) % 10_000) as f64)
+            .chain([f64::NAN, f64::INFINITY])
+            .fold_with(16);
+
+        assert_eq!(hist.bins().len(), 16);
+        assert_eq!(hist.total(), 10_000);
+        assert_eq!(hist.bins().iter().map(|&(_, m)| m).sum::<u64>(), 10_000);
+        assert!(hist.bins().windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(hist.bounds(), Some((0., 9999.)));
+        assert_eq!(hist.quantile(0.), Some(0.));
+        assert_eq!(hist.quantile(1.), Some(9999.));
+        assert_eq!(hist.count_below(-1.), 0.);
+        assert_eq!(hist.count_below(9999.), 10_000.);
+    }
+
+    #[test]
+    fn test_streaming_histogram_few_values() {
+        let hist: StreamingHistogram<f32> = [3u8, 1, 3, 2].into_iter().reduce_with();
+        assert_eq!(hist.bins(), [(1., 1), (2., 1), (3., 2)]);
+
+        let empty: StreamingHistogram<f32> = std::iter::empty::<f32>().reduce_with();
+        assert_eq!(empty.quantile(0.5), None);
+        assert_eq!(empty.count_below(0.), 0.);
+    }
+
+    #[test]
+    fn test_streaming_histogram_merge() {
+        type R = StreamingHistogram<f64>;
+
+        let fold =
+            |range: std::ops::Range<i32>| range.fold(State::from(20), <R as Reductor<i32>>::reduce);
+        let merged = <R as Reductor<i32>>::into_result(<R as MergeReductor<i32>>::merge(
+            fold(0..5000),
+            fold(5000..10_000),
+        ));
+
+        assert_eq!(merged.bins().len(), 20);
+        assert_eq!(merged.total(), 10_000);
+        assert_eq!(merged.bounds(), Some((0., 9999.)));
+        assert!((merged.quantile(0.5).unwrap() - 5000.).abs() < 200.);
+    }
+}
@@ -1,8 +1,13 @@
-use std::iter::{self, empty, once};
+use std::{
+    iter::{self, empty, once},
+    ops::AddAssign,
+};
 
-use crate::Reductor;
+use crate::{MergeReductor, Reductor};
 
 /// Reductor that adds items yielded by an iterator to each other (similarly to [`Iterator::sum`]).
+///
+/// See [`SumAssign`] for adding items in place, with [`AddAssign`].
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Sum<T>(pub T);
@@ -36,6 +41,76 @@ where
     }
 }
 
+impl<A, T> MergeReductor<A> for Sum<T>
+where
+    T: iter::Sum + iter::Sum<A>,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        once(left).chain(once(right)).sum()
+    }
+}
+
+/// Reductor that adds items yielded by an iterator to each other, like [`Sum`], but adds every
+/// item (after the first one) to the sum in place, using [`AddAssign`].
+///
+/// The result is the same as [`Sum`]'s, but this only supports sums that implement
+/// `AddAssign` for the iterator's item type (so e.g. not [`Option`]s).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SumAssign};
+///
+/// let SumAssign::<u64>(sum) = (1..=100).reduce_with();
+/// assert_eq!(sum, 5050);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SumAssign<T>(pub T);
+
+impl<T> Default for SumAssign<T>
+where
+    T: iter::Sum,
+{
+    #[inline]
+    fn default() -> Self {
+        Self(empty::<T>().sum())
+    }
+}
+
+impl<A, T> Reductor<A> for SumAssign<T>
+where
+    T: iter::Sum<A> + AddAssign<A>,
+{
+    type State = T;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        once(item).sum()
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: A) -> Self::State {
+        state += item;
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self(state)
+    }
+}
+
+impl<A, T> MergeReductor<A> for SumAssign<T>
+where
+    T: iter::Sum<A> + AddAssign<A> + AddAssign,
+{
+    #[inline]
+    fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+        left += right;
+        left
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Reduce;
@@ -46,4 +121,38 @@ mod tests {
     fn test_sum_borrowed() {
         let Sum::<f64>(_) = [].iter().reduce_with();
     }
+
+    #[test]
+    fn test_sum_options() {
+        // `Option<i32>` has no `AddAssign` impl, only `iter::Sum`
+        let Sum::<Option<i32>>(sum) = [Some(1), Some(2)].into_iter().fold_with(0);
+        assert_eq!(sum, Some(3));
+
+        let Sum::<Option<i32>>(sum) = [Some(1), None, Some(2)].into_iter().fold_with(0);
+        assert_eq!(sum, None);
+    }
+
+    #[test]
+    fn test_sum_assign_matches_sum() {
+        let ints: Vec<i64> = (-500..1000).map(|x| x * 7919 % 1013).collect();
+        let Sum::<i64>(sum) = ints.iter().copied().reduce_with();
+        assert_eq!(sum, ints.iter().sum());
+        let SumAssign::<i64>(sum_assign) = ints.iter().reduce_with();
+        assert_eq!(sum_assign, sum);
+
+        // float addition isn't associative, so the order of additions must be preserved exactly
+        let floats: Vec<f64> = ints.iter().map(|&x| x as f64 / 3. + 1e10).collect();
+        let Sum::<f64>(sum) = floats.iter().copied().reduce_with();
+        assert_eq!(sum.to_bits(), floats.iter().sum::<f64>().to_bits());
+        let SumAssign::<f64>(sum_assign) = floats.iter().reduce_with();
+        assert_eq!(sum_assign.to_bits(), sum.to_bits());
+
+        let floats: Vec<f32> = floats.iter().map(|&x| x as f32).collect();
+        let Sum::<f32>(sum) = floats.iter().copied().reduce_with();
+        let SumAssign::<f32>(sum_assign) = floats.iter().copied().reduce_with();
+        assert_eq!(sum_assign.to_bits(), sum.to_bits());
+
+        let SumAssign::<i64>(merged) = crate::parallel::par_reduce_slice(&ints, 3, 0);
+        assert_eq!(merged, ints.iter().sum());
+    }
 }
@@ -0,0 +1,180 @@
+use std::{
+    cmp::Ordering,
+    iter::{self, empty, once},
+    ops::Add,
+};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes both the sum of items yielded by an iterator, and the number of
+/// times consecutive items differ in sign.
+///
+/// Zero has no sign, so zeros neither break nor start a run of items of the same sign:
+/// a sign change is counted between a positive item and the next non-zero item if it is
+/// negative, and vice versa (e.g. `[1, 0, -1]` has a single sign change, while `[1, 0, 1]` has
+/// none). Items that can't be compared to zero (i.e. NaN) are treated the same way.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SumAndSignChanges};
+///
+/// let SumAndSignChanges::<i32> { sum, sign_changes } =
+///     [3, -1, -2, 0, 4, 0, 5, -6].into_iter().reduce_with();
+///
+/// assert_eq!(sum, 3);
+/// assert_eq!(sign_changes, 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SumAndSignChanges<T> {
+    /// Sum of items yielded by iterator.
+    pub sum: T,
+    /// Number of sign changes between consecutive (non-zero) items yielded by iterator.
+    pub sign_changes: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    sum: T,
+    first_sign: Option<Ordering>,
+    last_sign: Option<Ordering>,
+    sign_changes: usize,
+}
+
+impl<T> Default for State<T>
+where
+    T: iter::Sum,
+{
+    fn default() -> Self {
+        Self {
+            sum: empty::<T>().sum(),
+            first_sign: None,
+            last_sign: None,
+            sign_changes: 0,
+        }
+    }
+}
+
+impl<T> Default for SumAndSignChanges<T>
+where
+    T: iter::Sum,
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            sum: empty::<T>().sum(),
+            sign_changes: 0,
+        }
+    }
+}
+
+impl<A, T> Reductor<A> for SumAndSignChanges<T>
+where
+    T: iter::Sum + iter::Sum<A> + Add<Output = T> + PartialOrd,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: A) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: A) -> Self::State {
+        let item: T = once(item).sum();
+
+        match item.partial_cmp(&empty::<T>().sum()) {
+            None | Some(Ordering::Equal) => {}
+            sign => {
+                if state.last_sign.is_some_and(|last| Some(last) != sign) {
+                    state.sign_changes += 1;
+                }
+                state.first_sign = state.first_sign.or(sign);
+                state.last_sign = sign;
+            }
+        }
+
+        state.sum = state.sum + item;
+        state
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            sum: state.sum,
+            sign_changes: state.sign_changes,
+        }
+    }
+}
+
+impl<A, T> MergeReductor<A> for SumAndSignChanges<T>
+where
+    T: iter::Sum + iter::Sum<A> + Add<Output = T> + PartialOrd,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        let boundary_change = matches!(
+            (left.last_sign, right.first_sign),
+            (Some(last), Some(first)) if last != first
+        );
+
+        State {
+            sum: left.sum + right.sum,
+            first_sign: left.first_sign.or(right.first_sign),
+            last_sign: right.last_sign.or(left.last_sign),
+            sign_changes: left.sign_changes + right.sign_changes + usize::from(boundary_change),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_sum_and_sign_changes_zeros() {
+        let SumAndSignChanges::<i64> { sum, sign_changes } = [1, 0, -1].into_iter().reduce_with();
+        assert_eq!((sum, sign_changes), (0, 1));
+
+        let SumAndSignChanges::<i64> { sum, sign_changes } = [1, 0, 0, 1].into_iter().reduce_with();
+        assert_eq!((sum, sign_changes), (2, 0));
+
+        let SumAndSignChanges::<i64> { sum, sign_changes } =
+            [0, 0, -3, 0].into_iter().reduce_with();
+        assert_eq!((sum, sign_changes), (-3, 0));
+
+        let SumAndSignChanges::<f64> { sum, sign_changes } =
+            [-0.5, f64::NAN, 0., -0., 2.5].iter().reduce_with();
+        assert!(sum.is_nan());
+        assert_eq!(sign_changes, 1);
+
+        let SumAndSignChanges::<i64> { sum, sign_changes } =
+            std::iter::empty::<i64>().reduce_with();
+        assert_eq!((sum, sign_changes), (0, 0));
+    }
+
+    #[test]
+    fn test_sum_and_sign_changes_merge() {
+        type R = SumAndSignChanges<i32>;
+
+        let data = [3, 0, -1, -2, 0, 0, 4, 5, 0, -6, 7];
+        let expected = data.into_iter().reduce_with::<R>();
+        assert_eq!(
+            expected,
+            SumAndSignChanges {
+                sum: 10,
+                sign_changes: 4
+            }
+        );
+
+        for split in 0..=data.len() {
+            let (left, right) = data.split_at(split);
+            let left = left.iter().copied().fold(Default::default(), R::reduce);
+            let right = right.iter().copied().fold(Default::default(), R::reduce);
+
+            let merged =
+                <R as Reductor<i32>>::into_result(<R as MergeReductor<i32>>::merge(left, right));
+            assert_eq!(merged, expected, "split at {split}");
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::time::{Instant, SystemTime};
+
+use crate::{MergeReductor, Reductor};
+
+/// Timestamp that [`Throughput`] can measure the elapsed time between, implemented for
+/// [`Instant`], [`SystemTime`], and numbers of seconds (e.g. [`f64`] or [`u64`]).
+pub trait Timestamp {
+    /// Number of seconds elapsed from `earlier` to `self`, which is never negative.
+    fn secs_since(&self, earlier: &Self) -> f64;
+}
+
+impl Timestamp for Instant {
+    #[inline]
+    fn secs_since(&self, earlier: &Self) -> f64 {
+        self.saturating_duration_since(*earlier).as_secs_f64()
+    }
+}
+
+impl Timestamp for SystemTime {
+    #[inline]
+    fn secs_since(&self, earlier: &Self) -> f64 {
+        self.duration_since(*earlier)
+            .unwrap_or_default()
+            .as_secs_f64()
+    }
+}
+
+macro_rules! impl_timestamp {
+    ($($t:ty),*) => {
+        $(
+            impl Timestamp for $t {
+                #[inline]
+                fn secs_since(&self, earlier: &Self) -> f64 {
+                    (*self as f64 - *earlier as f64).max(0.)
+                }
+            }
+        )*
+    };
+}
+
+impl_timestamp!(u32, u64, i32, i64, f32, f64);
+
+/// Reductor that measures the rate of events, given the [`Timestamp`]s of the events yielded by
+/// an iterator.
+///
+/// The elapsed time is the span between the earliest and latest timestamps (which aren't
+/// necessarily the first and last ones, if timestamps are yielded out of order). The rate is
+/// undefined for fewer than two distinct timestamps, so [`rate`](Throughput::rate) returns `None`
+/// if no time has elapsed.
+///
+/// # Examples
+/// Measuring an ingest loop, while also splitting it into bursts with [`Sessions`](crate::Sessions):
+/// ```rust
+/// use std::time::{Duration, Instant};
+///
+/// use reductor::{Reduce, Reductors, Sessions, Throughput};
+///
+/// let start = Instant::now();
+/// let arrivals = [0, 10, 20, 30, 500, 510, 520, 1000].map(|ms| start + Duration::from_millis(ms));
+///
+/// let Reductors((throughput, sessions)): Reductors<(Throughput, Sessions<Duration>)> = arrivals
+///     .into_iter()
+///     .fold_with((Default::default(), Duration::from_millis(100).into()));
+///
+/// assert_eq!(throughput.count, 8);
+/// assert_eq!(throughput.elapsed_secs, 1.);
+/// assert_eq!(throughput.rate(), Some(8.));
+///
+/// assert_eq!(sessions.sessions, 3);
+/// assert_eq!(sessions.longest, Some(Duration::from_millis(30)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Throughput {
+    /// Number of timestamps yielded by iterator.
+    pub count: usize,
+    /// Seconds elapsed between the earliest and latest timestamps,
+    /// or zero if there were fewer than two timestamps.
+    pub elapsed_secs: f64,
+}
+
+impl Throughput {
+    /// Number of events per second, or `None` if no time has elapsed
+    /// (e.g. if there were fewer than two timestamps).
+    pub fn rate(&self) -> Option<f64> {
+        (self.elapsed_secs > 0.).then(|| self.count as f64 / self.elapsed_secs)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<T> {
+    bounds: Option<(T, T)>,
+    count: usize,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            bounds: None,
+            count: 0,
+        }
+    }
+}
+
+impl<T> Reductor<T> for Throughput
+where
+    T: Timestamp + PartialOrd + Clone,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        State {
+            bounds: Some((item.clone(), item)),
+            count: 1,
+        }
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        match &mut state.bounds {
+            Some((earliest, _)) if item < *earliest => *earliest = item,
+            Some((_, latest)) if item > *latest => *latest = item,
+            Some(_) => {}
+            None => return Self::new(item),
+        }
+        state.count += 1;
+        state
+    }
+
+    #[inline]
+    fn into_result(State { bounds, count }: Self::State) -> Self {
+        Self {
+            count,
+            elapsed_secs: bounds.map_or(0., |(earliest, latest)| latest.secs_since(&earliest)),
+        }
+    }
+}
+
+impl<T> MergeReductor<T> for Throughput
+where
+    T: Timestamp + PartialOrd + Clone,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        State {
+            bounds: match (left.bounds, right.bounds) {
+                (Some((left_earliest, left_latest)), Some((right_earliest, right_latest))) => {
+                    Some((
+                        if right_earliest < left_earliest {
+                            right_earliest
+                        } else {
+                            left_earliest
+                        },
+                        if right_latest > left_latest {
+                            right_latest
+                        } else {
+                            left_latest
+                        },
+                    ))
+                }
+                (bounds, None) | (None, bounds) => bounds,
+            },
+            count: left.count + right.count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_throughput_numeric() {
+        let throughput: Throughput = [12.5, 10., 11., 14.5, 13.].into_iter().reduce_with();
+        assert_eq!(
+            throughput,
+            Throughput {
+                count: 5,
+                elapsed_secs: 4.5,
+            }
+        );
+        assert_eq!(throughput.rate(), Some(5. / 4.5));
+
+        let throughput: Throughput = (1_700_000_000_u64..1_700_000_100).reduce_with();
+        assert_eq!(throughput.elapsed_secs, 99.);
+        assert_eq!(throughput.rate(), Some(100. / 99.));
+    }
+
+    #[test]
+    fn test_throughput_system_time() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let throughput: Throughput = (0..=40)
+            .map(|i| start + Duration::from_millis(i * 250))
+            .reduce_with();
+
+        assert_eq!(throughput.count, 41);
+        assert_eq!(throughput.elapsed_secs, 10.);
+        assert_eq!(throughput.rate(), Some(4.1));
+
+        let merged: Throughput = crate::parallel::par_reduce_slice(
+            &(0..=40)
+                .rev()
+                .map(|i| start + Duration::from_millis(i * 250))
+                .collect::<Vec<_>>(),
+            3,
+            0,
+        );
+        assert_eq!(merged, throughput);
+    }
+
+    #[test]
+    fn test_throughput_no_elapsed_time() {
+        let throughput: Throughput = std::iter::empty::<Instant>().reduce_with();
+        assert_eq!(throughput, Throughput::default());
+        assert_eq!(throughput.rate(), None);
+
+        let throughput: Throughput = [Instant::now()].into_iter().reduce_with();
+        assert_eq!(throughput.count, 1);
+        assert_eq!(throughput.rate(), None);
+
+        let throughput: Throughput = [7_u32; 3].into_iter().reduce_with();
+        assert_eq!(throughput.count, 3);
+        assert_eq!(throughput.rate(), None);
+    }
+}
@@ -0,0 +1,121 @@
+use std::iter::{self, empty, once};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that adds items yielded by an iterator to each other (like [`Sum`](crate::Sum)),
+/// while also finding the largest item, e.g. for reporting how much of a total came from
+/// a single item.
+///
+/// This is equivalent to combining `Sum` with [`Max`](crate::Max) (e.g. with
+/// [`Reductors`](crate::Reductors)), but rather than cloning every item, items are only cloned
+/// when they are larger than all items before them. `largest` is `None` for an empty iterator.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SumWithTopContributor};
+///
+/// let invoices_cents = [12_000u64, 4_500, 60_000, 3_500];
+///
+/// let SumWithTopContributor { sum, largest } = invoices_cents.into_iter().reduce_with();
+/// assert_eq!(sum, 80_000);
+/// assert_eq!(largest, Some(60_000));
+/// assert_eq!(largest.unwrap() * 100 / sum, 75);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SumWithTopContributor<T> {
+    /// Sum of items yielded by iterator.
+    pub sum: T,
+    /// Largest item yielded by iterator.
+    pub largest: Option<T>,
+}
+
+impl<T> Default for SumWithTopContributor<T>
+where
+    T: iter::Sum,
+{
+    fn default() -> Self {
+        Self {
+            sum: empty::<T>().sum(),
+            largest: None,
+        }
+    }
+}
+
+impl<T> Reductor<T> for SumWithTopContributor<T>
+where
+    T: iter::Sum + Ord + Clone,
+{
+    type State = Self;
+
+    #[inline]
+    fn new(item: T) -> Self::State {
+        Self::reduce(Self::default(), item)
+    }
+
+    #[inline]
+    fn reduce(Self { sum, mut largest }: Self::State, item: T) -> Self::State {
+        if largest.as_ref().is_none_or(|largest| item > *largest) {
+            largest = Some(item.clone());
+        }
+        Self {
+            sum: once(sum).chain(once(item)).sum(),
+            largest,
+        }
+    }
+
+    #[inline]
+    fn into_result(state: Self::State) -> Self {
+        state
+    }
+}
+
+impl<T> MergeReductor<T> for SumWithTopContributor<T>
+where
+    T: iter::Sum + Ord + Clone,
+{
+    fn merge(left: Self::State, right: Self::State) -> Self::State {
+        Self {
+            sum: once(left.sum).chain(once(right.sum)).sum(),
+            largest: left.largest.max(right.largest),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Max, Reduce, Reductors, Sum};
+
+    use super::*;
+
+    #[test]
+    fn test_sum_with_top_contributor() {
+        let data = [3, 17, 5, 17, 2, 11];
+
+        let SumWithTopContributor { sum, largest } = data.into_iter().reduce_with();
+        let Reductors((Sum::<u32>(expected_sum), Max(expected_largest))) =
+            data.into_iter().reduce_with::<Option<_>>().unwrap();
+
+        assert_eq!(sum, expected_sum);
+        assert_eq!(largest, Some(expected_largest));
+
+        let empty: SumWithTopContributor<i64> = std::iter::empty().reduce_with();
+        assert_eq!(
+            empty,
+            SumWithTopContributor {
+                sum: 0,
+                largest: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_sum_with_top_contributor_merge() {
+        let data: Vec<i64> = (0..1_000).map(|i| (i * 7919) % 1_009 - 500).collect();
+
+        let sequential: SumWithTopContributor<i64> = data.iter().copied().reduce_with();
+        let parallel: SumWithTopContributor<i64> = par_reduce_slice(&data, 4, 0);
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(sequential.largest, data.iter().copied().max());
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{MergeReductor, Reductor};
+
+/// Summary of one group of [`TwoSampleStats`].
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroupStats<F> {
+    /// Number of items in the group.
+    pub count: usize,
+    /// Mean of the group's items, or `None` if the group is empty.
+    pub mean: Option<F>,
+    /// Sample variance (i.e. with [Bessel's correction]) of the group's items,
+    /// or `None` if the group has fewer than two items.
+    ///
+    /// [Bessel's correction]: https://en.wikipedia.org/wiki/Bessel%27s_correction
+    pub variance: Option<F>,
+}
+
+/// Reductor that computes the count, mean and variance of each of two groups of items,
+/// given the `(group, item)` pairs yielded by an iterator, e.g. for A/B testing.
+///
+/// Items paired with `false` are summarized in `control`, and items paired with `true` are
+/// summarized in `treatment`. The means and variances are updated for each item using
+/// [Welford's online algorithm], which is numerically stable.
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and the
+/// items can be any type that implements [`Into<F>`].
+///
+/// [Welford's online algorithm]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, TwoSampleStats};
+///
+/// let conversions = [(false, 1.), (true, 2.), (false, 3.), (true, 6.), (false, 2.), (true, 4.)];
+///
+/// let stats: TwoSampleStats<f64> = conversions.into_iter().reduce_with();
+///
+/// assert_eq!(stats.control.mean, Some(2.));
+/// assert_eq!(stats.treatment.variance, Some(4.));
+/// assert_eq!(stats.mean_difference(), Some(2.));
+/// assert!((stats.t_statistic().unwrap() - 2. / (5f64 / 3.).sqrt()).abs() < 1e-12);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoSampleStats<F> {
+    /// Summary of the items paired with `false`.
+    pub control: GroupStats<F>,
+    /// Summary of the items paired with `true`.
+    pub treatment: GroupStats<F>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Welford<F> {
+    count: usize,
+    mean: F,
+    /// Sum of squared differences from the mean.
+    m2: F,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct State<F> {
+    control: Welford<F>,
+    treatment: Welford<F>,
+}
+
+macro_rules! impl_two_sample_stats {
+    ($f:ty) => {
+        impl Welford<$f> {
+            #[inline]
+            fn push(&mut self, item: $f) {
+                self.count += 1;
+                let delta = item - self.mean;
+                self.mean += delta / self.count as $f;
+                self.m2 += delta * (item - self.mean);
+            }
+
+            fn merge(self, other: Self) -> Self {
+                let count = self.count + other.count;
+                if count == 0 {
+                    return self;
+                }
+                let (self_count, other_count) = (self.count as $f, other.count as $f);
+                let delta = other.mean - self.mean;
+
+                Self {
+                    count,
+                    mean: self.mean + delta * other_count / count as $f,
+                    m2: self.m2 + other.m2 + delta * delta * self_count * other_count / count as $f,
+                }
+            }
+
+            fn into_group_stats(self) -> GroupStats<$f> {
+                GroupStats {
+                    count: self.count,
+                    mean: (self.count != 0).then_some(self.mean),
+                    variance: (self.count > 1).then(|| self.m2 / (self.count - 1) as $f),
+                }
+            }
+        }
+
+        impl TwoSampleStats<$f> {
+            /// Difference between the means of `treatment` and `control`,
+            /// or `None` if either group is empty.
+            pub fn mean_difference(&self) -> Option<$f> {
+                Some(self.treatment.mean? - self.control.mean?)
+            }
+
+            /// [Welch's t-statistic] for the difference between the means of `treatment` and
+            /// `control`, or `None` if either group has fewer than two items.
+            ///
+            /// If both groups have zero variance, the statistic is infinite (or NaN, if their
+            /// means are equal as well).
+            ///
+            /// [Welch's t-statistic]: https://en.wikipedia.org/wiki/Welch%27s_t-test
+            pub fn t_statistic(&self) -> Option<$f> {
+                let standard_error = (self.treatment.variance? / self.treatment.count as $f
+                    + self.control.variance? / self.control.count as $f)
+                    .sqrt();
+                Some(self.mean_difference()? / standard_error)
+            }
+        }
+
+        impl<T> Reductor<(bool, T)> for TwoSampleStats<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = State<$f>;
+
+            #[inline]
+            fn new(item: (bool, T)) -> Self::State {
+                Self::reduce(State::default(), item)
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, (group, item): (bool, T)) -> Self::State {
+                if group {
+                    state.treatment.push(item.into());
+                } else {
+                    state.control.push(item.into());
+                }
+                state
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                Self {
+                    control: state.control.into_group_stats(),
+                    treatment: state.treatment.into_group_stats(),
+                }
+            }
+        }
+
+        impl<T> MergeReductor<(bool, T)> for TwoSampleStats<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                State {
+                    control: left.control.merge(right.control),
+                    treatment: left.treatment.merge(right.treatment),
+                }
+            }
+        }
+    };
+}
+
+impl_two_sample_stats!(f32);
+impl_two_sample_stats!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    fn data() -> Vec<(bool, u8)> {
+        let control = [3, 1, 5, 2, 4].map(|x| (false, x));
+        let treatment = [8, 2, 12, 6, 10, 4].map(|x| (true, x));
+        // interleave the groups
+        let mut data: Vec<_> = control.into_iter().chain(treatment).collect();
+        data.sort_by_key(|&(_, x)| x);
+        data
+    }
+
+    #[test]
+    fn test_two_sample_stats() {
+        let data = data();
+        let stats: TwoSampleStats<f64> = data.iter().copied().reduce_with();
+
+        assert_eq!(
+            stats.control,
+            GroupStats {
+                count: 5,
+                mean: Some(3.),
+                variance: Some(2.5),
+            }
+        );
+        assert_eq!(
+            stats.treatment,
+            GroupStats {
+                count: 6,
+                mean: Some(7.),
+                variance: Some(14.),
+            }
+        );
+        assert_eq!(stats.mean_difference(), Some(4.));
+        // `scipy.stats.ttest_ind(treatment, control, equal_var=False).statistic`
+        let t = stats.t_statistic().unwrap();
+        assert!((t - 2.376_354_103_144_018_3).abs() < 1e-12, "{t}");
+
+        let merged: TwoSampleStats<f64> = crate::parallel::par_reduce_slice(&data, 3, 0);
+        assert_eq!(merged.control.count, 5);
+        assert!((merged.t_statistic().unwrap() - t).abs() < 1e-12);
+
+        let stats: TwoSampleStats<f32> = data.iter().copied().reduce_with();
+        assert!((stats.t_statistic().unwrap() - 2.376_354).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_two_sample_stats_degenerate() {
+        let stats: TwoSampleStats<f64> = [(true, 1.), (true, 2.), (true, 6.)]
+            .into_iter()
+            .reduce_with();
+        assert_eq!(stats.treatment.variance, Some(7.));
+        assert_eq!(
+            stats.control,
+            GroupStats {
+                count: 0,
+                mean: None,
+                variance: None,
+            }
+        );
+        assert_eq!(stats.mean_difference(), None);
+        assert_eq!(stats.t_statistic(), None);
+
+        let stats: TwoSampleStats<f64> = [(true, 1.), (true, 2.), (false, 6.)]
+            .into_iter()
+            .reduce_with();
+        assert_eq!(stats.mean_difference(), Some(-4.5));
+        assert_eq!(stats.t_statistic(), None);
+    }
+}
@@ -0,0 +1,544 @@
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that computes the (population) [variance] of items yielded by an iterator,
+/// i.e. the mean of the squared differences of the items from their mean.
+///
+/// The mean and the sum of squared differences from it are updated for each item using
+/// [Welford's online algorithm], which is numerically stable, even for items with a large
+/// mean relative to their spread.
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and the
+/// iterator's item type can be any type that implements [`Into<F>`].
+///
+/// [variance]: https://en.wikipedia.org/wiki/Variance
+/// [Welford's online algorithm]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Mean, Reduce, Reductors, Variance};
+///
+/// let Reductors((Mean::<f64>(mean), Variance::<f64>(variance))) = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(mean, 5.);
+/// assert_eq!(variance, 4.);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Variance<F>(pub F);
+
+/// Reductor that computes the (population) [standard deviation] of items yielded by an iterator,
+/// i.e. the square root of their [`Variance`].
+///
+/// Like [`Variance`], the mean and the sum of squared differences from it are updated for each
+/// item using Welford's online algorithm, and the square root is only taken once, at the end.
+/// The generic type `F` must be one of [`f32`] or [`f64`], and the iterator's item type can be
+/// any type that implements [`Into<F>`].
+///
+/// [standard deviation]: https://en.wikipedia.org/wiki/Standard_deviation
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Mean, Reduce, Reductors, StdDev};
+///
+/// let Reductors((Mean::<f32>(mean), StdDev::<f32>(std_dev))) = [2i16, 4, 4, 4, 5, 5, 7, 9]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(mean, 5.);
+/// assert_eq!(std_dev, 2.);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct StdDev<F>(pub F);
+
+/// Reductor that computes the (unbiased) [sample variance] of items yielded by an iterator,
+/// i.e. the sum of the squared differences of the items from their mean, divided by `n - 1`
+/// ([Bessel's correction]).
+///
+/// This shares its state with [`Variance`], see it for details. The sample variance of a
+/// single item is undefined, so in that case, the result is NaN.
+///
+/// [sample variance]: https://en.wikipedia.org/wiki/Variance#Sample_variance
+/// [Bessel's correction]: https://en.wikipedia.org/wiki/Bessel%27s_correction
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, SampleVariance};
+///
+/// let SampleVariance::<f64>(variance) = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+/// assert_eq!(variance, 32. / 7.);
+///
+/// let SampleVariance::<f64>(variance) = [2].into_iter().reduce_with::<Option<_>>().unwrap();
+/// assert!(variance.is_nan());
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SampleVariance<F>(pub F);
+
+/// Reductor that computes the [sample standard deviation] of items yielded by an iterator,
+/// i.e. the square root of their [`SampleVariance`].
+///
+/// This shares its state with [`Variance`], see it for details. The sample standard deviation
+/// of a single item is undefined, so in that case, the result is NaN.
+///
+/// [sample standard deviation]: https://en.wikipedia.org/wiki/Standard_deviation#Corrected_sample_standard_deviation
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Mean, Reduce, Reductors, SampleStdDev};
+///
+/// let Reductors((Mean::<f64>(mean), SampleStdDev::<f64>(std_dev))) = [1, 3, 5]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(mean, 3.);
+/// assert_eq!(std_dev, 2.);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SampleStdDev<F>(pub F);
+
+/// Reductor that computes the count, [mean](crate::Mean) and (population) [`Variance`] of items
+/// yielded by an iterator, all from the same state.
+///
+/// This shares its state with [`Variance`], see it for details.
+///
+/// # Examples
+/// A full summary of the items, in a single pass:
+/// ```rust
+/// use reductor::{MeanVariance, MinMax, Reduce, Reductors};
+///
+/// let Reductors((MeanVariance::<f64> { mean, variance, count }, MinMax { min, max })) =
+///     [2, 4, 4, 4, 5, 5, 7, 9]
+///         .into_iter()
+///         .reduce_with::<Option<_>>()
+///         .unwrap();
+///
+/// assert_eq!((mean, variance, count), (5., 4., 8));
+/// assert_eq!((min, max), (2, 9));
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeanVariance<F> {
+    /// Mean of items yielded by iterator.
+    pub mean: F,
+    /// Population variance of items yielded by iterator.
+    pub variance: F,
+    /// Number of items yielded by iterator.
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct State<F> {
+    pub(super) count: usize,
+    pub(super) mean: F,
+    /// Sum of squared differences from the mean.
+    pub(super) m2: F,
+}
+
+macro_rules! impl_variance {
+    ($f:ty) => {
+        impl State<$f> {
+            #[inline]
+            pub(super) fn new(item: $f) -> Self {
+                Self {
+                    count: 1,
+                    mean: item,
+                    m2: 0.,
+                }
+            }
+
+            #[inline]
+            pub(super) fn push(mut self, item: $f) -> Self {
+                self.count += 1;
+                let delta = item - self.mean;
+                self.mean += delta / self.count as $f;
+                self.m2 += delta * (item - self.mean);
+                self
+            }
+
+            pub(super) fn merge(self, other: Self) -> Self {
+                let count = self.count + other.count;
+                let (self_count, other_count) = (self.count as $f, other.count as $f);
+                let delta = other.mean - self.mean;
+
+                Self {
+                    count,
+                    mean: self.mean + delta * other_count / count as $f,
+                    m2: self.m2 + other.m2 + delta * delta * self_count * other_count / count as $f,
+                }
+            }
+        }
+
+        impl<T> Reductor<T> for Variance<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                NonEmptyState(State::<$f>::new(item.into()))
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(state): Self::State, item: T) -> Self::State {
+                NonEmptyState(state.push(item.into()))
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(state): Self::State) -> Self {
+                Self(state.m2 / state.count as $f)
+            }
+        }
+
+        impl<T> MergeReductor<T> for Variance<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(
+                NonEmptyState(left): Self::State,
+                NonEmptyState(right): Self::State,
+            ) -> Self::State {
+                NonEmptyState(left.merge(right))
+            }
+        }
+
+        impl<T> Reductor<T> for StdDev<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                <Variance<$f> as Reductor<T>>::new(item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: T) -> Self::State {
+                <Variance<$f> as Reductor<T>>::reduce(state, item)
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                let Variance(variance) = <Variance<$f> as Reductor<T>>::into_result(state);
+                Self(variance.sqrt())
+            }
+        }
+
+        impl<T> MergeReductor<T> for StdDev<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                <Variance<$f> as MergeReductor<T>>::merge(left, right)
+            }
+        }
+
+        impl<T> Reductor<T> for MeanVariance<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                <Variance<$f> as Reductor<T>>::new(item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: T) -> Self::State {
+                <Variance<$f> as Reductor<T>>::reduce(state, item)
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(state): Self::State) -> Self {
+                Self {
+                    mean: state.mean,
+                    variance: state.m2 / state.count as $f,
+                    count: state.count,
+                }
+            }
+        }
+
+        impl<T> MergeReductor<T> for MeanVariance<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                <Variance<$f> as MergeReductor<T>>::merge(left, right)
+            }
+        }
+
+        impl<T> Reductor<T> for SampleVariance<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                <Variance<$f> as Reductor<T>>::new(item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: T) -> Self::State {
+                <Variance<$f> as Reductor<T>>::reduce(state, item)
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(state): Self::State) -> Self {
+                if state.count < 2 {
+                    return Self(<$f>::NAN);
+                }
+                Self(state.m2 / (state.count - 1) as $f)
+            }
+        }
+
+        impl<T> MergeReductor<T> for SampleVariance<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                <Variance<$f> as MergeReductor<T>>::merge(left, right)
+            }
+        }
+
+        impl<T> Reductor<T> for SampleStdDev<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<State<$f>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                <Variance<$f> as Reductor<T>>::new(item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, item: T) -> Self::State {
+                <Variance<$f> as Reductor<T>>::reduce(state, item)
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                let SampleVariance(variance) =
+                    <SampleVariance<$f> as Reductor<T>>::into_result(state);
+                Self(variance.sqrt())
+            }
+        }
+
+        impl<T> MergeReductor<T> for SampleStdDev<$f>
+        where
+            T: Into<$f>,
+        {
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                <Variance<$f> as MergeReductor<T>>::merge(left, right)
+            }
+        }
+    };
+}
+
+impl_variance!(f32);
+impl_variance!(f64);
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use crate::{parallel::par_reduce_slice, Count, Mean, Reduce, Reductors};
+
+    use super::*;
+
+    #[test]
+    fn test_variance_two_pass() {
+        macro_rules! test {
+            ($f:ty, $tolerance:expr) => {
+                let mut rng = SmallRng::seed_from_u64(0);
+                let data: Vec<$f> = (0..1000)
+                    .map(|_| 1000. + rng.random_range(-5. ..5.))
+                    .collect();
+
+                // reference computed in `f64`, to keep its own rounding errors out of the way
+                let mean = data.iter().map(|&x| f64::from(x)).sum::<f64>() / data.len() as f64;
+                let two_pass = data
+                    .iter()
+                    .map(|&x| (f64::from(x) - mean).powi(2))
+                    .sum::<f64>()
+                    / data.len() as f64;
+
+                let Reductors((Mean::<$f>(streaming_mean), Variance::<$f>(variance))) =
+                    data.iter().copied().reduce_with::<Option<_>>().unwrap();
+                assert!((f64::from(streaming_mean) - mean).abs() < $tolerance * mean);
+                assert!(
+                    (f64::from(variance) - two_pass).abs() < $tolerance * two_pass,
+                    "{variance} != {two_pass}"
+                );
+
+                let Variance::<$f>(merged) = par_reduce_slice::<Option<_>, _>(&data, 4, 0).unwrap();
+                assert!((f64::from(merged) - two_pass).abs() < $tolerance * two_pass);
+            };
+        }
+
+        test!(f32, 1e-3);
+        test!(f64, 1e-12);
+    }
+
+    #[test]
+    fn test_std_dev() {
+        macro_rules! test {
+            ($f:ty) => {
+                let mut rng = SmallRng::seed_from_u64(1);
+                let data: Vec<$f> = (0..500).map(|_| rng.random_range(-50. ..50.)).collect();
+
+                let Reductors((Variance::<$f>(variance), StdDev::<$f>(std_dev))) =
+                    data.iter().copied().reduce_with::<Option<_>>().unwrap();
+                assert_eq!(std_dev, variance.sqrt());
+
+                let StdDev::<$f>(merged) = par_reduce_slice::<Option<_>, _>(&data, 3, 0).unwrap();
+                assert!((merged - std_dev).abs() < 1e-4);
+
+                let StdDev::<$f>(std_dev) = [7u8].into_iter().reduce_with::<Option<_>>().unwrap();
+                assert_eq!(std_dev, 0.);
+            };
+        }
+
+        test!(f32);
+        test!(f64);
+    }
+
+    #[test]
+    fn test_std_dev_two_pass() {
+        macro_rules! test {
+            ($f:ty, $tolerance:expr) => {
+                let mut rng = SmallRng::seed_from_u64(3);
+                let data: Vec<$f> = (0..2000).map(|_| rng.random_range(0. ..100.)).collect();
+
+                let len = data.len() as f64;
+                let mean = data.iter().map(|&x| f64::from(x)).sum::<f64>() / len;
+                let squares = data
+                    .iter()
+                    .map(|&x| (f64::from(x) - mean).powi(2))
+                    .sum::<f64>();
+                let (population, sample) = ((squares / len).sqrt(), (squares / (len - 1.)).sqrt());
+
+                let Reductors((
+                    Mean::<$f>(streaming_mean),
+                    StdDev::<$f>(std_dev),
+                    SampleStdDev::<$f>(sample_std_dev),
+                )) = data.iter().copied().reduce_with::<Option<_>>().unwrap();
+                assert!((f64::from(streaming_mean) - mean).abs() < $tolerance * mean);
+                assert!(
+                    (f64::from(std_dev) - population).abs() < $tolerance * population,
+                    "{std_dev} != {population}"
+                );
+                assert!(
+                    (f64::from(sample_std_dev) - sample).abs() < $tolerance * sample,
+                    "{sample_std_dev} != {sample}"
+                );
+
+                let SampleStdDev::<$f>(single) =
+                    [1u8].into_iter().reduce_with::<Option<_>>().unwrap();
+                assert!(single.is_nan());
+            };
+        }
+
+        test!(f32, 1e-4);
+        test!(f64, 1e-12);
+    }
+
+    #[test]
+    fn test_sample_variance_two_pass() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        for len in [2, 3, 10, 1000] {
+            let data: Vec<f64> = (0..len).map(|_| rng.random_range(-1e3..1e3)).collect();
+
+            let mean = data.iter().sum::<f64>() / len as f64;
+            let two_pass = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (len - 1) as f64;
+
+            let Reductors((Count(count), SampleVariance::<f64>(variance))) =
+                data.iter().copied().reduce_with::<Option<_>>().unwrap();
+            assert_eq!(count, len);
+            assert!(
+                (variance - two_pass).abs() < 1e-9 * two_pass,
+                "{variance} != {two_pass}"
+            );
+
+            let Variance::<f64>(population) =
+                data.iter().copied().reduce_with::<Option<_>>().unwrap();
+            assert!((variance * (count - 1) as f64 - population * count as f64).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_sample_variance_single_item() {
+        let SampleVariance::<f32>(variance) =
+            [4.2f32].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert!(variance.is_nan());
+
+        let SampleVariance::<f64>(variance) =
+            [1, 3].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(variance, 2.);
+
+        assert_eq!(
+            std::iter::empty::<f64>().reduce_with::<Option<SampleVariance<f64>>>(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_mean_variance() {
+        let mut rng = SmallRng::seed_from_u64(4);
+        let data: Vec<f32> = (0..300).map(|_| rng.random_range(10. ..20.)).collect();
+
+        let Reductors((
+            MeanVariance::<f32> {
+                mean,
+                variance,
+                count,
+            },
+            Mean::<f32>(separate_mean),
+            Variance::<f32>(separate_variance),
+        )) = data.iter().copied().reduce_with::<Option<_>>().unwrap();
+
+        assert_eq!(count, data.len());
+        assert!((mean - separate_mean).abs() < 1e-4);
+        assert_eq!(variance, separate_variance);
+
+        let merged: MeanVariance<f32> = par_reduce_slice::<Option<_>, _>(&data, 4, 0).unwrap();
+        assert_eq!(merged.count, count);
+        assert!((merged.mean - mean).abs() < 1e-4);
+        assert!((merged.variance - variance).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_variance_degenerate() {
+        let Variance::<f64>(variance) = [3u8].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(variance, 0.);
+
+        let Variance::<f32>(variance) = [-1.5f32; 10]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(variance, 0.);
+
+        assert_eq!(
+            std::iter::empty::<f64>().reduce_with::<Option<Variance<f64>>>(),
+            None
+        );
+    }
+}
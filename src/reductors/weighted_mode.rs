@@ -0,0 +1,154 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that finds the category with the greatest total weight, among the
+/// `(category, weight)` pairs yielded by an iterator, i.e. the [mode] of the categories, where
+/// each occurrence counts as much as its weight (rather than just once).
+///
+/// Weights can be any type that implements [`Into<f64>`]. If several categories are tied for
+/// the greatest total weight, the one that was yielded first wins. Categories whose total weight
+/// is NaN are never the mode. The mode is `None` if the iterator yields no items.
+///
+/// See [`ModeAndFrequencies`](crate::ModeAndFrequencies) for the (unweighted) mode.
+///
+/// [mode]: https://en.wikipedia.org/wiki/Mode_(statistics)
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, WeightedMode};
+///
+/// // (region, revenue) of each sale
+/// let sales = [("east", 120), ("west", 900), ("east", 300), ("north", 50), ("east", 200)];
+///
+/// let WeightedMode(dominant) = sales.into_iter().reduce_with();
+/// assert_eq!(dominant, Some("west"));
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WeightedMode<T>(pub Option<T>);
+
+impl<T> Default for WeightedMode<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    /// Total weight of each category, and the index at which it was first yielded.
+    weights: HashMap<T, (f64, usize)>,
+    len: usize,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            weights: HashMap::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T, W> Reductor<(T, W)> for WeightedMode<T>
+where
+    T: Eq + Hash,
+    W: Into<f64>,
+{
+    type State = State<T>;
+
+    #[inline]
+    fn new(item: (T, W)) -> Self::State {
+        Self::reduce(State::default(), item)
+    }
+
+    #[inline]
+    fn reduce(mut state: Self::State, (category, weight): (T, W)) -> Self::State {
+        state.weights.entry(category).or_insert((0., state.len)).0 += weight.into();
+        state.len += 1;
+        state
+    }
+
+    fn into_result(State { weights, .. }: Self::State) -> Self {
+        Self(
+            weights
+                .into_iter()
+                .filter(|(_, (weight, _))| !weight.is_nan())
+                .max_by(|(_, (left, left_first)), (_, (right, right_first))| {
+                    left.total_cmp(right)
+                        .then_with(|| right_first.cmp(left_first))
+                })
+                .map(|(category, _)| category),
+        )
+    }
+}
+
+impl<T, W> MergeReductor<(T, W)> for WeightedMode<T>
+where
+    T: Eq + Hash,
+    W: Into<f64>,
+{
+    fn merge(mut left: Self::State, right: Self::State) -> Self::State {
+        for (category, (weight, first)) in right.weights {
+            match left.weights.entry(category) {
+                Entry::Occupied(mut entry) => entry.get_mut().0 += weight,
+                Entry::Vacant(entry) => {
+                    entry.insert((weight, left.len + first));
+                }
+            }
+        }
+        left.len += right.len;
+        left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::par_reduce_slice, Reduce};
+
+    use super::*;
+
+    #[test]
+    fn test_weighted_mode() {
+        // "b" is the most common category, but "a" has the greatest total weight
+        let data = [
+            ("a", 5.),
+            ("b", 1.),
+            ("b", 2.),
+            ("c", 4.5),
+            ("b", 1.5),
+            ("a", 0.5),
+        ];
+
+        let WeightedMode(mode) = data.into_iter().reduce_with();
+        assert_eq!(mode, Some("a"));
+
+        for threads in 2..=4 {
+            let WeightedMode(merged) = par_reduce_slice(&data, threads, 0);
+            assert_eq!(merged, Some("a"));
+        }
+
+        let WeightedMode(mode) = std::iter::empty::<(char, u8)>().reduce_with();
+        assert_eq!(mode, None);
+    }
+
+    #[test]
+    fn test_weighted_mode_ties() {
+        let data = [('x', 1), ('y', 3), ('z', 3), ('x', 1), ('w', 1)];
+
+        let WeightedMode(mode) = data.into_iter().reduce_with();
+        assert_eq!(mode, Some('y'));
+
+        // the tie is broken by the first occurrence, even when merging
+        for threads in 2..=5 {
+            let WeightedMode(merged) = par_reduce_slice(&data, threads, 0);
+            assert_eq!(merged, Some('y'));
+        }
+
+        let WeightedMode(mode) = [('a', f64::NAN), ('b', -1.)].into_iter().reduce_with();
+        assert_eq!(mode, Some('b'));
+    }
+}
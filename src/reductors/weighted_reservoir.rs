@@ -0,0 +1,186 @@
+use std::{cmp::Ordering, collections::BinaryHeap, marker::PhantomData};
+
+use rand::Rng;
+
+use crate::Reductor;
+
+/// Reductor that samples up to `N` items out of the `(item, weight)` pairs yielded by an iterator,
+/// where the probability of each item being sampled is proportional to its weight.
+///
+/// This uses the [Efraimidis–Spirakis] algorithm (A-Res): each item is assigned a random key of
+/// `u^(1/w)` (where `u` is uniformly distributed in `[0, 1)`, and `w` is the item's weight), and
+/// the `N` items with the largest keys are retained.
+///
+/// Items with a weight that is zero, negative or NaN are skipped, and can never be sampled.
+///
+/// The random number generator must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with), so that sampling can be made deterministic
+/// by using a seeded generator.
+///
+/// Requires the `rand` feature.
+///
+/// [Efraimidis–Spirakis]: https://en.wikipedia.org/wiki/Reservoir_sampling#Algorithm_A-Res
+///
+/// # Panics
+/// Panics if reduced without an RNG (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use rand::{rngs::SmallRng, SeedableRng};
+/// use reductor::{Reduce, WeightedReservoir};
+///
+/// let events = [("debug", 0.1), ("info", 1.), ("warn", 5.), ("error", 20.), ("ignored", 0.)];
+///
+/// let WeightedReservoir::<_, _, 2> { items, .. } = events
+///     .into_iter()
+///     .fold_with(SmallRng::seed_from_u64(7));
+///
+/// assert_eq!(items.len(), 2);
+/// assert!(!items.contains(&"ignored"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WeightedReservoir<T, R, const N: usize> {
+    /// Sampled items, in no particular order.
+    pub items: Vec<T>,
+    rng: PhantomData<R>,
+}
+
+#[derive(Debug, Clone)]
+struct Keyed<T> {
+    key: f64,
+    item: T,
+}
+
+impl<T> PartialEq for Keyed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Keyed<T> {}
+
+impl<T> PartialOrd for Keyed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reversed, so that a [`BinaryHeap`] pops the smallest key first.
+impl<T> Ord for Keyed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key.total_cmp(&self.key)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State<T, R> {
+    rng: R,
+    heap: BinaryHeap<Keyed<T>>,
+}
+
+impl<T, R> From<R> for State<T, R> {
+    fn from(rng: R) -> Self {
+        Self {
+            rng,
+            heap: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<T, R, const N: usize> Reductor<(T, f64)> for WeightedReservoir<T, R, N>
+where
+    R: Rng,
+{
+    type State = State<T, R>;
+
+    fn new(_: (T, f64)) -> Self::State {
+        panic!("`WeightedReservoir` must be used with `fold_with`, providing a random number generator")
+    }
+
+    fn reduce(mut state: Self::State, (item, weight): (T, f64)) -> Self::State {
+        if weight.is_nan() || weight <= 0. || N == 0 {
+            return state;
+        }
+
+        // `ln(u^(1/w)) = ln(u) / w`, which has the same ordering, but is more numerically stable.
+        let key = state.rng.random::<f64>().ln() / weight;
+
+        if state.heap.len() < N {
+            state.heap.push(Keyed { key, item });
+        } else if let Some(mut min) = state.heap.peek_mut() {
+            if key > min.key {
+                *min = Keyed { key, item };
+            }
+        }
+
+        state
+    }
+
+    fn into_result(state: Self::State) -> Self {
+        Self {
+            items: state.heap.into_iter().map(|keyed| keyed.item).collect(),
+            rng: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_weighted_reservoir_size() {
+        let WeightedReservoir::<_, _, 5> { items, .. } = (0..100)
+            .map(|i| (i, 1.))
+            .fold_with(SmallRng::seed_from_u64(0));
+        assert_eq!(items.len(), 5);
+
+        let WeightedReservoir::<_, _, 5> { items, .. } = (0..3)
+            .map(|i| (i, 1.))
+            .fold_with(SmallRng::seed_from_u64(0));
+        assert_eq!(items.len(), 3);
+    }
+
+    #[test]
+    fn test_weighted_reservoir_skips_invalid_weights() {
+        for seed in 0..100 {
+            let WeightedReservoir::<_, _, 2> { mut items, .. } =
+                [(0, 0.), (1, -3.), (2, f64::NAN), (3, 0.5), (4, 2.)]
+                    .into_iter()
+                    .fold_with(SmallRng::seed_from_u64(seed));
+            items.sort_unstable();
+            assert_eq!(items, [3, 4]);
+        }
+    }
+
+    #[test]
+    fn test_weighted_reservoir_proportional() {
+        const RUNS: u64 = 20_000;
+
+        let weights = [1., 2., 3., 4.];
+        let total: f64 = weights.iter().sum();
+        let mut counts = [0u64; 4];
+
+        for seed in 0..RUNS {
+            let WeightedReservoir::<_, _, 1> { items, .. } = weights
+                .iter()
+                .enumerate()
+                .map(|(i, &w)| (i, w))
+                .fold_with(SmallRng::seed_from_u64(seed));
+            counts[items[0]] += 1;
+        }
+
+        for (count, weight) in counts.into_iter().zip(weights) {
+            let observed = count as f64 / RUNS as f64;
+            let expected = weight / total;
+            assert!(
+                (observed - expected).abs() < 0.02,
+                "observed {observed}, expected {expected}"
+            );
+        }
+    }
+}
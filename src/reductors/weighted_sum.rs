@@ -0,0 +1,115 @@
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that sums the `(value, weight)` pairs yielded by an iterator into a weighted sum and
+/// a total weight, the components of a [weighted arithmetic mean].
+///
+/// Unlike a mean, these can be [`combine`](Self::combine)d exactly, e.g. when partial results
+/// are computed on separate shards of the data. The weighted mean is
+/// `weighted_sum / total_weight`.
+///
+/// [weighted arithmetic mean]: https://en.wikipedia.org/wiki/Weighted_arithmetic_mean
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, WeightedSumAndWeight};
+///
+/// let shard_a = [(4.5, 10), (3.0, 2)];
+/// let shard_b = [(6.0, 2)];
+///
+/// let a: WeightedSumAndWeight<f64> = shard_a.into_iter().reduce_with();
+/// let b: WeightedSumAndWeight<f64> = shard_b.into_iter().reduce_with();
+/// let WeightedSumAndWeight { weighted_sum, total_weight } = a.combine(b);
+///
+/// assert_eq!(weighted_sum / total_weight, 4.5);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct WeightedSumAndWeight<F> {
+    /// Sum of values, each multiplied by its weight.
+    pub weighted_sum: F,
+    /// Sum of weights.
+    pub total_weight: F,
+}
+
+macro_rules! impl_weighted_sum {
+    ($f:ty) => {
+        impl WeightedSumAndWeight<$f> {
+            /// Combine two partial results, as if their items were yielded by a single iterator.
+            #[must_use]
+            pub fn combine(self, other: Self) -> Self {
+                Self {
+                    weighted_sum: self.weighted_sum + other.weighted_sum,
+                    total_weight: self.total_weight + other.total_weight,
+                }
+            }
+        }
+
+        impl<V, W> Reductor<(V, W)> for WeightedSumAndWeight<$f>
+        where
+            V: Into<$f>,
+            W: Into<$f>,
+        {
+            type State = Self;
+
+            #[inline]
+            fn new(item: (V, W)) -> Self::State {
+                Self::reduce(Self::default(), item)
+            }
+
+            #[inline]
+            fn reduce(state: Self::State, (value, weight): (V, W)) -> Self::State {
+                let weight = weight.into();
+                Self {
+                    weighted_sum: value.into().mul_add(weight, state.weighted_sum),
+                    total_weight: state.total_weight + weight,
+                }
+            }
+
+            #[inline]
+            fn into_result(state: Self::State) -> Self {
+                state
+            }
+        }
+
+        impl<V, W> MergeReductor<(V, W)> for WeightedSumAndWeight<$f>
+        where
+            V: Into<$f>,
+            W: Into<$f>,
+        {
+            #[inline]
+            fn merge(left: Self::State, right: Self::State) -> Self::State {
+                left.combine(right)
+            }
+        }
+    };
+}
+
+impl_weighted_sum!(f32);
+impl_weighted_sum!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_weighted_sum_and_weight() {
+        let grades = [(90u8, 0.5f32), (70, 0.25), (80, 0.25)];
+
+        let WeightedSumAndWeight::<f64> {
+            weighted_sum,
+            total_weight,
+        } = grades.into_iter().reduce_with();
+        assert_eq!((weighted_sum, total_weight), (82.5, 1.));
+        assert_eq!(weighted_sum / total_weight, 82.5);
+
+        let (first, rest) = grades.split_at(1);
+        let first: WeightedSumAndWeight<f64> = first.iter().copied().reduce_with();
+        let rest: WeightedSumAndWeight<f64> = rest.iter().copied().reduce_with();
+        assert_eq!(first.combine(rest), grades.into_iter().reduce_with());
+
+        let empty: WeightedSumAndWeight<f32> = std::iter::empty::<(f32, f32)>().reduce_with();
+        assert_eq!(empty, WeightedSumAndWeight::default());
+    }
+}
@@ -0,0 +1,101 @@
+use half::{bf16, f16};
+
+use crate::{MergeReductor, Reductor};
+
+/// Reductor that adds half-precision floats ([`f16`](struct@f16) or [`bf16`]) yielded by an iterator
+/// into a wider accumulator ([`f32`] or [`f64`]).
+///
+/// Summing many half-precision values in half precision quickly loses precision, and eventually
+/// stalls completely once the sum is large enough that adding a small value rounds back to the
+/// same sum. Accumulating into a wider float avoids this. ([`Sum`](crate::Sum) can't be used
+/// for this, since its implementation relies on [`std::iter::Sum`], which `f32` doesn't
+/// implement for half-precision items.)
+///
+/// Requires the `half` feature.
+///
+/// # Examples
+/// ```rust
+/// use half::f16;
+/// use reductor::{Reduce, WideSum};
+///
+/// let WideSum::<f32>(sum) = std::iter::repeat_n(f16::from_f32(0.1), 10_000).reduce_with();
+/// assert!((sum - 1000.).abs() < 1.);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct WideSum<F>(pub F);
+
+macro_rules! impl_wide_sum {
+    ($f:ty: $($half:ty),+) => {
+        $(
+            impl Reductor<$half> for WideSum<$f> {
+                type State = $f;
+
+                #[inline]
+                fn new(item: $half) -> Self::State {
+                    item.into()
+                }
+
+                #[inline]
+                fn reduce(state: Self::State, item: $half) -> Self::State {
+                    state + <$f>::from(item)
+                }
+
+                #[inline]
+                fn into_result(state: Self::State) -> Self {
+                    Self(state)
+                }
+            }
+
+            impl MergeReductor<$half> for WideSum<$f> {
+                #[inline]
+                fn merge(left: Self::State, right: Self::State) -> Self::State {
+                    left + right
+                }
+            }
+        )+
+    };
+}
+
+impl_wide_sum!(f32: f16, bf16);
+impl_wide_sum!(f64: f16, bf16);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mean, Reduce};
+
+    use super::*;
+
+    #[test]
+    fn test_wide_sum_doesnt_stall() {
+        let item = f16::from_f32(0.01);
+
+        let naive = std::iter::repeat_n(item, 100_000).fold(f16::ZERO, |sum, item| sum + item);
+        let WideSum::<f32>(wide) = std::iter::repeat_n(item, 100_000).reduce_with();
+
+        // Adding 0.01 to a half-precision sum stops having any effect at 32.
+        assert_eq!(naive, f16::from_f32(32.));
+        assert!((wide - 1000.).abs() < 1., "{wide}");
+
+        let WideSum::<f64>(wide) =
+            std::iter::repeat_n(bf16::from_f32(0.5), 1_000_000).reduce_with();
+        assert_eq!(wide, 500_000.);
+    }
+
+    #[test]
+    fn test_half_mean() {
+        let Mean::<f32>(mean) = [1.5, 2.5, -1., 5.]
+            .map(f16::from_f32)
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(mean, 2.);
+
+        let Mean::<f64>(mean) = [bf16::ONE, bf16::from_f32(3.)]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(mean, 2.);
+    }
+}
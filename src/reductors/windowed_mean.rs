@@ -0,0 +1,120 @@
+use super::state::NonEmptyState;
+use crate::Reductor;
+
+/// Reductor that computes the [arithmetic mean] of the last `N` items yielded by an iterator
+/// (or of all items, if the iterator yielded fewer than `N` items).
+///
+/// Like [`Mean`](crate::Mean), the generic type `F` must be one of [`f32`] or [`f64`], and the
+/// iterator's item type can be any type that implements [`Into<F>`].
+///
+/// The last `N` values are kept in a ring buffer, and the sum of the window is only computed
+/// when the iterator is exhausted, so no numerical error accumulates over long iterators.
+/// `N` must be greater than zero.
+///
+/// [arithmetic mean]: https://en.wikipedia.org/wiki/Arithmetic_mean
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, Reductors, Mean, WindowedMean};
+///
+/// let Reductors((Mean::<f64>(mean), WindowedMean::<f64, 3>(recent))) = [1, 2, 3, 10, 10, 10]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(mean, 6.);
+/// assert_eq!(recent, 10.);
+/// ```
+#[repr(transparent)]
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct WindowedMean<F, const N: usize>(pub F);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Window<F, const N: usize> {
+    buf: [F; N],
+    count: usize,
+}
+
+macro_rules! impl_windowed_mean {
+    ($f:ty) => {
+        impl<T, const N: usize> Reductor<T> for WindowedMean<$f, N>
+        where
+            T: Into<$f>,
+        {
+            type State = NonEmptyState<Window<$f, N>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                const { assert!(N > 0, "window size must be greater than zero") };
+
+                let mut buf = [0.; N];
+                buf[0] = item.into();
+                NonEmptyState(Window { buf, count: 1 })
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(mut window): Self::State, item: T) -> Self::State {
+                window.buf[window.count % N] = item.into();
+                window.count += 1;
+                NonEmptyState(window)
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(window): Self::State) -> Self {
+                let values = &window.buf[..window.count.min(N)];
+                Self(values.iter().sum::<$f>() / values.len() as $f)
+            }
+        }
+    };
+}
+
+impl_windowed_mean!(f32);
+impl_windowed_mean!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mean, Reduce};
+
+    use super::*;
+
+    const DATA: [f64; 12] = [3.5, -1., 8.25, 0., 12., 4.5, -7., 2., 9.75, 1.5, 6., -3.25];
+
+    #[test]
+    fn test_windowed_mean_short() {
+        let WindowedMean::<f64, 16>(windowed) =
+            DATA.into_iter().reduce_with::<Option<_>>().unwrap();
+        let Mean::<f64>(mean) = DATA.into_iter().reduce_with::<Option<_>>().unwrap();
+
+        assert!((windowed - mean).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_windowed_mean_tail() {
+        macro_rules! test {
+            ($n:literal) => {
+                let WindowedMean::<f64, $n>(windowed) =
+                    DATA.into_iter().reduce_with::<Option<_>>().unwrap();
+                let tail = &DATA[DATA.len() - $n..];
+                let expected = tail.iter().sum::<f64>() / $n as f64;
+
+                assert!((windowed - expected).abs() < 1e-12);
+            };
+        }
+
+        test!(1);
+        test!(3);
+        test!(5);
+        test!(11);
+        test!(12);
+    }
+
+    #[test]
+    fn test_windowed_mean_f32() {
+        let WindowedMean::<f32, 2>(windowed) = [1u8, 2, 3, 4]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(windowed, 3.5);
+    }
+}
@@ -0,0 +1,221 @@
+use std::{cmp::Ordering, collections::VecDeque};
+
+use super::state::NonEmptyState;
+use crate::Reductor;
+
+/// Window of the last `N` items, only keeping the items that may still become the extreme
+/// of the window, in a monotonic deque.
+///
+/// Each item is pushed and popped at most once, so updating the window takes amortized
+/// `O(1)` time, regardless of `N`.
+#[derive(Debug, Clone)]
+pub struct Window<T, const N: usize> {
+    /// `(index, item)` pairs, in order of arrival, where each item is strictly more extreme
+    /// than all items after it.
+    deque: VecDeque<(usize, T)>,
+    count: usize,
+}
+
+impl<T, const N: usize> Window<T, N>
+where
+    T: Ord,
+{
+    fn new(item: T) -> Self {
+        const { assert!(N > 0, "window size must be greater than zero") };
+
+        Self {
+            deque: VecDeque::from([(0, item)]),
+            count: 1,
+        }
+    }
+
+    /// Push an item, where `extreme` is the ordering of an item relative to the items it
+    /// would displace.
+    #[inline]
+    fn push(mut self, item: T, extreme: Ordering) -> Self {
+        while self
+            .deque
+            .back()
+            .is_some_and(|(_, back)| back.cmp(&item) != extreme)
+        {
+            self.deque.pop_back();
+        }
+
+        if self
+            .deque
+            .front()
+            .is_some_and(|&(index, _)| index + N <= self.count)
+        {
+            self.deque.pop_front();
+        }
+
+        self.deque.push_back((self.count, item));
+        self.count += 1;
+        self
+    }
+
+    fn into_extreme(mut self) -> T {
+        self.deque
+            .pop_front()
+            .expect("window always contains the last item")
+            .1
+    }
+}
+
+macro_rules! impl_windowed_min_max {
+    ($name:ident, $extreme:expr) => {
+        impl<T, const N: usize> Reductor<T> for $name<T, N>
+        where
+            T: Ord,
+        {
+            type State = NonEmptyState<Window<T, N>>;
+
+            #[inline]
+            fn new(item: T) -> Self::State {
+                NonEmptyState(Window::new(item))
+            }
+
+            #[inline]
+            fn reduce(NonEmptyState(window): Self::State, item: T) -> Self::State {
+                NonEmptyState(window.push(item, $extreme))
+            }
+
+            #[inline]
+            fn into_result(NonEmptyState(window): Self::State) -> Self {
+                Self(window.into_extreme())
+            }
+        }
+    };
+}
+
+/// Reductor that finds the maximum of the last `N` items yielded by an iterator
+/// (or of all items, if the iterator yielded fewer than `N` items).
+///
+/// Rather than rescanning the window for every item, this keeps a monotonic deque of the items that
+/// may still become the maximum, taking amortized `O(1)` time per item. `N` must be greater
+/// than zero. If several items in the window are equal to the maximum, the last one is returned.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Max, Reduce, Reductors, WindowedMax};
+///
+/// let Reductors((Max::<u32>(max), WindowedMax::<_, 3>(recent))) = [9, 2, 7, 3, 5, 1]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(max, 9);
+/// assert_eq!(recent, 5);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WindowedMax<T, const N: usize>(pub T);
+
+impl_windowed_min_max!(WindowedMax, Ordering::Greater);
+
+/// Reductor that finds the minimum of the last `N` items yielded by an iterator
+/// (or of all items, if the iterator yielded fewer than `N` items).
+///
+/// See [`WindowedMax`] for details. If several items in the window are equal to the minimum,
+/// the last one is returned.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, WindowedMin};
+///
+/// let WindowedMin::<_, 4>(recent) = [1, 8, 6, 4, 7, 5]
+///     .into_iter()
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(recent, 4);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WindowedMin<T, const N: usize>(pub T);
+
+impl_windowed_min_max!(WindowedMin, Ordering::Less);
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use crate::Reduce;
+
+    use super::*;
+
+    fn check<const N: usize>(data: &[u16]) {
+        let window = &data[data.len().saturating_sub(N)..];
+
+        let max: Option<WindowedMax<_, N>> = data.iter().reduce_with();
+        let min: Option<WindowedMin<_, N>> = data.iter().reduce_with();
+
+        assert_eq!(max.map(|WindowedMax(max)| max), window.iter().max());
+        assert_eq!(min.map(|WindowedMin(min)| min), window.iter().min());
+    }
+
+    fn check_prefixes(data: &[u16]) {
+        for len in 0..=data.len() {
+            check::<1>(&data[..len]);
+            check::<3>(&data[..len]);
+            check::<16>(&data[..len]);
+        }
+    }
+
+    #[test]
+    fn test_windowed_min_max_random() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let data: Vec<u16> = (0..500).map(|_| rng.random()).collect();
+        check_prefixes(&data);
+
+        // Heavy duplicates.
+        let data: Vec<u16> = (0..500).map(|_| rng.random_range(0..4)).collect();
+        check_prefixes(&data);
+    }
+
+    #[test]
+    fn test_windowed_min_max_monotone() {
+        let increasing: Vec<u16> = (0..200).collect();
+        let decreasing: Vec<u16> = (0..200).rev().collect();
+        let sawtooth: Vec<u16> = (0..200).map(|i| i % 50).collect();
+        let plateaus: Vec<u16> = (0..200).map(|i| i / 20).collect();
+
+        for data in [increasing, decreasing, sawtooth, plateaus] {
+            check_prefixes(&data);
+        }
+    }
+
+    /// Ordered by `key` only, so that items with equal keys can be told apart.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Tagged {
+        key: u8,
+        tag: char,
+    }
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    #[test]
+    fn test_windowed_min_max_latest_tie() {
+        let items = || {
+            [(5, 'a'), (7, 'b'), (7, 'c'), (5, 'd'), (5, 'e'), (6, 'f')]
+                .into_iter()
+                .map(|(key, tag)| Tagged { key, tag })
+        };
+
+        let WindowedMax::<_, 5>(max) = items().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(max.tag, 'c');
+
+        let WindowedMin::<_, 5>(min) = items().reduce_with::<Option<_>>().unwrap();
+        assert_eq!(min.tag, 'e');
+    }
+}
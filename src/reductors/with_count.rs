@@ -0,0 +1,217 @@
+use std::cmp::{Ord, Ordering};
+
+use super::state::NonEmptyState;
+use crate::{MergeReductor, Reductor};
+
+macro_rules! impl_with_count {
+    () => {
+        type State = NonEmptyState<(T, usize)>;
+
+        #[inline]
+        fn new(item: T) -> Self::State {
+            NonEmptyState((item, 1))
+        }
+
+        #[inline]
+        fn reduce(state: Self::State, item: T) -> Self::State {
+            Self::merge(state, Self::new(item))
+        }
+
+        #[inline]
+        fn into_result(NonEmptyState((value, count)): Self::State) -> Self {
+            Self { value, count }
+        }
+    };
+}
+
+macro_rules! impl_with_count_merge {
+    ($better:path, keep_last: $keep_last:literal) => {
+        #[inline]
+        fn merge(
+            NonEmptyState((left, left_count)): Self::State,
+            NonEmptyState((right, right_count)): Self::State,
+        ) -> Self::State {
+            NonEmptyState(match right.cmp(&left) {
+                $better => (right, right_count),
+                Ordering::Equal => (
+                    if $keep_last { right } else { left },
+                    left_count + right_count,
+                ),
+                _ => (left, left_count),
+            })
+        }
+    };
+}
+
+/// Reductor that retains the maximum value yielded by an iterator (similarly to [`Iterator::max`]),
+/// along with the number of items that were equal to it.
+///
+/// Like [`Iterator::max`], if several items are equal to the maximum, the last one is retained.
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, Reductors, MaxWithCount, Count};
+///
+/// let timeout_ms = 500;
+/// let latencies_ms = [120, 500, 80, 500, 310, 500];
+///
+/// let Reductors((MaxWithCount { value, count }, Count(total))) = latencies_ms
+///     .into_iter()
+///     .map(|latency| latency.min(timeout_ms))
+///     .reduce_with::<Option<_>>()
+///     .unwrap();
+///
+/// assert_eq!(value, timeout_ms);
+/// assert_eq!(count as f64 / total as f64, 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaxWithCount<T> {
+    /// Maximum value yielded by iterator.
+    pub value: T,
+    /// Number of items that were equal to the maximum value.
+    pub count: usize,
+}
+
+impl<T> Reductor<T> for MaxWithCount<T>
+where
+    T: Ord,
+{
+    impl_with_count!();
+}
+
+impl<T> MergeReductor<T> for MaxWithCount<T>
+where
+    T: Ord,
+{
+    impl_with_count_merge!(Ordering::Greater, keep_last: true);
+}
+
+/// Reductor that retains the minimum value yielded by an iterator (similarly to [`Iterator::min`]),
+/// along with the number of items that were equal to it.
+///
+/// Like [`Iterator::min`], if several items are equal to the minimum, the first one is retained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MinWithCount<T> {
+    /// Minimum value yielded by iterator.
+    pub value: T,
+    /// Number of items that were equal to the minimum value.
+    pub count: usize,
+}
+
+impl<T> Reductor<T> for MinWithCount<T>
+where
+    T: Ord,
+{
+    impl_with_count!();
+}
+
+impl<T> MergeReductor<T> for MinWithCount<T>
+where
+    T: Ord,
+{
+    impl_with_count_merge!(Ordering::Less, keep_last: false);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Count, Reduce, Reductors};
+
+    use super::*;
+
+    #[test]
+    fn test_all_equal() {
+        let MaxWithCount { value, count } = [7; 5].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!((value, count), (7, 5));
+
+        let MinWithCount { value, count } = [7; 5].into_iter().reduce_with::<Option<_>>().unwrap();
+        assert_eq!((value, count), (7, 5));
+    }
+
+    #[test]
+    fn test_extreme_first_and_last() {
+        let MaxWithCount { value, count } = [9, 1, 9, 3, 2]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!((value, count), (9, 2));
+
+        let MaxWithCount { value, count } = [1, 3, 2, 8, 9]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!((value, count), (9, 1));
+
+        let MinWithCount { value, count } = [0, 3, 0, 8, 1]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!((value, count), (0, 2));
+
+        let MinWithCount { value, count } = [5, 3, 2, 8, -1]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!((value, count), (-1, 1));
+    }
+
+    #[test]
+    fn test_tie_retains_like_std() {
+        let data = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        let key = |&(k, _): &(i32, char)| k;
+
+        let MaxWithCount { value, count } = data
+            .iter()
+            .map(|item| ByKey(*item))
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(
+            value.0,
+            data.iter().max_by_key(|item| key(item)).copied().unwrap()
+        );
+        assert_eq!(count, 2);
+
+        let MinWithCount { value, .. } = data
+            .iter()
+            .map(|item| ByKey(*item))
+            .reduce_with::<Option<_>>()
+            .unwrap();
+        assert_eq!(
+            value.0,
+            data.iter().min_by_key(|item| key(item)).copied().unwrap()
+        );
+    }
+
+    #[derive(Debug)]
+    struct ByKey((i32, char));
+
+    impl PartialEq for ByKey {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 .0 == other.0 .0
+        }
+    }
+
+    impl Eq for ByKey {}
+
+    impl PartialOrd for ByKey {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for ByKey {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0 .0.cmp(&other.0 .0)
+        }
+    }
+
+    #[test]
+    fn test_fraction_with_count() {
+        let Reductors((MinWithCount { value, count }, Count(total))) = [3, 1, 4, 1, 5, 9, 2, 6]
+            .into_iter()
+            .reduce_with::<Option<_>>()
+            .unwrap();
+
+        assert_eq!(value, 1);
+        assert_eq!(count as f64 / total as f64, 0.25);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::Reductor;
+
+/// Reductor that counts the items yielded by an iterator that lie within a tolerance of the
+/// mean of all items, i.e. for which `|x - mean| <= tol`.
+///
+/// Since the mean isn't known until the iterator is exhausted, this reductor buffers all items,
+/// so it uses `O(n)` memory, and only compares them with the mean in
+/// [`into_result`](Reductor::into_result). When the reference value is known in advance,
+/// [`Proportion`](crate::Proportion) can count the items within the tolerance without buffering,
+/// e.g. with a `|&x| (x - reference).abs() <= tol` predicate.
+///
+/// The tolerance must be provided as the initial state, by calling
+/// [`fold_with`](crate::Reduce::fold_with).
+///
+/// # Panics
+/// Panics if the tolerance is negative or NaN.
+///
+/// Panics if reduced without a tolerance (see [`fold_with`](crate::Reduce::fold_with)).
+///
+/// # Examples
+/// ```rust
+/// use reductor::{Reduce, WithinTolerance};
+///
+/// let widths_mm = [10.02, 9.98, 10.01, 10.4, 9.99, 9.6];
+///
+/// let WithinTolerance::<f64> { mean, within_count, total } = widths_mm.into_iter().fold_with(0.05);
+/// assert_eq!(mean, Some(10.));
+/// assert_eq!((within_count, total), (4, 6));
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)] // `F` never impls `Eq`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct WithinTolerance<F> {
+    /// Arithmetic mean of items yielded by iterator, or `None` if there were no items.
+    pub mean: Option<F>,
+    /// Number of items within the tolerance of the mean.
+    pub within_count: usize,
+    /// Total number of items.
+    pub total: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct State<F> {
+    tolerance: F,
+    items: Vec<F>,
+}
+
+macro_rules! impl_within_tolerance {
+    ($f:ty) => {
+        impl From<$f> for State<$f> {
+            fn from(tolerance: $f) -> Self {
+                assert!(
+                    tolerance >= 0.,
+                    "tolerance must be non-negative, got {tolerance}"
+                );
+
+                Self {
+                    tolerance,
+                    items: Vec::new(),
+                }
+            }
+        }
+
+        impl<T> Reductor<T> for WithinTolerance<$f>
+        where
+            T: Into<$f>,
+        {
+            type State = State<$f>;
+
+            fn new(_: T) -> Self::State {
+                panic!("`WithinTolerance` must be used with `fold_with`, providing a tolerance")
+            }
+
+            #[inline]
+            fn reduce(mut state: Self::State, item: T) -> Self::State {
+                state.items.push(item.into());
+                state
+            }
+
+            fn into_result(State { tolerance, items }: Self::State) -> Self {
+                let total = items.len();
+                if total == 0 {
+                    return Self {
+                        mean: None,
+                        within_count: 0,
+                        total,
+                    };
+                }
+
+                let mean = items.iter().sum::<$f>() / total as $f;
+                let within_count = items
+                    .iter()
+                    .filter(|item| (*item - mean).abs() <= tolerance)
+                    .count();
+
+                Self {
+                    mean: Some(mean),
+                    within_count,
+                    total,
+                }
+            }
+        }
+    };
+}
+
+impl_within_tolerance!(f32);
+impl_within_tolerance!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Reduce;
+
+    use super::*;
+
+    #[test]
+    fn test_within_tolerance() {
+        let WithinTolerance::<f64> {
+            mean,
+            within_count,
+            total,
+        } = [2, 4, 4, 4, 5, 5, 7, 9].into_iter().fold_with(1.);
+
+        // Mean is 5, so 4, 4, 4, 5, 5 are within the tolerance (inclusive), and 2, 7, 9 aren't.
+        assert_eq!(mean, Some(5.));
+        assert_eq!((within_count, total), (5, 8));
+
+        let WithinTolerance::<f32> { within_count, .. } = [1u8, 2, 3].into_iter().fold_with(0.);
+        assert_eq!(within_count, 1);
+    }
+
+    #[test]
+    fn test_within_tolerance_empty() {
+        let empty: WithinTolerance<f64> = std::iter::empty::<f64>().fold_with(1.);
+        assert_eq!(
+            empty,
+            WithinTolerance {
+                mean: None,
+                within_count: 0,
+                total: 0
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic = "non-negative"]
+    fn test_within_tolerance_negative() {
+        let _: WithinTolerance<f64> = [1.].into_iter().fold_with(-1.);
+    }
+}
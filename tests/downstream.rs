@@ -0,0 +1,98 @@
+//! Reductors and state conversions that are implemented outside of this crate,
+//! using only its public API.
+
+use reductor::{
+    MeanMaxDeviation, MergeReductor, NonEmptyState, Product, ProductState, Reduce, Reductor,
+    Reductors, Sum,
+};
+
+/// Reductor that retains the last item yielded by an iterator.
+#[derive(Debug, PartialEq)]
+struct Last<T>(T);
+
+impl<T> Reductor<T> for Last<T> {
+    type State = NonEmptyState<T>;
+
+    fn new(item: T) -> Self::State {
+        NonEmptyState::new(item)
+    }
+
+    fn reduce(mut state: Self::State, item: T) -> Self::State {
+        *state.get_mut() = item;
+        state
+    }
+
+    fn into_result(state: Self::State) -> Self {
+        Self(state.into_inner())
+    }
+}
+
+impl<T> MergeReductor<T> for Last<T> {
+    fn merge(_: Self::State, right: Self::State) -> Self::State {
+        right
+    }
+}
+
+#[test]
+fn test_non_empty_state_reductor() {
+    assert_eq!(
+        [1, 2, 3].into_iter().reduce_with::<Option<_>>(),
+        Some(Last(3))
+    );
+    assert_eq!(
+        std::iter::empty::<u8>().reduce_with::<Option<Last<_>>>(),
+        None
+    );
+
+    let Reductors((Last(last), Sum::<i32>(sum))) =
+        [4, 5, 6].into_iter().reduce_with::<Option<_>>().unwrap();
+    assert_eq!((last, sum), (6, 15));
+
+    let left = [1, 2].into_iter().fold(Last::new(0), Last::reduce);
+    let right = [7, 8].into_iter().fold(Last::new(6), Last::reduce);
+    assert_eq!(*Last::merge(left, right).get(), 8);
+}
+
+/// Percentage, that can be used as the initial state of a [`Product`].
+struct Percent(f64);
+
+impl From<Percent> for ProductState<f64> {
+    fn from(Percent(percent): Percent) -> Self {
+        (percent / 100.).into()
+    }
+}
+
+#[test]
+fn test_built_in_state_conversion() {
+    let Product(product) = [2., 3.]
+        .into_iter()
+        .fold_with::<Product<f64>, _>(Percent(50.));
+    assert_eq!(product, 3.);
+}
+
+#[test]
+fn test_store_built_in_states() {
+    type Deviation = Option<MeanMaxDeviation<f64>>;
+
+    struct Partial {
+        product: ProductState<i64>,
+        deviation: <Deviation as Reductor<f64>>::State,
+    }
+
+    let mut partial = Partial {
+        product: Default::default(),
+        deviation: None,
+    };
+    for item in [2, 5, 3] {
+        partial.product = Product::reduce(partial.product, item);
+        partial.deviation = <Deviation as Reductor<f64>>::reduce(partial.deviation, item as f64);
+    }
+
+    assert_eq!(
+        <Product<i64> as Reductor<i64>>::into_result(partial.product),
+        Product(30)
+    );
+    let MeanMaxDeviation { mean, .. } =
+        <Deviation as Reductor<f64>>::into_result(partial.deviation).unwrap();
+    assert_eq!(mean, 10. / 3.);
+}